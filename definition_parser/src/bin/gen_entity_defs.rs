@@ -0,0 +1,418 @@
+//! Generate the tables in `replay_parser/src/entity_defs` from a tree of definition files (one directory per
+//! version, see [VersionDefinitions]).
+//!
+//! ```text
+//! cargo run -p wot_def_file_parser --bin gen_entity_defs -- [--check] [DEF_DIR] [OUTPUT_DIR]
+//! ```
+//!
+//! `DEF_DIR` defaults to the `DEF_DIR` environment variable, or `definition_parser/definitions` if it is not
+//! set, and `OUTPUT_DIR` to the `entity_defs` module of the replay parser. With `--check`, nothing is written
+//! and the program fails if the checked-in tables are not the same as the ones generated from the
+//! definitions.
+//!
+//! Every version that has a directory in `DEF_DIR` is generated, including the ones we only have the tables
+//! of (see [TABLES_DIR](wot_def_file_parser::TABLES_DIR)). `VERSIONS` lists the versions whose entity types
+//! are known, so adding a version is a matter of adding its directory and running the generator.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use wot_def_file_parser::utils::get_definitions_root;
+use wot_def_file_parser::{
+    find_versions, EntitySignature, EntityTables, OpaqueType, VersionDefinitions, WotType,
+};
+
+const HEADER: &str =
+    "// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
+";
+
+fn main() -> ExitCode {
+    let mut check = false;
+    let mut paths = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ => paths.push(arg),
+        }
+    }
+
+    let mut paths = paths.into_iter();
+    let def_dir = paths.next().unwrap_or_else(get_definitions_root);
+    let output_dir = paths.next().map(PathBuf::from).unwrap_or_else(default_output_dir);
+
+    let files = match generate(&def_dir) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("cannot load definitions from {def_dir}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut is_ok = true;
+    for (file_name, contents) in files {
+        let path = output_dir.join(file_name);
+
+        if check {
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+                eprintln!("{} is out of date", path.display());
+                is_ok = false;
+            }
+        } else if let Err(err) = std::fs::write(&path, contents) {
+            eprintln!("cannot write {}: {err}", path.display());
+            is_ok = false;
+        }
+    }
+
+    if is_ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The `entity_defs` module of the replay parser
+fn default_output_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../replay_parser/src/entity_defs")
+}
+
+/// Manual adjustment of a generated table, for when the order derived from the definition files is not the
+/// one found in replays
+struct Override {
+    table:   &'static str,
+    version: [u16; 4],
+
+    /// Replace the name at an index by another one
+    names:   &'static [(usize, &'static str)],
+    comment: &'static str,
+}
+
+const OVERRIDES: &[Override] = &[Override {
+    table:   "VEHICLE_PROPS",
+    version: [0, 9, 20, 0],
+    names:   &[(4, "GunAnglesPacked"), (9, "GunAnglesPackedArr")],
+    comment: "Manual adjustment here: We switched places of `GunAnglesPacked` and `IsDisappeared`, Then in \
+              original position of GunAnglesPacked we use GunAnglesPackedArr. It's a special case for this \
+              version",
+}];
+
+/// Contents of each generated file
+fn generate(def_dir: &str) -> wot_def_file_parser::Result<Vec<(&'static str, String)>> {
+    let definitions = find_versions(def_dir)?
+        .into_iter()
+        .map(|version| VersionDefinitions::load(def_dir, version))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let names = |table: fn(&VersionDefinitions) -> &Vec<String>| {
+        move |defs: &VersionDefinitions| Some(table(defs).iter().map(|name| format!("\"{name}\"")).collect())
+    };
+
+    let avatar = [
+        render_map(
+            "AVATAR_METHODS",
+            "&str",
+            &definitions,
+            names(|defs| &defs.avatar_methods),
+        ),
+        render_map(
+            "AVATAR_PROPS",
+            "&str",
+            &definitions,
+            names(|defs| &defs.avatar_props),
+        ),
+    ];
+    let vehicle = [
+        render_map(
+            "VEHICLE_METHODS",
+            "&str",
+            &definitions,
+            names(|defs| &defs.vehicle_methods),
+        ),
+        render_map(
+            "VEHICLE_PROPS",
+            "&str",
+            &definitions,
+            names(|defs| &defs.vehicle_props),
+        ),
+    ];
+    let arena_tables = |table: fn(&EntityTables) -> &Vec<String>| {
+        move |defs: &VersionDefinitions| {
+            if !defs.has_def_files {
+                return None;
+            }
+            defs.entity_types.as_ref()?;
+
+            let tables = defs
+                .arena_entities
+                .iter()
+                .map(|entity| {
+                    let names = table(entity)
+                        .iter()
+                        .map(|name| format!("\"{name}\""))
+                        .collect::<Vec<_>>();
+                    format!("(EntityType::{}, &[{}])", entity.name, names.join(", "))
+                })
+                .collect();
+
+            Some(tables)
+        }
+    };
+    let arena_entities = [
+        render_map(
+            "ARENA_ENTITY_METHODS",
+            "(EntityType, &[&str])",
+            &definitions,
+            arena_tables(|entity| &entity.methods),
+        ),
+        render_map(
+            "ARENA_ENTITY_PROPS",
+            "(EntityType, &[&str])",
+            &definitions,
+            arena_tables(|entity| &entity.props),
+        ),
+    ];
+    let entity_type_map = render_map("ENTITY_TYPE_MAP", "EntityType", &definitions, |defs| {
+        defs.entity_types.clone()
+    });
+
+    let mut consts = Consts::default();
+    let mut signatures = Vec::new();
+    for defs in &definitions {
+        let entity_types = match &defs.entity_types {
+            Some(entity_types) if defs.has_def_files => entity_types,
+            _ => {
+                signatures.push(None);
+                continue;
+            }
+        };
+
+        let entities = EntitySignature::load_all(def_dir, defs.version, entity_types)?
+            .iter()
+            .map(|entity| {
+                format!(
+                    "(EntityType::{}, {})",
+                    entity.name,
+                    render_signature(entity, &mut consts)
+                )
+            })
+            .collect();
+        signatures.push(Some(entities));
+    }
+    let mut signatures = signatures.into_iter();
+    let signature_map = render_map(
+        "ENTITY_SIGNATURES",
+        "(EntityType, EntitySignatures)",
+        &definitions,
+        |_| signatures.next().flatten(),
+    );
+
+    let versions = definitions
+        .iter()
+        .filter(|defs| defs.entity_types.is_some())
+        .map(|defs| format!("    {:?},\n", defs.version))
+        .collect::<String>();
+
+    Ok(vec![
+        (
+            "versions.rs",
+            format!(
+                "{HEADER}\n/// Versions we have the tables of, i.e. the versions of the definitions whose entity types are \
+                 known. A\n/// replay of another version is parsed with the tables of the closest one\npub const \
+                 VERSIONS: &[[u16; 4]] = &[\n{versions}];\n"
+            ),
+        ),
+        (
+            "avatar.rs",
+            format!("{HEADER}use phf::phf_map;\n\n{}", avatar.join("\n")),
+        ),
+        (
+            "vehicle.rs",
+            format!("{HEADER}use phf::phf_map;\n\n{}", vehicle.join("\n")),
+        ),
+        (
+            "entity_type_map.rs",
+            format!(
+                "{HEADER}use phf::phf_map;\n\nuse super::EntityType::{{self, *}};\n\n/// A map that stores \
+                 entity_type id of an entity for each version\n{entity_type_map}"
+            ),
+        ),
+        (
+            "arena_entities.rs",
+            format!(
+                "{HEADER}use phf::phf_map;\n\nuse super::EntityType;\n\n{}",
+                arena_entities.join("\n")
+            ),
+        ),
+        (
+            "entity_signatures.rs",
+            format!(
+                "{HEADER}use phf::phf_map;\n\nuse super::DefType::{{self, *}};\nuse \
+                 super::{{EntitySignatures, EntityType}};\n\n/// Signatures of the \
+                 entities of each version\n{signature_map}\n{}",
+                consts.items.join("\n")
+            ),
+        ),
+    ])
+}
+
+/// Types and signatures that are used more than once are written as `const` items and referred to by name
+#[derive(Default)]
+struct Consts {
+    items: Vec<String>,
+    names: HashMap<String, String>,
+    count: HashMap<String, usize>,
+}
+
+impl Consts {
+    /// Name of the `const` whose value is `value`
+    fn intern(&mut self, prefix: &str, ty: &str, value: String) -> String {
+        if let Some(name) = self.names.get(&value) {
+            return name.clone();
+        }
+
+        let count = self.count.entry(prefix.to_string()).or_default();
+        let name = format!("{prefix}_{count}");
+        *count += 1;
+
+        self.items.push(format!("const {name}: {ty} = {value};\n"));
+        self.names.insert(value, name.clone());
+
+        name
+    }
+}
+
+/// `DefType` expression of `ty`
+fn render_type(ty: &WotType, consts: &mut Consts) -> String {
+    use OpaqueType::*;
+
+    let value = match ty {
+        WotType::OpaqueType(ty) => {
+            let name = match ty {
+                U8 => "U8",
+                I8 => "I8",
+                U16 => "U16",
+                I16 => "I16",
+                U32 => "U32",
+                I32 => "I32",
+                U64 => "U64",
+                I64 => "I64",
+                F32 => "F32",
+                F64 => "F64",
+                String => "String",
+                Vector2 => "Vector2",
+                Vector3 => "Vector3",
+                Vector4 => "Vector4",
+                Pickle => "Pickle",
+                MailBox => "MailBox",
+                UserType => "UserType",
+                Alias(ty) => return render_type(ty, consts),
+            };
+
+            return name.to_string();
+        }
+        WotType::Array(ty) => format!("Array(&{})", render_type(ty, consts)),
+        WotType::FixedArray(ty, len) => format!("FixedArray(&{}, {len})", render_type(ty, consts)),
+        WotType::FixedDict { is_nullable, dict } => {
+            let fields = dict
+                .iter()
+                .map(|(name, ty)| format!("(\"{name}\", {})", render_type(ty, consts)))
+                .collect::<Vec<_>>();
+
+            format!(
+                "FixedDict {{ is_nullable: {is_nullable}, fields: &[{}] }}",
+                fields.join(", ")
+            )
+        }
+    };
+
+    consts.intern("TYPE", "DefType", value)
+}
+
+/// Name of the `const` with the `EntitySignatures` of `entity`
+fn render_signature(entity: &EntitySignature, consts: &mut Consts) -> String {
+    let mut value = "EntitySignatures {\n    methods:    &[\n".to_string();
+    for (name, params) in &entity.methods {
+        let params = params
+            .iter()
+            .map(|ty| render_type(ty, consts))
+            .collect::<Vec<_>>();
+        value.push_str(&format!("        (\"{name}\", &[{}]),\n", params.join(", ")));
+    }
+    value.push_str("    ],\n    properties: &[\n");
+    for (name, ty) in &entity.props {
+        value.push_str(&format!("        (\"{name}\", {}),\n", render_type(ty, consts)));
+    }
+    value.push_str("    ],\n}");
+
+    consts.intern(&screaming_snake_case(&entity.name), "EntitySignatures", value)
+}
+
+/// `AreaDestructibles` => `AREA_DESTRUCTIBLES`
+fn screaming_snake_case(name: &str) -> String {
+    let mut output = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            output.push('_');
+        }
+        output.push(c.to_ascii_uppercase());
+    }
+
+    output
+}
+
+/// `pub static NAME: phf::Map<&str, &[ty]> = phf_map! { "version" => &[value, ...], ... };`
+/// Versions for which `values` returns `None` are left out
+fn render_map(
+    name: &str, ty: &str, definitions: &[VersionDefinitions],
+    mut values: impl FnMut(&VersionDefinitions) -> Option<Vec<String>>,
+) -> String {
+    let mut output = format!("pub static {name}: phf::Map<&str, &[{ty}]> = phf_map! {{\n");
+
+    for defs in definitions {
+        let Some(mut values) = values(defs) else {
+            continue;
+        };
+
+        let overrides = OVERRIDES
+            .iter()
+            .filter(|table_override| table_override.table == name && table_override.version == defs.version);
+        for table_override in overrides {
+            output.push_str(&format!("    // {}\n", table_override.comment));
+
+            for (index, value) in table_override.names {
+                if let Some(old_value) = values.get_mut(*index) {
+                    *old_value = format!("\"{value}\"");
+                }
+            }
+        }
+
+        output.push_str(&format!(
+            "    \"{}\" => &[{}],\n",
+            utils::version_as_string(defs.version),
+            values.join(", ")
+        ));
+    }
+    output.push_str("};\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same as `gen_entity_defs --check`
+    #[test]
+    fn checked_in_tables_are_up_to_date() {
+        let def_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/definitions");
+
+        for (file_name, contents) in generate(def_dir).unwrap() {
+            let checked_in = std::fs::read_to_string(default_output_dir().join(file_name)).unwrap();
+
+            assert!(
+                checked_in == contents,
+                "{file_name} is out of date, run `just gen-defs`"
+            );
+        }
+    }
+}
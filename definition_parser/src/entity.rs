@@ -12,6 +12,7 @@ use super::{Size, TypeAliasLookup};
 pub struct Entity {
     _name:        String,
     version:      [u16; 4],
+    def_dir:      String,
     type_aliases: Rc<TypeAliasLookup>,
 
     _volatiles: Vec<Property>,
@@ -29,6 +30,16 @@ pub struct Property {
     pub flag: String,
 }
 
+impl Property {
+    /// Whether the server sends this property to the client. Only these properties have an id
+    pub fn is_client_property(&self) -> bool {
+        matches!(
+            self.flag.as_str(),
+            "ALL_CLIENTS" | "OTHER_CLIENTS" | "OWN_CLIENT" | "BASE_AND_CLIENT" | "CELL_PUBLIC_AND_OWN"
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Method {
     name:                 String,
@@ -52,9 +63,17 @@ impl Method {
 
 impl Entity {
     pub fn new(name: &str, version: [u16; 4], type_aliases: Rc<TypeAliasLookup>) -> Result<Self> {
+        Self::load(&crate::utils::get_definitions_root(), name, version, type_aliases)
+    }
+
+    /// Same as [Entity::new] but the `.def` files are read from `def_dir` instead of `DEF_DIR`
+    pub fn load(
+        def_dir: &str, name: &str, version: [u16; 4], type_aliases: Rc<TypeAliasLookup>,
+    ) -> Result<Self> {
         let mut entity = Entity {
             _name: name.to_string(),
             version,
+            def_dir: def_dir.to_string(),
             type_aliases,
             _volatiles: Vec::new(),
             properties: Vec::new(),
@@ -63,13 +82,10 @@ impl Entity {
             base_methods: Vec::new(),
         };
 
-        entity.parse_def_file(get_def_file_path(version, name, false))?;
+        entity.parse_def_file(get_def_file_path(def_dir, version, name, false))?;
         entity.client_methods.sort_by_key(|a| a.get_size());
         entity.properties.sort_by_key(|a| a.get_size());
 
-        for method in &entity.client_methods {
-            println!("{} {:?}: {}", name, version, method.get_size())
-        }
         Ok(entity)
     }
 
@@ -79,11 +95,17 @@ impl Entity {
         Some(&method.name)
     }
 
+    /// Properties that are sent to the client, in the order of their ids
+    pub fn client_properties(&self) -> impl Iterator<Item = &Property> {
+        self.properties
+            .iter()
+            .filter(|property| property.is_client_property())
+    }
+
     fn parse_def_file(&mut self, path: String) -> Result<()> {
-        println!("{:?}", path);
-        let xml_string = std::fs::read_to_string(path)?;
-        let document = Document::parse(&xml_string).unwrap();
-        let root = document.root().first_child().unwrap();
+        let xml_string = std::fs::read_to_string(&path)?;
+        let document = Document::parse(&xml_string).map_err(|err| invalid_data(format!("{path}: {err}")))?;
+        let root = document.root_element();
 
         if let Some(implements) = select_child("Implements", &root) {
             parse_interfaces(self, implements)?;
@@ -115,14 +137,35 @@ impl Entity {
     }
 }
 
+/// Names of the entities that exist on both the client and the server, as listed in `entities.xml`. The
+/// entity type id found in replays is the (1-based) position in this list
+pub fn client_server_entities(def_dir: &str, version: [u16; 4]) -> Result<Vec<String>> {
+    let path = format!("{def_dir}/{}/entities.xml", utils::version_as_string(version));
+
+    let xml_string = std::fs::read_to_string(&path)?;
+    let document = Document::parse(&xml_string).map_err(|err| invalid_data(format!("{path}: {err}")))?;
+    let root = document.root_element();
+    let entities = select_child("ClientServerEntities", &root)
+        .ok_or_else(|| invalid_data(format!("{path}: missing <ClientServerEntities>")))?;
+
+    Ok(entities
+        .children()
+        .filter(XMLNode::is_element)
+        .map(|entity| entity.tag_name().name().to_string())
+        .collect())
+}
+
 fn parse_interfaces(entity: &mut Entity, node: XMLNode) -> Result<()> {
     for child in node.children().filter(XMLNode::is_element) {
         if is_interface(&child) {
-            let interface_path = get_def_file_path(entity.version, get_interface_name(&child), true);
+            let interface_path =
+                get_def_file_path(&entity.def_dir, entity.version, get_interface_name(&child), true);
 
             entity.parse_def_file(interface_path)?;
         } else {
-            panic!("<Implements> may only contain <Interfaces>");
+            return Err(invalid_data(
+                "<Implements> may only contain <Interfaces>".to_string(),
+            ));
         }
     }
 
@@ -132,13 +175,14 @@ fn parse_interfaces(entity: &mut Entity, node: XMLNode) -> Result<()> {
 fn parse_properties(entity: &mut Entity, node: XMLNode) -> Result<()> {
     for child in node.children().filter(XMLNode::is_element) {
         let name = child.tag_name().name().to_string();
-        let flag = select_child("Flags", &child).unwrap();
-        let ty = select_child("Type", &child).unwrap();
+        let missing = |tag| invalid_data(format!("property {name} has no <{tag}>"));
+        let flag = select_child("Flags", &child).ok_or_else(|| missing("Flags"))?;
+        let ty = select_child("Type", &child).ok_or_else(|| missing("Type"))?;
 
         let property = Property {
             name,
             ty: entity.type_aliases.parse_type(&ty)?,
-            flag: flag.text().unwrap().trim().to_string(),
+            flag: flag.text().unwrap_or_default().trim().to_string(),
         };
 
         entity.properties.push(property)
@@ -168,6 +212,14 @@ fn parse_method(node: XMLNode, type_alias_dict: &TypeAliasLookup) -> Result<Meth
             params.push(ty);
             continue;
         }
+        // Named arguments: `<Args> <name> TYPE </name> ... </Args>`
+        if is_args(&child) {
+            for arg in child.children().filter(XMLNode::is_element) {
+                params.push(type_alias_dict.parse_type(&arg)?);
+            }
+
+            continue;
+        }
         if is_variable_header_size(&child) {
             variable_header_size = Some(parse_variable_header_size(&child)?);
 
@@ -214,18 +266,30 @@ fn is_arg(node: &XMLNode) -> bool {
     tag_name == "Arg"
 }
 
+fn is_args(node: &XMLNode) -> bool {
+    let tag_name = node.tag_name().name();
+
+    tag_name == "Args"
+}
+
 /// Get file path of `.def` for a particular version of an entity / interface.
-/// We force a rule that all paths be lowercase because the casing was not consistent in originial WoT files.
+/// We force a rule that all paths inside `def_dir` be lowercase because the casing was not consistent in
+/// originial WoT files.
 ///
 /// For ex. in WoT `v0.9.15`, everything was lowercase (ex: `avatar.def`) then in later versions it was
 /// `Avatar.def`
-fn get_def_file_path(version: [u16; 4], name: &str, is_interface: bool) -> String {
+fn get_def_file_path(def_dir: &str, version: [u16; 4], name: &str, is_interface: bool) -> String {
     let game_version = utils::version_as_string(version);
-    let def_dir = crate::utils::get_definitions_root();
 
-    if is_interface {
-        format!("{def_dir}/{game_version}/interfaces/{name}.def").to_lowercase()
+    let relative_path = if is_interface {
+        format!("{game_version}/interfaces/{name}.def")
     } else {
-        format!("{def_dir}/{game_version}/{name}.def").to_lowercase()
-    }
+        format!("{game_version}/{name}.def")
+    };
+
+    format!("{def_dir}/{}", relative_path.to_lowercase())
+}
+
+pub(crate) fn invalid_data(err: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, err)
 }
@@ -1,9 +1,13 @@
 mod entity;
 mod types;
 pub mod utils;
+mod version;
 
-pub use entity::{Entity, Method, Property};
+pub use entity::{client_server_entities, Entity, Method, Property};
 pub use types::{OpaqueType, TypeAliasLookup, WotType};
+pub use version::{
+    find_versions, EntitySignature, EntityTables, VersionDefinitions, ARENA_ENTITIES, TABLES_DIR,
+};
 pub type Result<T> = core::result::Result<T, std::io::Error>;
 
 pub trait Size {
@@ -15,19 +19,24 @@ pub trait Size {
 // Implementations of the trait `Size`
 //////////////////////////////////////////////////////////////////////////////////////////////
 ///
+/// Types whose size is not fixed all have the size `u16::MAX` so that they keep the order of the definition
+/// files when they are sorted by size
 impl Size for WotType {
     fn get_size(&self) -> u64 {
-        match self {
+        let size = match self {
             WotType::OpaqueType(ty) => ty.get_size(),
             WotType::Array(_) => u16::MAX as u64,
+            WotType::FixedArray(ty, len) => ty.get_size() * *len as u64,
             WotType::FixedDict { is_nullable, dict } => {
                 if *is_nullable {
                     u16::MAX as u64
                 } else {
-                    dict.values().fold(0, |acc, ty| acc + ty.get_size())
+                    dict.iter().fold(0, |acc, (_, ty)| acc + ty.get_size())
                 }
             }
-        }
+        };
+
+        size.min(u16::MAX as u64)
     }
 }
 
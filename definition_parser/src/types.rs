@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use roxmltree::{Document, Node as XMLNode};
 
+use super::entity::invalid_data;
 use super::utils::{get_definitions_root, select_child};
 use super::Result;
 
@@ -10,9 +11,13 @@ use super::Result;
 pub enum WotType {
     OpaqueType(OpaqueType),
     Array(Box<WotType>),
+
+    /// `ARRAY` or `TUPLE` with a `<size>`. The number of elements is not sent over the network
+    FixedArray(Box<WotType>, usize),
+    /// Fields are in the order they are sent over the network
     FixedDict {
         is_nullable: bool,
-        dict:        HashMap<String, WotType>,
+        dict:        Vec<(String, WotType)>,
     },
 }
 
@@ -34,7 +39,7 @@ pub enum OpaqueType {
     String,
     Vector2,
     Vector3,
-    Vector4, // is this actually present in def files?
+    Vector4,
     Pickle,
     MailBox,
     UserType,
@@ -48,16 +53,20 @@ pub struct TypeAliasLookup {
 
 impl TypeAliasLookup {
     pub fn load(version: [u16; 4]) -> Result<Self> {
-        let def_root = get_definitions_root();
-        let path = format!("{def_root}/{}/alias.xml", utils::version_as_string(version));
+        Self::load_from(&get_definitions_root(), version)
+    }
+
+    /// Same as [TypeAliasLookup::load] but `alias.xml` is read from `def_dir` instead of `DEF_DIR`
+    pub fn load_from(def_dir: &str, version: [u16; 4]) -> Result<Self> {
+        let path = format!("{def_dir}/{}/alias.xml", utils::version_as_string(version));
 
-        let xml_string = std::fs::read_to_string(path)?;
-        let document = Document::parse(&xml_string).unwrap();
-        let root = document.root().first_child().unwrap();
+        let xml_string = std::fs::read_to_string(&path)?;
+        let document = Document::parse(&xml_string).map_err(|err| invalid_data(format!("{path}: {err}")))?;
+        let root = document.root_element();
 
         let mut type_alias_dict = TypeAliasLookup { dict: HashMap::new() };
         for node in root.children().filter(XMLNode::is_element) {
-            type_alias_dict.parse_type_alias(&node).unwrap();
+            type_alias_dict.parse_type_alias(&node)?;
         }
 
         Ok(type_alias_dict)
@@ -65,10 +74,10 @@ impl TypeAliasLookup {
 
     fn parse_type_alias(&mut self, node: &XMLNode) -> Result<()> {
         let type_name = node.tag_name().name().to_string();
-        let ty = node.text().unwrap();
+        let ty = node.text().unwrap_or_default();
 
         if ty.contains("FIXED_DICT") {
-            let dict = self.parse_dict_type(node);
+            let dict = self.parse_dict_type(node)?;
 
             let mut is_nullable = false;
             if let Some(allow_none) = select_child("AllowNone", node) {
@@ -90,20 +99,22 @@ impl TypeAliasLookup {
         Ok(())
     }
 
-    fn parse_dict_type(&self, node: &XMLNode) -> HashMap<String, WotType> {
-        let properties = select_child("Properties", node).unwrap();
+    fn parse_dict_type(&self, node: &XMLNode) -> Result<Vec<(String, WotType)>> {
+        let properties = select_child("Properties", node)
+            .ok_or_else(|| invalid_data(format!("FIXED_DICT without <Properties>: {node:?}")))?;
 
-        self.parse_properties(&properties).unwrap()
+        self.parse_properties(&properties)
     }
 
-    fn parse_properties(&self, node: &XMLNode) -> Result<HashMap<String, WotType>> {
-        let mut dict = HashMap::new();
+    fn parse_properties(&self, node: &XMLNode) -> Result<Vec<(String, WotType)>> {
+        let mut dict = Vec::new();
 
         for child in node.children().filter(XMLNode::is_element) {
             let name = child.tag_name().name().to_string();
-            let ty = select_child("Type", &child).unwrap();
+            let ty = select_child("Type", &child)
+                .ok_or_else(|| invalid_data(format!("property without <Type>: {name}")))?;
 
-            dict.insert(name, self.parse_type(&ty)?);
+            dict.push((name, self.parse_type(&ty)?));
         }
 
         Ok(dict)
@@ -111,17 +122,28 @@ impl TypeAliasLookup {
 
     /// Parse nodes like `<Type>    ARRAY      <of> INT32    </of> </Type>`
     pub fn parse_type(&self, node: &XMLNode) -> Result<WotType> {
-        let ty = node.text().unwrap().trim();
+        let ty = node.text().unwrap_or_default().trim();
 
         match ty {
             "ARRAY" | "TUPLE" => {
-                let child_type = select_child("of", node).unwrap();
-                let child_type = self.parse_type(&child_type)?;
-
-                Ok(WotType::Array(Box::new(child_type)))
+                let child_type = select_child("of", node)
+                    .ok_or_else(|| invalid_data(format!("{ty} without <of>: {node:?}")))?;
+                let child_type = Box::new(self.parse_type(&child_type)?);
+
+                match select_child("size", node) {
+                    Some(size) => {
+                        let size = size.text().unwrap_or_default().trim();
+                        let size = size
+                            .parse()
+                            .map_err(|_| invalid_data(format!("invalid size of {ty}: {size}")))?;
+
+                        Ok(WotType::FixedArray(child_type, size))
+                    }
+                    None => Ok(WotType::Array(child_type)),
+                }
             }
             "FIXED_DICT" => {
-                let dict = self.parse_dict_type(node);
+                let dict = self.parse_dict_type(node)?;
 
                 let mut is_nullable = false;
                 if let Some(allow_none) = select_child("AllowNone", node) {
@@ -132,7 +154,7 @@ impl TypeAliasLookup {
                 Ok(WotType::FixedDict { is_nullable, dict })
             }
             _ => {
-                let type_as_text = node.text().unwrap();
+                let type_as_text = node.text().unwrap_or_default();
                 let ty = type_from_str(type_as_text, self)?;
 
                 Ok(WotType::OpaqueType(ty))
@@ -162,6 +184,7 @@ fn type_from_str(s: &str, type_lookup: &TypeAliasLookup) -> Result<OpaqueType> {
         "STRING" => Ok(String),
         "VECTOR2" => Ok(Vector2),
         "VECTOR3" => Ok(Vector3),
+        "VECTOR4" => Ok(Vector4),
         "PYTHON" => Ok(Pickle),
         "MAILBOX" => Ok(MailBox),
         "USER_TYPE" => Ok(UserType),
@@ -169,7 +192,7 @@ fn type_from_str(s: &str, type_lookup: &TypeAliasLookup) -> Result<OpaqueType> {
             if let Some(alias) = type_lookup.dict.get(s) {
                 Ok(Alias(Box::new(alias.clone())))
             } else {
-                panic!("cannot find alias type: {}", s)
+                Err(invalid_data(format!("cannot find alias type: {s}")))
             }
         }
     }
@@ -8,6 +8,33 @@ pub fn select_child<'a, 'b>(tag_name: &'static str, parent: &'a XMLNode<'a, 'b>)
         .find(|child| child.tag_name().name() == tag_name)
 }
 
+/// `DEF_DIR` environment variable, or the `definitions` directory of this crate if it is not set
 pub fn get_definitions_root() -> String {
-    std::env::var("DEF_DIR").unwrap_or_else(|_| "../definition_parser/definitions".to_string())
+    std::env::var("DEF_DIR")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/definitions").to_string())
+}
+
+/// Name of a version directory in the definitions tree to version. `1_20_0_0` => `[1, 20, 0, 0]`
+pub fn parse_version_dir(name: &str) -> Option<[u16; 4]> {
+    let mut version = [0; 4];
+    let mut parts = name.split('_');
+
+    for part in version.iter_mut() {
+        *part = parts.next()?.parse().ok()?;
+    }
+
+    parts.next().is_none().then_some(version)
+}
+
+/// Names in the `.def` files are in camelCase while the parser uses PascalCase, for ex.
+/// `onHealthChanged` => `OnHealthChanged`. Underscores are removed: `messenger_onActionByServer_chat2` =>
+/// `MessengerOnActionByServerChat2`
+pub fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+
+            chars.next().into_iter().flat_map(char::to_uppercase).chain(chars)
+        })
+        .collect()
 }
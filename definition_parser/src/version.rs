@@ -0,0 +1,194 @@
+use std::path::Path;
+use std::rc::Rc;
+
+use super::entity::{client_server_entities, Entity};
+use super::utils::{parse_version_dir, to_pascal_case};
+use super::{Result, TypeAliasLookup, WotType};
+
+/// Entities of the arena, other than `Avatar` and `Vehicle`, the replay parser has tables for
+pub const ARENA_ENTITIES: &[&str] = &[
+    "AreaDestructibles",
+    "DestructibleEntity",
+    "SectorBase",
+    "Sector",
+    "ProtectionZone",
+    "BasicMine",
+    "StepRepairPoint",
+    "EventPointsPickup",
+];
+
+/// The tables the replay parser needs for one version of the game. Names are in PascalCase and each list is
+/// in the order of the ids found in replays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDefinitions {
+    pub version: [u16; 4],
+
+    /// Entity types in the order of their (1-based) entity type id. `None` if the version has no
+    /// `entities.xml`
+    pub entity_types: Option<Vec<String>>,
+
+    pub avatar_methods:  Vec<String>,
+    pub avatar_props:    Vec<String>,
+    pub vehicle_methods: Vec<String>,
+    pub vehicle_props:   Vec<String>,
+
+    /// Tables of the entities of [ARENA_ENTITIES] that exist in this version. Empty if the entity types are
+    /// not known or if the version has no `.def` files
+    pub arena_entities: Vec<EntityTables>,
+
+    /// `false` for the versions we only have the tables of (see [TABLES_DIR]). These have no signatures and
+    /// no tables for the arena entities
+    pub has_def_files: bool,
+}
+
+/// Directory of a version with the tables of `Avatar` and `Vehicle`, one file per table with one name per
+/// line (ex. `tables/avatar_methods.txt`). Used instead of the `.def` files for the versions we do not have
+/// the definition files of
+pub const TABLES_DIR: &str = "tables";
+
+/// Names of the client methods and properties of an entity, in the order of their ids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityTables {
+    pub name:    String,
+    pub methods: Vec<String>,
+    pub props:   Vec<String>,
+}
+
+impl VersionDefinitions {
+    /// Load the definitions of `version` from `def_dir/<version>`. The tables are read from [TABLES_DIR] if
+    /// the version has one
+    pub fn load(def_dir: &str, version: [u16; 4]) -> Result<Self> {
+        let version_dir = Path::new(def_dir).join(utils::version_as_string(version));
+        let entity_types = if version_dir.join("entities.xml").exists() {
+            Some(client_server_entities(def_dir, version)?)
+        } else {
+            None
+        };
+
+        let tables_dir = version_dir.join(TABLES_DIR);
+        if tables_dir.is_dir() {
+            let read_table = |name: &str| -> Result<Vec<String>> {
+                let names = std::fs::read_to_string(tables_dir.join(format!("{name}.txt")))?;
+
+                Ok(names.lines().map(str::to_string).collect())
+            };
+
+            return Ok(VersionDefinitions {
+                version,
+                entity_types,
+                avatar_methods: read_table("avatar_methods")?,
+                avatar_props: read_table("avatar_props")?,
+                vehicle_methods: read_table("vehicle_methods")?,
+                vehicle_props: read_table("vehicle_props")?,
+                arena_entities: Vec::new(),
+                has_def_files: false,
+            });
+        }
+
+        let type_aliases = Rc::new(TypeAliasLookup::load_from(def_dir, version)?);
+        let avatar = Entity::load(def_dir, "Avatar", version, type_aliases.clone())?;
+        let vehicle = Entity::load(def_dir, "Vehicle", version, type_aliases.clone())?;
+
+        let method_names = |entity: &Entity| {
+            entity
+                .client_methods
+                .iter()
+                .map(|method| to_pascal_case(method.get_name()))
+                .collect()
+        };
+        let property_names = |entity: &Entity| {
+            entity
+                .client_properties()
+                .map(|property| to_pascal_case(&property.name))
+                .collect()
+        };
+
+        let mut arena_entities = Vec::new();
+        for name in ARENA_ENTITIES {
+            let exists = entity_types
+                .iter()
+                .flatten()
+                .any(|entity_type| entity_type == name);
+            if !exists {
+                continue;
+            }
+
+            let entity = Entity::load(def_dir, name, version, type_aliases.clone())?;
+            arena_entities.push(EntityTables {
+                name:    name.to_string(),
+                methods: method_names(&entity),
+                props:   property_names(&entity),
+            });
+        }
+
+        Ok(VersionDefinitions {
+            version,
+            entity_types,
+            avatar_methods: method_names(&avatar),
+            avatar_props: property_names(&avatar),
+            vehicle_methods: method_names(&vehicle),
+            vehicle_props: property_names(&vehicle),
+            arena_entities,
+            has_def_files: true,
+        })
+    }
+}
+
+/// Types of the arguments of the client methods and of the client properties of an entity, in the order of
+/// their ids. Names are in PascalCase
+#[derive(Debug, Clone)]
+pub struct EntitySignature {
+    pub name:    String,
+    pub methods: Vec<(String, Vec<WotType>)>,
+    pub props:   Vec<(String, WotType)>,
+}
+
+impl EntitySignature {
+    /// Signatures of the entities of `names` whose `.def` file in `def_dir/<version>` can be read. The others
+    /// are left out (some entities of the hangar have `.def` files that are not valid XML)
+    pub fn load_all(def_dir: &str, version: [u16; 4], names: &[String]) -> Result<Vec<Self>> {
+        let type_aliases = Rc::new(TypeAliasLookup::load_from(def_dir, version)?);
+
+        let mut signatures = Vec::new();
+        for name in names {
+            let Ok(entity) = Entity::load(def_dir, name, version, type_aliases.clone()) else {
+                continue;
+            };
+            let methods = entity
+                .client_methods
+                .iter()
+                .map(|method| (to_pascal_case(method.get_name()), method.get_params().to_vec()))
+                .collect();
+            let props = entity
+                .client_properties()
+                .map(|property| (to_pascal_case(&property.name), property.ty.clone()))
+                .collect();
+
+            signatures.push(EntitySignature {
+                name: name.clone(),
+                methods,
+                props,
+            });
+        }
+
+        Ok(signatures)
+    }
+}
+
+/// Versions that have a directory in `def_dir`, from oldest to newest
+pub fn find_versions(def_dir: &str) -> Result<Vec<[u16; 4]>> {
+    let mut versions = Vec::new();
+
+    for entry in std::fs::read_dir(def_dir)? {
+        let entry = entry?;
+
+        if let Some(version) = parse_version_dir(&entry.file_name().to_string_lossy()) {
+            if entry.file_type()?.is_dir() {
+                versions.push(version);
+            }
+        }
+    }
+    versions.sort();
+
+    Ok(versions)
+}
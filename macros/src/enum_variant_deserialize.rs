@@ -13,7 +13,9 @@ pub fn imp_enum_variant_deserialize_macro(ast: &syn::DeriveInput) -> TokenStream
         _ => panic!("expected an enum"),
     };
 
-    let match_statements = variants.into_iter().map(|variant| {
+    let variant_names = variants.iter().map(|variant| &variant.ident);
+
+    let match_statements = variants.into_iter().enumerate().map(|(index, variant)| {
         let attrs: Vec<_> = variant.attrs.iter().filter_map(get_args).collect();
         let name = &variant.ident;
         let right_side = match &variant.fields {
@@ -22,7 +24,7 @@ pub fn imp_enum_variant_deserialize_macro(ast: &syn::DeriveInput) -> TokenStream
                 if unnamed.len() > 1 {
                     panic!("Enum variant can only have one field");
                 }
-                match attrs.get(0){
+                match attrs.first(){
                     Some(s) if s == "delegate" => quote!(Ok(Self::#name(crate::packet_parser::prelude::from_slice(input, context.get_version())?))),
                     Some(s) if s == "manual" => quote!(unreachable!("You should've specified manual parser for {}", stringify!(#name))),
                     Some(_) => panic!("Unknown args"),
@@ -33,21 +35,48 @@ pub fn imp_enum_variant_deserialize_macro(ast: &syn::DeriveInput) -> TokenStream
         };
 
         quote! {
-            stringify!(#name) => #right_side,
+            #index => #right_side,
         }
     });
 
+    let unit_variants = variants
+        .into_iter()
+        .filter(|variant| matches!(variant.fields, syn::Fields::Unit))
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let has_payload = if unit_variants.is_empty() {
+        quote!(true)
+    } else {
+        quote!(!matches!(self, #(Self::#unit_variants)|*))
+    };
+
     let gen = quote! {
         impl VariantDeserializer for #enum_name {
             fn deserialize_variant(discrim: &'static str, input: &[u8], context: &crate::Context) -> core::result::Result<Self, crate::PacketError>
             where
                 Self: Sized
             {
-                match discrim {
+                const VARIANTS: &[&str] = &[#(stringify!(#variant_names)),*];
+
+                match VARIANTS.iter().position(|variant| *variant == discrim) {
+                    Some(variant) => Self::deserialize_variant_at(variant, input, context),
+                    None => panic!("{} is not found in match statement", discrim)
+                }
+            }
+
+            fn deserialize_variant_at(variant: usize, input: &[u8], context: &crate::Context) -> core::result::Result<Self, crate::PacketError>
+            where
+                Self: Sized
+            {
+                match variant {
                     #(#match_statements)*
-                    _ => panic!("{} is not found in match statement", discrim)
+                    _ => panic!("{} has no variant {}", stringify!(#enum_name), variant)
                 }
             }
+
+            fn has_payload(&self) -> bool {
+                #has_payload
+            }
         }
     };
     gen.into()
@@ -3,16 +3,15 @@ use std::path::Path;
 use criterion::BenchmarkId;
 use criterion::{criterion_group, criterion_main, Criterion};
 
+const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input_files");
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("Replay: Parse JSON Only", |b| b.iter(parse_json_only));
     c.bench_function("Replay: Parse in Entirety", |b| b.iter(parse_entire_replay));
 }
 
 fn parse_json_only() {
-    let files = utils::parse_dir(Path::new(
-        "/home/dacite/Projects/wot-battle-results-parser/replay_parser/input_files",
-    ))
-    .unwrap();
+    let files = utils::parse_dir(Path::new(INPUT_DIR)).unwrap();
     for entry in files {
         let file = std::fs::read(entry.path()).unwrap();
         let _result = wot_replay_parser::ReplayParser::parse_json(file).unwrap();
@@ -20,10 +19,7 @@ fn parse_json_only() {
 }
 
 fn parse_entire_replay() {
-    let files = utils::parse_dir(Path::new(
-        "/home/dacite/Projects/wot-battle-results-parser/replay_parser/input_files",
-    ))
-    .unwrap();
+    let files = utils::parse_dir(Path::new(INPUT_DIR)).unwrap();
     for entry in files {
         let file = std::fs::read(entry.path()).unwrap();
         let _result = wot_replay_parser::ReplayParser::parse(file).unwrap();
@@ -32,12 +28,19 @@ fn parse_entire_replay() {
 
 fn parse_events(bin_stream: &[u8]) {
     let parser = wot_replay_parser::ReplayParser::parse(bin_stream.to_vec()).unwrap();
+    parse_events_only(&parser);
+}
+
+fn parse_events_only(parser: &wot_replay_parser::ReplayParser) {
     let event_stream = parser.event_stream().unwrap();
     let _events: Vec<_> = event_stream.into_iter().collect();
 }
 
 pub fn criterion_benchmark_events(c: &mut Criterion) {
-    let file = std::fs::read("/home/dacite/Projects/wot-battle-results-parser/replay_parser/input_files/20220312_2330_uk-GB98_T95_FV4201_Chieftain_59_asia_great_wall.wotreplay").unwrap();
+    let file = std::fs::read(Path::new(INPUT_DIR).join("example.wotreplay")).unwrap();
+
+    // Decrypting and inflating the packets is done once here, so that only the event parsing is measured
+    let parser = wot_replay_parser::ReplayParser::parse(file.clone()).unwrap();
 
     let mut group = c.benchmark_group("Event parsing overhead");
     group.sample_size(100);
@@ -48,6 +51,13 @@ pub fn criterion_benchmark_events(c: &mut Criterion) {
             bencher.iter(|| parse_events(input));
         },
     );
+    group.bench_with_input(
+        BenchmarkId::new("input_example", "events_only"),
+        &parser,
+        |bencher, input| {
+            bencher.iter(|| parse_events_only(input));
+        },
+    );
     group.finish();
 }
 
@@ -0,0 +1,798 @@
+//! Replaces player names, clan tags and account IDs of a replay with pseudonyms. The pseudonyms are derived
+//! from a seed and the original value only, so the same player gets the same alias in every replay
+//! anonymized with the same seed.
+
+mod pickle;
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use miniz_oxide::inflate::decompress_to_vec_zlib;
+use nom::number::complete::le_i64;
+use serde_json::Value as JsonVal;
+
+use self::pickle::{pickle_int, PickleNode};
+use crate::entity_defs::EntityType;
+use crate::events::{
+    find_property_value, ArenaUpdateData, AvatarMethods, EntityCreate, EntityMethod, EntityMethodEvent,
+    UpdateArena, VehicleData,
+};
+use crate::packet_parser::{from_slice_unchecked, parse_byte_array, write_byte_array, METADATA_SIZE};
+use crate::{BattleEvent, Context, Packet, PacketError, ReplayError, ReplayParser, ReplayWriter};
+
+/// Level used when compressing the rewritten pickles
+const COMPRESSION_LEVEL: u8 = 6;
+
+/// JSON keys whose value is a player name
+const NAME_KEYS: [&str; 4] = ["name", "fakeName", "realName", "playerName"];
+
+/// JSON keys whose value is an account ID
+const ACCOUNT_ID_KEYS: [&str; 2] = ["accountDBID", "playerID"];
+
+/// Smallest account ID alias. Keeps aliases of ordinary account IDs pickled as 4 byte ints
+const MIN_ID_ALIAS: i64 = 1 << 24;
+
+/// Avatar methods that carry the account ID of the sender
+const CHAT_ACTIONS: [&str; 2] = ["OnChatAction", "MessengerOnActionByServerChat2"];
+
+/// Position of the account ID of the sender in the arguments of `OnChatAction`, after the request id, the
+/// action, the response, the two timestamps and the channel. The name of the sender follows it
+const CHAT_ACTION_ORIGINATOR: usize = 30;
+
+/// Position of `int64_arg1` in the arguments of `MessengerOnActionByServerChat2`, after the action id, the
+/// request id and `int32_arg1`
+const CHAT_COMMAND_SENDER: usize = 13;
+
+/// Positions in the vehicle info tuples of `VehicleList`, `VehicleAdded` and `VehicleUpdated`
+const VEHICLE_NAME: usize = 2;
+const VEHICLE_ACCOUNT_DBID: usize = 7;
+const VEHICLE_CLAN_ABBREV: usize = 8;
+const VEHICLE_CLAN_DBID: usize = 9;
+
+/// Scrubs player identities from a replay. Names, clan tags, account IDs and clan IDs are rewritten in:
+/// - the JSON blocks (`vehicles` map, `players`, `avatars`, `personal` etc.)
+/// - `AvatarCreate.name`
+/// - the `name` in the public info of vehicles created by `EntityCreate`
+/// - the `VehicleList`, `VehicleAdded` and `VehicleUpdated` arena updates
+/// - `Chat` messages
+/// - the account ID and the name of the sender of `OnChatAction`
+/// - the account ID of the sender of `MessengerOnActionByServerChat2`
+///
+/// Only these fields are rewritten, at the position the packet format gives them, so the output can still
+/// be played by the game client. Anonymization fails if a packet fails to parse or has an identity that
+/// cannot be located (ex: the properties of vehicles in a version we do not have the definitions of), since
+/// we cannot tell whether such a packet has an identity. Chat actions are the exception: their sender is
+/// replaced even if the rest of the packet cannot be parsed.
+/// ## Example
+/// ```
+/// # use wot_replay_parser::*;
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+///
+/// let anonymizer = ReplayAnonymizer::new(42);
+/// let bytes = anonymizer.anonymize(&replay).unwrap().to_bytes().unwrap();
+///
+/// let anonymized = ReplayParser::parse(bytes).unwrap();
+/// let player_name = anonymized.replay_json_start().unwrap()["playerName"].as_str().unwrap();
+/// assert_eq!(player_name, anonymizer.alias_name("_Dacite"));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayAnonymizer {
+    seed: u64,
+}
+
+impl ReplayAnonymizer {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Pseudonym used for the player `name`
+    pub fn alias_name(&self, name: &str) -> String {
+        format!("Player_{:08x}", self.hash(b"name", name.as_bytes()) as u32)
+    }
+
+    /// Pseudonym used for the clan tag `clan`. Players without a clan keep the empty tag
+    pub fn alias_clan(&self, clan: &str) -> String {
+        if clan.is_empty() {
+            return String::new();
+        }
+
+        format!("C{:04X}", self.hash(b"clan", clan.as_bytes()) as u16)
+    }
+
+    /// Pseudonym used for the account ID `account_id`
+    pub fn alias_account_id(&self, account_id: i64) -> i64 {
+        self.alias_id(b"account", account_id)
+    }
+
+    /// Pseudonym used for the clan ID `clan_id`. `0` (no clan) is kept as is
+    pub fn alias_clan_id(&self, clan_id: i64) -> i64 {
+        self.alias_id(b"clan_id", clan_id)
+    }
+
+    /// Anonymize `replay`. The packets must be loaded
+    pub fn anonymize(&self, replay: &ReplayParser) -> Result<ReplayWriter, ReplayError> {
+        let mut identities = Identities::new(*self);
+        for json in replay.replay_json() {
+            identities.collect_json(json);
+        }
+
+        let mut packets_buffer = Vec::with_capacity(replay.packets_buffer()?.len());
+        let mut context = replay.context()?;
+        for packet in replay.packet_stream() {
+            let packet = packet?;
+
+            let anonymized = identities
+                .anonymize_packet(&packet, &mut context)
+                .map_err(|error| ReplayError::PacketParseError {
+                    packet_id: packet.id(),
+                    packet_type: packet.packet_type(),
+                    error,
+                })?;
+
+            match anonymized {
+                Some(payload) => {
+                    packets_buffer.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+                    packets_buffer.extend_from_slice(&packet.inner()[4..METADATA_SIZE]);
+                    packets_buffer.extend_from_slice(&payload);
+                }
+                None => packets_buffer.extend_from_slice(packet.inner()),
+            }
+        }
+
+        // Done last so that identities only found in the packets are also replaced
+        let json: Vec<JsonVal> = replay
+            .replay_json()
+            .iter()
+            .map(|json| {
+                let mut json = json.clone();
+                identities.anonymize_json(&mut json);
+                json
+            })
+            .collect();
+
+        let mut writer = ReplayWriter::new();
+        writer.set_json(&json)?.set_packets_buffer(packets_buffer);
+
+        Ok(writer)
+    }
+
+    /// Keep the alias in the same range as the original so that both take the same number of bytes when
+    /// pickled
+    fn alias_id(&self, domain: &[u8], id: i64) -> i64 {
+        if id <= 0 {
+            return id;
+        }
+
+        let hash = self.hash(domain, &id.to_le_bytes()) as i64 & i64::MAX;
+        if id <= i32::MAX as i64 {
+            MIN_ID_ALIAS + hash % (i32::MAX as i64 - MIN_ID_ALIAS)
+        } else {
+            (1 << 31) + hash % (1 << 31)
+        }
+    }
+
+    /// FNV-1a. Unlike `std` hashers, its output is guaranteed to be the same across builds
+    fn hash(&self, domain: &[u8], value: &[u8]) -> u64 {
+        let seed = self.seed.to_le_bytes();
+
+        let input = seed.iter().chain(domain).chain(&[0xFF]).chain(value);
+        input.fold(0xcbf29ce484222325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+}
+
+/// Identities found in a replay along with their pseudonyms
+struct Identities {
+    anonymizer:  ReplayAnonymizer,
+    names:       HashMap<String, String>,
+    clans:       HashMap<String, String>,
+    account_ids: HashMap<i64, i64>,
+    clan_ids:    HashMap<i64, i64>,
+}
+
+impl Identities {
+    fn new(anonymizer: ReplayAnonymizer) -> Self {
+        Self {
+            anonymizer,
+            names: HashMap::new(),
+            clans: HashMap::new(),
+            account_ids: HashMap::new(),
+            clan_ids: HashMap::new(),
+        }
+    }
+
+    fn add_name(&mut self, name: &str) {
+        if !name.is_empty() && !self.names.contains_key(name) {
+            self.names
+                .insert(name.to_string(), self.anonymizer.alias_name(name));
+        }
+    }
+
+    fn add_clan(&mut self, clan: &str) {
+        if !clan.is_empty() && !self.clans.contains_key(clan) {
+            self.clans
+                .insert(clan.to_string(), self.anonymizer.alias_clan(clan));
+        }
+    }
+
+    fn add_account_id(&mut self, account_id: i64) {
+        if account_id > 0 {
+            let alias = self.anonymizer.alias_account_id(account_id);
+            self.account_ids.insert(account_id, alias);
+        }
+    }
+
+    fn add_clan_id(&mut self, clan_id: i64) {
+        if clan_id > 0 {
+            let alias = self.anonymizer.alias_clan_id(clan_id);
+            self.clan_ids.insert(clan_id, alias);
+        }
+    }
+
+    /// Players that joined after the battle started are not always in the JSON
+    fn add_vehicle(&mut self, vehicle: &VehicleData) {
+        self.add_name(&vehicle.name);
+        self.add_clan(&vehicle.clan_abbrev);
+        self.add_account_id(vehicle.account_dbid);
+        self.add_clan_id(vehicle.clan_dbid);
+    }
+
+    /// Collect identities from the `vehicles` map of the first JSON block (also the second element of the
+    /// second JSON block) and the `players` map of the battle results
+    fn collect_json(&mut self, json: &JsonVal) {
+        match json {
+            JsonVal::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        (key, JsonVal::String(name)) if NAME_KEYS.contains(&key) && is_player(map) => {
+                            self.add_name(name)
+                        }
+                        ("clanAbbrev", JsonVal::String(clan)) => self.add_clan(clan),
+                        (key, JsonVal::Number(id)) if ACCOUNT_ID_KEYS.contains(&key) => {
+                            if let Some(id) = id.as_i64() {
+                                self.add_account_id(id);
+                            }
+                        }
+                        ("clanDBID", JsonVal::Number(id)) => {
+                            if let Some(id) = id.as_i64() {
+                                self.add_clan_id(id);
+                            }
+                        }
+                        ("players", JsonVal::Object(players)) => {
+                            for account_id in players.keys() {
+                                if let Ok(id) = account_id.parse() {
+                                    self.add_account_id(id);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    self.collect_json(value);
+                }
+            }
+            JsonVal::Array(array) => array.iter().for_each(|value| self.collect_json(value)),
+            _ => {}
+        }
+    }
+
+    fn anonymize_json(&self, json: &mut JsonVal) {
+        match json {
+            JsonVal::Object(map) => {
+                let entries = std::mem::take(map);
+                for (key, mut value) in entries {
+                    match (key.as_str(), &mut value) {
+                        (key, JsonVal::String(name)) if NAME_KEYS.contains(&key) => {
+                            if let Some(alias) = self.names.get(name.as_str()) {
+                                *name = alias.clone();
+                            }
+                        }
+                        ("clanAbbrev", JsonVal::String(clan)) => {
+                            if let Some(alias) = self.clans.get(clan.as_str()) {
+                                *clan = alias.clone();
+                            }
+                        }
+                        (key, value) if ACCOUNT_ID_KEYS.contains(&key) => {
+                            if let Some(alias) = value.as_i64().and_then(|id| self.account_ids.get(&id)) {
+                                *value = (*alias).into();
+                            }
+                        }
+                        ("clanDBID", value) => {
+                            if let Some(alias) = value.as_i64().and_then(|id| self.clan_ids.get(&id)) {
+                                *value = (*alias).into();
+                            }
+                        }
+                        _ => self.anonymize_json(&mut value),
+                    }
+
+                    // `players` and `avatars` of the battle results are keyed by account ID
+                    let key = match key.parse().ok().and_then(|id| self.account_ids.get(&id)) {
+                        Some(alias) => alias.to_string(),
+                        None => key,
+                    };
+
+                    map.insert(key, value);
+                }
+            }
+            JsonVal::Array(array) => array.iter_mut().for_each(|value| self.anonymize_json(value)),
+            _ => {}
+        }
+    }
+
+    /// Return the anonymized payload of `packet` or `None` if the packet is kept as is. Fails if the packet
+    /// cannot be parsed or has an identity we cannot locate
+    fn anonymize_packet(
+        &mut self, packet: &Packet, context: &mut Context,
+    ) -> Result<Option<Vec<u8>>, PacketError> {
+        let payload = packet.payload();
+
+        let event = match BattleEvent::parse(packet, context) {
+            Ok(event) => event,
+
+            // The sender must not be left in a chat action we fail to parse. It does not depend on the parts
+            // of the packet we may fail on
+            Err(ReplayError::PacketParseError {
+                error: PacketError::EntityMethodError { method, .. },
+                ..
+            }) if CHAT_ACTIONS.contains(&method) => return self.anonymize_chat_action(payload, method),
+            Err(ReplayError::PacketParseError { error, .. }) => return Err(error),
+            Err(err) => return Err(PacketError::DataError { err: err.to_string() }),
+        };
+
+        match event {
+            BattleEvent::AvatarCreate(avatar_create) => {
+                self.add_name(&avatar_create.name);
+                self.anonymize_avatar_create(payload, &avatar_create.name, context.get_version())
+            }
+            BattleEvent::EntityCreate(entity_create) => {
+                self.anonymize_entity_create(payload, &entity_create, context)
+            }
+            BattleEvent::EntityMethod(EntityMethodEvent {
+                method: EntityMethod::Avatar(method),
+                ..
+            }) => match method {
+                AvatarMethods::UpdateArena(UpdateArena { update_data, .. }) => match update_data {
+                    ArenaUpdateData::VehicleList(vehicles) => {
+                        vehicles.iter().for_each(|vehicle| self.add_vehicle(vehicle));
+                        self.anonymize_vehicle_list(payload)
+                    }
+                    ArenaUpdateData::VehicleAdded(vehicle) | ArenaUpdateData::VehicleUpdated(vehicle) => {
+                        self.add_vehicle(&vehicle);
+                        self.anonymize_vehicle_list(payload)
+                    }
+                    _ => Ok(None),
+                },
+                AvatarMethods::OnChatAction(_) => self.anonymize_chat_action(payload, CHAT_ACTIONS[0]),
+                AvatarMethods::MessengerOnActionByServerChat2(_) => {
+                    self.anonymize_chat_action(payload, CHAT_ACTIONS[1])
+                }
+                _ => Ok(None),
+            },
+            BattleEvent::Chat(_) => Ok(self.anonymize_chat(payload)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The name follows the header of the entity and the size of the rest of the payload
+    fn anonymize_avatar_create(
+        &self, payload: &[u8], name: &str, version: [u16; 4],
+    ) -> Result<Option<Vec<u8>>, PacketError> {
+        let Some(alias) = self.names.get(name) else {
+            return Ok(None);
+        };
+        let (original, replacement) = (packed_string(name), packed_string(alias));
+
+        let size_position = if version >= [0, 9, 14, 0] { 10 } else { 6 };
+        let position = size_position + 4;
+        if payload.get(position..(position + original.len())) != Some(&original[..]) {
+            return Err(PacketError::DataError {
+                err: "name of the avatar not found after its header".to_string(),
+            });
+        }
+
+        let size = u32::from_le_bytes(payload[size_position..position].try_into().unwrap());
+        let size = size as usize + replacement.len() - original.len();
+
+        let mut output = payload[..size_position].to_vec();
+        output.extend_from_slice(&(size as u32).to_le_bytes());
+        output.extend_from_slice(&replacement);
+        output.extend_from_slice(&payload[(position + original.len())..]);
+
+        Ok(Some(output))
+    }
+
+    /// The properties of the entity follow the header of the packet and are preceded by their size. The name
+    /// of a player is the first field of the `PublicInfo` property of their vehicle
+    fn anonymize_entity_create(
+        &mut self, payload: &[u8], entity_create: &EntityCreate, context: &Context,
+    ) -> Result<Option<Vec<u8>>, PacketError> {
+        if context.find_entity_type(entity_create.entity_id)? != EntityType::Vehicle {
+            return Ok(None);
+        }
+
+        let (remaining, _) = from_slice_unchecked::<EntityCreate>(payload, context.get_version())?;
+        let header = &payload[..(payload.len() - remaining.len())];
+        let properties = remaining.get(4..).ok_or_else(|| PacketError::IncompleteInput {
+            err: "size of the properties".to_string(),
+        })?;
+
+        let Some(public_info) = find_property_value(EntityType::Vehicle, properties, "PublicInfo", context)?
+        else {
+            return Ok(None);
+        };
+        let (after_name, name) = parse_byte_array(&properties[public_info.start..])?;
+        let name = String::from_utf8_lossy(name);
+
+        self.add_name(&name);
+        let Some(alias) = self.names.get(name.as_ref()) else {
+            return Ok(None);
+        };
+
+        let mut anonymized = properties[..public_info.start].to_vec();
+        write_byte_array(alias.as_bytes(), &mut anonymized);
+        anonymized.extend_from_slice(after_name);
+
+        let mut output = header.to_vec();
+        output.extend_from_slice(&(anonymized.len() as u32).to_le_bytes());
+        output.extend_from_slice(&anonymized);
+
+        Ok(Some(output))
+    }
+
+    /// Method packet with a compressed pickle of vehicle info tuples: `(vehicle_id, compact_descr, name,
+    /// team, is_alive, is_avatar_ready, is_team_killer, account_dbid, clan_abbrev, clan_dbid, ...)`. Only the
+    /// values at these positions are replaced, the rest of the pickle is kept byte for byte
+    fn anonymize_vehicle_list(&self, payload: &[u8]) -> Result<Option<Vec<u8>>, PacketError> {
+        let (header, args) = split_method(payload)?;
+        let (&update_type, arena_data) = args.split_first().ok_or_else(|| PacketError::IncompleteInput {
+            err: "arena update type".to_string(),
+        })?;
+        let (remaining, compressed) = parse_byte_array(arena_data)?;
+
+        let pickle = decompress_to_vec_zlib(compressed).map_err(|err| PacketError::ConversionError {
+            err: format!("{err:?}"),
+        })?;
+
+        let mut replacements = Vec::new();
+        for vehicle in vehicle_tuples(&pickle::parse(&pickle)?) {
+            self.vehicle_replacements(&pickle, &vehicle, &mut replacements);
+        }
+
+        if replacements.is_empty() {
+            return Ok(None);
+        }
+        let pickle = pickle::patch(&pickle, replacements);
+
+        let mut method_data = vec![update_type];
+        write_byte_array(
+            &compress_to_vec_zlib(&pickle, COMPRESSION_LEVEL),
+            &mut method_data,
+        );
+        method_data.extend_from_slice(remaining);
+
+        Ok(Some(method_payload(header, &method_data)))
+    }
+
+    fn vehicle_replacements(
+        &self, pickle: &[u8], vehicle: &[PickleNode], replacements: &mut Vec<(Range<usize>, Vec<u8>)>,
+    ) {
+        let value = |index| match vehicle.get(index) {
+            Some(PickleNode::Value(range)) => Some(range.clone()),
+            _ => None,
+        };
+
+        for (index, aliases) in [(VEHICLE_NAME, &self.names), (VEHICLE_CLAN_ABBREV, &self.clans)] {
+            let Some(range) = value(index) else { continue };
+            if let Some(alias) = pickle::read_str(pickle, &range).and_then(|value| aliases.get(value)) {
+                let replacement = pickle::pickle_str_like(pickle, &range, alias);
+                replacements.push((range, replacement));
+            }
+        }
+
+        for (index, aliases) in [
+            (VEHICLE_ACCOUNT_DBID, &self.account_ids),
+            (VEHICLE_CLAN_DBID, &self.clan_ids),
+        ] {
+            let Some(range) = value(index) else { continue };
+            if let Some(alias) = pickle::read_int(pickle, &range).and_then(|value| aliases.get(&value)) {
+                replacements.push((range, pickle_int(*alias)));
+            }
+        }
+    }
+
+    /// `OnChatAction` has the account ID and the name of the sender. `MessengerOnActionByServerChat2` has the
+    /// account ID of the sender in `int64_arg1`, which is only replaced if it is the ID of a known account
+    /// since the meaning of the argument depends on the command
+    fn anonymize_chat_action(
+        &mut self, payload: &[u8], method: &str,
+    ) -> Result<Option<Vec<u8>>, PacketError> {
+        let (header, args) = split_method(payload)?;
+        let position = if method == CHAT_ACTIONS[0] {
+            CHAT_ACTION_ORIGINATOR
+        } else {
+            CHAT_COMMAND_SENDER
+        };
+
+        let incomplete = || PacketError::IncompleteInput {
+            err: format!("sender of {method}"),
+        };
+        let (remaining, account_id) = le_i64(args.get(position..).ok_or_else(incomplete)?)?;
+
+        let mut output = args[..position].to_vec();
+        if method == CHAT_ACTIONS[0] {
+            let (remaining, name) = parse_byte_array(remaining)?;
+            let name = String::from_utf8_lossy(name);
+            self.add_account_id(account_id);
+            self.add_name(&name);
+
+            let alias = self
+                .names
+                .get(name.as_ref())
+                .map_or(name.as_ref(), String::as_str);
+            output.extend_from_slice(
+                &self
+                    .account_ids
+                    .get(&account_id)
+                    .unwrap_or(&account_id)
+                    .to_le_bytes(),
+            );
+            write_byte_array(alias.as_bytes(), &mut output);
+            output.extend_from_slice(remaining);
+        } else {
+            let Some(alias) = self.account_ids.get(&account_id) else {
+                return Ok(None);
+            };
+            output.extend_from_slice(&alias.to_le_bytes());
+            output.extend_from_slice(remaining);
+        }
+
+        Ok((output != args).then(|| method_payload(header, &output)))
+    }
+
+    /// Messages look like `<font color='#80D63A'>name[CLAN] (Tank)&nbsp;: </font><font ..>text</font>`
+    fn anonymize_chat(&self, payload: &[u8]) -> Option<Vec<u8>> {
+        let size = u32::from_le_bytes(payload.get(..4)?.try_into().unwrap()) as usize;
+        let msg = payload.get(4..(4 + size))?;
+        let remaining = &payload[(4 + size)..];
+
+        let mut msg = String::from_utf8_lossy(msg).into_owned();
+
+        // Longest first so that a name that is a part of another name does not break the longer one
+        let mut names: Vec<_> = self.names.iter().collect();
+        names.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+        for (name, alias) in names {
+            msg = replace_word(&msg, name, alias);
+        }
+        for (clan, alias) in &self.clans {
+            msg = msg.replace(&format!("[{clan}]"), &format!("[{alias}]"));
+        }
+
+        let mut output = (msg.len() as u32).to_le_bytes().to_vec();
+        output.extend_from_slice(msg.as_bytes());
+        output.extend_from_slice(remaining);
+
+        (output != payload).then_some(output)
+    }
+}
+
+/// Player entries are the only JSON objects that have both a name and a team
+fn is_player(map: &serde_json::Map<String, JsonVal>) -> bool {
+    map.contains_key("team")
+}
+
+fn packed_string(value: &str) -> Vec<u8> {
+    let mut output = Vec::with_capacity(value.len() + 1);
+    write_byte_array(value.as_bytes(), &mut output);
+
+    output
+}
+
+/// Vehicle info tuples of a `VehicleList` (a list of tuples) or of a `VehicleAdded` / `VehicleUpdated` (a
+/// single tuple)
+fn vehicle_tuples(pickle: &PickleNode) -> Vec<Vec<PickleNode>> {
+    match pickle {
+        PickleNode::Tuple(vehicle) => vec![vehicle.clone()],
+        PickleNode::List(list) => list
+            .borrow()
+            .iter()
+            .filter_map(|item| match item {
+                PickleNode::Tuple(vehicle) => Some(vehicle.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Split the payload of a method packet into the entity ID and method ID, and the arguments. The size of the
+/// arguments between them is left out
+fn split_method(payload: &[u8]) -> Result<(&[u8], &[u8]), PacketError> {
+    match (payload.get(..8), payload.get(12..)) {
+        (Some(header), Some(args)) => Ok((header, args)),
+        _ => Err(PacketError::IncompleteInput {
+            err: "method header".to_string(),
+        }),
+    }
+}
+
+fn method_payload(header: &[u8], args: &[u8]) -> Vec<u8> {
+    let mut output = header.to_vec();
+    output.extend_from_slice(&(args.len() as u32).to_le_bytes());
+    output.extend_from_slice(args);
+
+    output
+}
+
+/// Replace `word` only if it is not a part of a longer word. Player names are made of ASCII letters, digits,
+/// `_` and `-`
+fn replace_word(text: &str, word: &str, replacement: &str) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+
+    let mut output = String::with_capacity(text.len());
+    let mut last = 0;
+    for (position, _) in text.match_indices(word) {
+        let end = position + word.len();
+        let before = text[..position].chars().next_back();
+        let after = text[end..].chars().next();
+
+        if position >= last && !before.is_some_and(is_name_char) && !after.is_some_and(is_name_char) {
+            output.push_str(&text[last..position]);
+            output.push_str(replacement);
+            last = end;
+        }
+    }
+    output.push_str(&text[last..]);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identities() -> Identities {
+        let mut identities = Identities::new(ReplayAnonymizer::new(42));
+        identities.add_name("abc");
+        identities.add_clan("CZ4R");
+        identities.add_account_id(1035966068);
+
+        identities
+    }
+
+    fn method_packet(args: &[u8]) -> Vec<u8> {
+        method_payload(&[1, 0, 0, 0, 2, 0, 0, 0], args)
+    }
+
+    #[test]
+    fn fails_on_packets_that_cannot_be_parsed() {
+        let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+
+        // Cut the name of the player in `AvatarCreate`, which follows the header and the size of the rest
+        let mut packets_buffer = Vec::new();
+        for packet in replay.packet_stream() {
+            let packet = packet.unwrap();
+
+            if packet.packet_type() == 0x00 {
+                assert!(packet.payload()[14..].starts_with(b"\x07_Dacite"));
+                let payload = &packet.payload()[..16];
+
+                packets_buffer.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+                packets_buffer.extend_from_slice(&packet.inner()[4..METADATA_SIZE]);
+                packets_buffer.extend_from_slice(payload);
+            } else {
+                packets_buffer.extend_from_slice(packet.inner());
+            }
+        }
+
+        let mut writer = ReplayWriter::new();
+        writer
+            .set_json(replay.replay_json())
+            .unwrap()
+            .set_packets_buffer(packets_buffer);
+        let malformed = ReplayParser::parse(writer.to_bytes().unwrap()).unwrap();
+
+        let result = ReplayAnonymizer::new(42).anonymize(&malformed);
+        assert!(matches!(
+            result,
+            Err(ReplayError::PacketParseError {
+                packet_type: 0x00,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn replaces_whole_words_only() {
+        assert_eq!(replace_word("abc: hi", "abc", "X"), "X: hi");
+        assert_eq!(replace_word("abcd abc_ abc", "abc", "X"), "abcd abc_ X");
+        assert_eq!(replace_word("[abc](abc)", "abc", "X"), "[X](X)");
+        assert_eq!(replace_word("a-abc abc-a", "abc", "X"), "a-abc abc-a");
+    }
+
+    #[test]
+    fn replaces_vehicle_fields_only() {
+        let identities = identities();
+
+        // The compact descriptor of the vehicle happens to contain the pickled name and account ID
+        let descr = [&b"U\x03abc"[..], &pickle_int(1035966068)].concat();
+        let pickle = [
+            &b"\x80\x02]q\x01((J\x01\x00\x00\x00U"[..],
+            &[descr.len() as u8],
+            &descr,
+            b"U\x03abcK\x02K\x01K\x00K\x00",
+            &pickle_int(1035966068),
+            b"U\x04CZ4RK\x00K\x00t",
+            b"q\x02e.",
+        ]
+        .concat();
+
+        let mut args = vec![1];
+        write_byte_array(&compress_to_vec_zlib(&pickle, 6), &mut args);
+        let payload = identities
+            .anonymize_vehicle_list(&method_packet(&args))
+            .unwrap()
+            .unwrap();
+
+        let (_, compressed) = parse_byte_array(&payload[13..]).unwrap();
+        let anonymized = decompress_to_vec_zlib(compressed).unwrap();
+
+        let PickleNode::List(list) = pickle::parse(&anonymized).unwrap() else {
+            panic!("expected a list");
+        };
+        let PickleNode::Tuple(vehicle) = &list.borrow()[0] else {
+            panic!("expected a tuple");
+        };
+        let value = |index| match &vehicle[index] {
+            PickleNode::Value(range) => range.clone(),
+            node => panic!("unexpected node: {node:?}"),
+        };
+
+        assert_eq!(&anonymized[value(1)][2..], &descr[..]);
+        assert_eq!(
+            pickle::read_str(&anonymized, &value(VEHICLE_NAME)),
+            Some(identities.names["abc"].as_str())
+        );
+        assert_eq!(
+            pickle::read_str(&anonymized, &value(VEHICLE_CLAN_ABBREV)),
+            Some(identities.clans["CZ4R"].as_str())
+        );
+        assert_eq!(
+            pickle::read_int(&anonymized, &value(VEHICLE_ACCOUNT_DBID)),
+            Some(identities.account_ids[&1035966068])
+        );
+    }
+
+    #[test]
+    fn replaces_sender_of_chat_action_only() {
+        let mut identities = identities();
+        let alias = identities.account_ids[&1035966068];
+
+        // The pickled data also contains the account ID, as a raw `i64`
+        let mut args = vec![0; CHAT_ACTION_ORIGINATOR];
+        args.extend_from_slice(&1035966068i64.to_le_bytes());
+        write_byte_array(b"abc", &mut args);
+        args.push(0);
+        write_byte_array(&1035966068i64.to_le_bytes(), &mut args);
+        args.push(0);
+
+        let payload = identities
+            .anonymize_chat_action(&method_packet(&args), CHAT_ACTIONS[0])
+            .unwrap()
+            .unwrap();
+        let args = &payload[12..];
+
+        let (remaining, originator) = le_i64::<_, ()>(&args[CHAT_ACTION_ORIGINATOR..]).unwrap();
+        let (remaining, nick) = parse_byte_array(remaining).unwrap();
+        assert_eq!(originator, alias);
+        assert_eq!(nick, identities.names["abc"].as_bytes());
+        assert_eq!(&remaining[2..10], &1035966068i64.to_le_bytes());
+
+        // Only known accounts are replaced in commands
+        let mut args = vec![0; CHAT_COMMAND_SENDER];
+        args.extend_from_slice(&7i64.to_le_bytes());
+        let result = identities.anonymize_chat_action(&method_packet(&args), CHAT_ACTIONS[1]);
+        assert!(result.unwrap().is_none());
+    }
+}
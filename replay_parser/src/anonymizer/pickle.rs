@@ -0,0 +1,400 @@
+//! Just enough of a pickle reader to find where the values of a Python 2 pickle are, so that some of them
+//! can be replaced without touching the rest. Re-pickling with `serde_pickle` would lose the objects it
+//! cannot represent (ex: the `_BWp Array` of vehicle lists) and the objects shared through the memo
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::PacketError;
+
+/// A value of a pickle. Only tuples and lists are kept as is, because they are what the anonymizer looks
+/// into. Everything else that is not a plain value (dicts, objects etc.) is an `Object`
+#[derive(Debug, Clone)]
+pub(super) enum PickleNode {
+    /// Position of the opcode (and its argument) that pushes the value
+    Value(Range<usize>),
+    Tuple(Vec<PickleNode>),
+
+    /// Lists are filled after they are created, and may be in the memo by then
+    List(Rc<RefCell<Vec<PickleNode>>>),
+    Object,
+}
+
+enum StackItem {
+    Mark,
+    Node(PickleNode),
+}
+
+/// Read the structure of `pickle`. Fails on opcodes of protocols above 2, which the game does not use
+pub(super) fn parse(pickle: &[u8]) -> Result<PickleNode, PacketError> {
+    let mut stack = Vec::new();
+    let mut memo = HashMap::new();
+    let mut position = 0;
+
+    while let Some(&opcode) = pickle.get(position) {
+        let start = position;
+        position += 1;
+
+        let mut take = |len: usize| {
+            let arg = pickle
+                .get(position..(position + len))
+                .ok_or_else(|| err("truncated pickle"))?;
+            position += len;
+            Ok::<_, PacketError>(arg)
+        };
+
+        match opcode {
+            b'.' => return pop_node(&mut stack),
+            0x80 => {
+                take(1)?;
+            }
+            b'(' => stack.push(StackItem::Mark),
+            b'0' => {
+                stack.pop();
+            }
+            b'1' => {
+                pop_mark(&mut stack)?;
+            }
+            b'2' => {
+                let node = peek_node(&stack)?.clone();
+                stack.push(StackItem::Node(node));
+            }
+
+            // Values
+            b'N' | 0x88 | 0x89 => push_value(&mut stack, start, position),
+            b'K' | b'M' | b'J' | b'G' => {
+                let len = match opcode {
+                    b'K' => 1,
+                    b'M' => 2,
+                    b'J' => 4,
+                    _ => 8,
+                };
+                take(len)?;
+                push_value(&mut stack, start, position);
+            }
+            b'U' | 0x8A => {
+                let len = take(1)?[0] as usize;
+                take(len)?;
+                push_value(&mut stack, start, position);
+            }
+            b'T' | b'X' | 0x8B => {
+                let len = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+                take(len)?;
+                push_value(&mut stack, start, position);
+            }
+            b'I' | b'L' | b'F' | b'S' | b'V' => {
+                position = line_end(pickle, position)?;
+                push_value(&mut stack, start, position);
+            }
+
+            // Tuples and lists
+            b')' => stack.push(StackItem::Node(PickleNode::Tuple(Vec::new()))),
+            0x85..=0x87 => {
+                let len = (opcode - 0x84) as usize;
+                let items = stack
+                    .len()
+                    .checked_sub(len)
+                    .ok_or_else(|| err("stack underflow"))?;
+                let items = stack
+                    .split_off(items)
+                    .into_iter()
+                    .map(into_node)
+                    .collect::<Result<_, _>>()?;
+                stack.push(StackItem::Node(PickleNode::Tuple(items)));
+            }
+            b't' => {
+                let items = pop_mark(&mut stack)?;
+                stack.push(StackItem::Node(PickleNode::Tuple(items)));
+            }
+            b']' => stack.push(StackItem::Node(PickleNode::List(Rc::default()))),
+            b'l' => {
+                let items = pop_mark(&mut stack)?;
+                stack.push(StackItem::Node(PickleNode::List(Rc::new(RefCell::new(items)))));
+            }
+            b'a' => {
+                let item = pop_node(&mut stack)?;
+                if let PickleNode::List(list) = peek_node(&stack)? {
+                    list.borrow_mut().push(item);
+                }
+            }
+            b'e' => {
+                let items = pop_mark(&mut stack)?;
+                if let PickleNode::List(list) = peek_node(&stack)? {
+                    list.borrow_mut().extend(items);
+                }
+            }
+
+            // Everything else that ends up on the stack
+            b'}' => stack.push(StackItem::Node(PickleNode::Object)),
+            b'd' | b'o' => {
+                pop_mark(&mut stack)?;
+                stack.push(StackItem::Node(PickleNode::Object));
+            }
+            b's' => {
+                pop_node(&mut stack)?;
+                pop_node(&mut stack)?;
+            }
+            b'u' => {
+                pop_mark(&mut stack)?;
+            }
+            b'c' => {
+                position = line_end(pickle, line_end(pickle, position)?)?;
+                stack.push(StackItem::Node(PickleNode::Object));
+            }
+            b'i' => {
+                position = line_end(pickle, line_end(pickle, position)?)?;
+                pop_mark(&mut stack)?;
+                stack.push(StackItem::Node(PickleNode::Object));
+            }
+            b'R' | 0x81 => {
+                pop_node(&mut stack)?;
+                pop_node(&mut stack)?;
+                stack.push(StackItem::Node(PickleNode::Object));
+            }
+            b'b' => {
+                pop_node(&mut stack)?;
+            }
+
+            // Memo
+            b'q' | b'r' | b'p' => {
+                let index = match opcode {
+                    b'q' => take(1)?[0] as u32,
+                    b'r' => u32::from_le_bytes(take(4)?.try_into().unwrap()),
+                    _ => read_line_index(pickle, &mut position)?,
+                };
+                memo.insert(index, peek_node(&stack)?.clone());
+            }
+            b'h' | b'j' | b'g' => {
+                let index = match opcode {
+                    b'h' => take(1)?[0] as u32,
+                    b'j' => u32::from_le_bytes(take(4)?.try_into().unwrap()),
+                    _ => read_line_index(pickle, &mut position)?,
+                };
+                let node = memo
+                    .get(&index)
+                    .ok_or_else(|| err("memo index not found"))?
+                    .clone();
+                stack.push(StackItem::Node(node));
+            }
+            _ => return Err(err(&format!("unsupported opcode {opcode:#04x}"))),
+        }
+    }
+
+    Err(err("pickle has no STOP opcode"))
+}
+
+/// The integer pushed by the opcode at `range`. `None` if it is not an integer that fits in an `i64`
+pub(super) fn read_int(pickle: &[u8], range: &Range<usize>) -> Option<i64> {
+    let value = &pickle[range.clone()];
+    let (opcode, arg) = value.split_first()?;
+
+    match opcode {
+        b'K' => Some(arg[0] as i64),
+        b'M' => Some(u16::from_le_bytes(arg.try_into().ok()?) as i64),
+        b'J' => Some(i32::from_le_bytes(arg.try_into().ok()?) as i64),
+        0x8A if arg.len() > 1 && arg.len() <= 9 => {
+            let bytes = &arg[1..];
+            let fill = if bytes.last()? & 0x80 != 0 { 0xFF } else { 0 };
+            let mut padded = [fill; 8];
+            padded[..bytes.len().min(8)].copy_from_slice(&bytes[..bytes.len().min(8)]);
+            Some(i64::from_le_bytes(padded))
+        }
+        _ => None,
+    }
+}
+
+/// The string pushed by the opcode at `range`. `None` if it is not a binary string
+pub(super) fn read_str<'a>(pickle: &'a [u8], range: &Range<usize>) -> Option<&'a str> {
+    let value = &pickle[range.clone()];
+
+    let bytes = match value.first()? {
+        b'U' => value.get(2..)?,
+        b'T' | b'X' => value.get(5..)?,
+        _ => return None,
+    };
+
+    std::str::from_utf8(bytes).ok()
+}
+
+/// Encode `value` as a string of the same kind (`str` or `unicode`) as the one at `range`
+pub(super) fn pickle_str_like(pickle: &[u8], range: &Range<usize>, value: &str) -> Vec<u8> {
+    let mut output = Vec::with_capacity(value.len() + 5);
+
+    match pickle[range.start] {
+        b'X' => {
+            output.push(b'X');
+            output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        }
+        _ if value.len() <= 0xFF => output.extend_from_slice(&[b'U', value.len() as u8]),
+        _ => {
+            output.push(b'T');
+            output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        }
+    }
+    output.extend_from_slice(value.as_bytes());
+
+    output
+}
+
+/// Encode an integer the same way Python's pickler does (`BININT1`, `BININT2`, `BININT` or `LONG1`)
+pub(super) fn pickle_int(value: i64) -> Vec<u8> {
+    match value {
+        0..=0xFF => vec![b'K', value as u8],
+        0x100..=0xFFFF => [&[b'M'][..], &(value as u16).to_le_bytes()].concat(),
+        _ if i32::try_from(value).is_ok() => [&[b'J'][..], &(value as i32).to_le_bytes()].concat(),
+        _ => {
+            let mut bytes = value.to_le_bytes().to_vec();
+            // Two's complement with as few bytes as possible
+            while bytes.len() > 1 {
+                let (last, prev) = (bytes[bytes.len() - 1], bytes[bytes.len() - 2]);
+                if (last == 0 && prev & 0x80 == 0) || (last == 0xFF && prev & 0x80 != 0) {
+                    bytes.pop();
+                } else {
+                    break;
+                }
+            }
+
+            [&[0x8A, bytes.len() as u8][..], &bytes].concat()
+        }
+    }
+}
+
+/// Replace the opcodes at the given ranges. A range that is given more than once (a value shared through
+/// the memo) is replaced once
+pub(super) fn patch(pickle: &[u8], mut replacements: Vec<(Range<usize>, Vec<u8>)>) -> Vec<u8> {
+    replacements.sort_by_key(|(range, _)| range.start);
+    replacements.dedup_by_key(|(range, _)| range.start);
+
+    let mut output = Vec::with_capacity(pickle.len());
+    let mut last = 0;
+    for (range, replacement) in replacements {
+        output.extend_from_slice(&pickle[last..range.start]);
+        output.extend_from_slice(&replacement);
+        last = range.end;
+    }
+    output.extend_from_slice(&pickle[last..]);
+
+    output
+}
+
+fn push_value(stack: &mut Vec<StackItem>, start: usize, end: usize) {
+    stack.push(StackItem::Node(PickleNode::Value(start..end)));
+}
+
+fn into_node(item: StackItem) -> Result<PickleNode, PacketError> {
+    match item {
+        StackItem::Node(node) => Ok(node),
+        StackItem::Mark => Err(err("unexpected mark")),
+    }
+}
+
+fn pop_node(stack: &mut Vec<StackItem>) -> Result<PickleNode, PacketError> {
+    into_node(stack.pop().ok_or_else(|| err("stack underflow"))?)
+}
+
+fn peek_node(stack: &[StackItem]) -> Result<&PickleNode, PacketError> {
+    match stack.last() {
+        Some(StackItem::Node(node)) => Ok(node),
+        _ => Err(err("expected a value on the stack")),
+    }
+}
+
+/// Pop the items up to the last mark, in the order they were pushed
+fn pop_mark(stack: &mut Vec<StackItem>) -> Result<Vec<PickleNode>, PacketError> {
+    let mark = stack
+        .iter()
+        .rposition(|item| matches!(item, StackItem::Mark))
+        .ok_or_else(|| err("mark not found"))?;
+
+    let items = stack.split_off(mark + 1);
+    stack.pop();
+
+    items.into_iter().map(into_node).collect()
+}
+
+/// Position after the next `\n`, starting at `position`
+fn line_end(pickle: &[u8], position: usize) -> Result<usize, PacketError> {
+    let len = pickle
+        .get(position..)
+        .and_then(|rest| rest.iter().position(|&byte| byte == b'\n'))
+        .ok_or_else(|| err("truncated pickle"))?;
+
+    Ok(position + len + 1)
+}
+
+fn read_line_index(pickle: &[u8], position: &mut usize) -> Result<u32, PacketError> {
+    let end = line_end(pickle, *position)?;
+    let line = std::str::from_utf8(&pickle[*position..(end - 1)]).map_err(|_| err("invalid memo index"))?;
+    *position = end;
+
+    line.parse().map_err(|_| err("invalid memo index"))
+}
+
+fn err(message: &str) -> PacketError {
+    PacketError::PickleError {
+        err: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pickles_ints_like_python() {
+        // Output of `pickle.dumps(value, 2)` in Python 2
+        assert_eq!(pickle_int(7), b"K\x07");
+        assert_eq!(pickle_int(300), b"M\x2c\x01");
+        assert_eq!(pickle_int(1035966068), b"J\x74\x96\xbf\x3d");
+        assert_eq!(pickle_int(-1), b"J\xff\xff\xff\xff");
+        assert_eq!(pickle_int(1 << 31), b"\x8a\x05\x00\x00\x00\x80\x00");
+        assert_eq!(pickle_int(-(1 << 40)), b"\x8a\x06\x00\x00\x00\x00\x00\xff");
+
+        for value in [7, 300, 1035966068, -1, 1 << 31, -(1 << 40), i64::MAX] {
+            let pickled = pickle_int(value);
+            assert_eq!(read_int(&pickled, &(0..pickled.len())), Some(value));
+        }
+    }
+
+    #[test]
+    fn finds_values_through_the_memo() {
+        // `[('abc', 7), ('abc', 8)]` where the second 'abc' is the first one, taken from the memo
+        let pickle = b"\x80\x02]q\x01(U\x03abcq\x02K\x07\x86q\x03h\x02K\x08\x86q\x04e.";
+
+        let PickleNode::List(list) = parse(pickle).unwrap() else {
+            panic!("expected a list");
+        };
+        let list = list.borrow();
+        assert_eq!(list.len(), 2);
+
+        let (PickleNode::Tuple(first), PickleNode::Tuple(second)) = (&list[0], &list[1]) else {
+            panic!("expected tuples");
+        };
+        let (PickleNode::Value(first_name), PickleNode::Value(second_name)) = (&first[0], &second[0]) else {
+            panic!("expected values");
+        };
+        assert_eq!(first_name, second_name);
+        assert_eq!(read_str(pickle, first_name), Some("abc"));
+
+        let patched = patch(
+            pickle,
+            vec![
+                (first_name.clone(), pickle_str_like(pickle, first_name, "xy")),
+                (second_name.clone(), pickle_str_like(pickle, second_name, "xy")),
+            ],
+        );
+        assert_eq!(
+            &patched[..],
+            b"\x80\x02]q\x01(U\x02xyq\x02K\x07\x86q\x03h\x02K\x08\x86q\x04e."
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_opcodes() {
+        // SHORT_BINBYTES is from protocol 3
+        assert!(parse(b"\x80\x03C\x01a.").is_err());
+        assert!(parse(b"\x80\x02]q\x01(K\x07").is_err());
+    }
+}
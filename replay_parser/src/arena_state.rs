@@ -0,0 +1,523 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::Value as JsonVal;
+use wot_types::ArenaPeriod;
+
+use crate::events::{
+    ArenaUpdateData, AvatarMethods, EntityMethod, EntityProperty, Vector3, VehicleMethods, VehicleProperties,
+};
+use crate::utils::{as_i64, as_map, as_string};
+use crate::{BattleEvent, ReplayError, ReplayParser};
+
+/// State of the battle (health, position and status of each vehicle, capture points of the bases and period
+/// of the arena) rebuilt from the events of the replay. Every event that changes the state is kept so that
+/// the state can be queried at any time of the replay.
+///
+/// ```no_run
+/// # use wot_replay_parser::{ArenaState, ReplayParser};
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+/// let (arena, _errors) = ArenaState::from_replay(&replay).unwrap();
+///
+/// for team in arena.state_at(300.0).teams() {
+///     println!("team {}: {}/{} alive", team.team, team.vehicles_alive, team.vehicles_total);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArenaState {
+    roster:   HashMap<i32, RosterEntry>,
+    vehicles: HashMap<i32, VehicleState>,
+    bases:    BTreeMap<(i32, i32), BaseState>,
+    period:   Option<ArenaPeriod>,
+    time:     f32,
+
+    /// Events that changed the state, so that the state at an earlier time can be rebuilt
+    history: Vec<(f32, BattleEvent)>,
+}
+
+/// A player of the battle, as listed in the JSON of the replay
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RosterEntry {
+    /// ID of the vehicle entity of the player
+    pub vehicle_id:   i32,
+    pub name:         String,
+    pub clan_abbrev:  String,
+    pub vehicle_type: String,
+    pub team:         i32,
+
+    /// Not available in older replays
+    pub max_health: Option<i32>,
+}
+
+/// Snapshot of a vehicle
+#[derive(Debug, Clone, Serialize)]
+pub struct VehicleState {
+    pub vehicle_id: i32,
+
+    /// `None` if the vehicle is not in the roster of the replay (ex: bots)
+    pub roster: Option<RosterEntry>,
+
+    /// `None` until the vehicle was seen by the recording player. Can be negative when the vehicle is
+    /// destroyed by an ammo rack explosion
+    pub health:   Option<i32>,
+    pub position: Option<Vector3>,
+    pub alive:    bool,
+
+    /// ID of the vehicle that destroyed this vehicle
+    pub killer_id: Option<i32>,
+
+    /// Time of the last event that changed this vehicle
+    pub last_update: f32,
+}
+
+/// Capture points of a base. `team` is the team that owns the base
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BaseState {
+    pub team:           i32,
+    pub base_id:        i32,
+    pub points:         i32,
+    pub invaders_count: Option<i32>,
+    pub captured:       bool,
+}
+
+/// Snapshot of a team
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamState {
+    pub team:           i32,
+    pub vehicles_total: usize,
+    pub vehicles_alive: usize,
+
+    /// Sum of the known health of the vehicles that are alive
+    pub total_health: i32,
+    pub bases:        Vec<BaseState>,
+}
+
+impl ArenaState {
+    pub fn new(roster: HashMap<i32, RosterEntry>) -> Self {
+        let vehicles = roster
+            .values()
+            .map(|entry| {
+                (
+                    entry.vehicle_id,
+                    VehicleState::new(entry.vehicle_id, Some(entry.clone())),
+                )
+            })
+            .collect();
+
+        ArenaState {
+            roster,
+            vehicles,
+            ..Default::default()
+        }
+    }
+
+    /// Apply every event of the replay that changes the state. Packets that cannot be parsed are skipped and
+    /// returned with the state, so that a packet we do not support does not leave the state empty
+    pub fn from_replay(replay: &ReplayParser) -> Result<(Self, Vec<ReplayError>), ReplayError> {
+        let roster = get_roster(replay.replay_json_start()?)?;
+        let mut arena = ArenaState::new(roster);
+        let mut errors = Vec::new();
+
+        for event in replay.event_stream()?.timed() {
+            match event {
+                Ok((time, event)) => {
+                    arena.apply(time, &event);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Ok((arena, errors))
+    }
+
+    /// Apply an event to the state. Returns `false` if the event does not change the state
+    pub fn apply(&mut self, time: f32, event: &BattleEvent) -> bool {
+        let applied = match event {
+            BattleEvent::EntityCreate(create) => {
+                let health = create.properties.iter().find_map(|property| match property {
+                    EntityProperty::Vehicle(VehicleProperties::Health(health)) => Some(*health),
+                    _ => None,
+                });
+
+                // Only vehicles are created with a health
+                match health {
+                    Some(health) => {
+                        let vehicle = self.vehicle_mut(create.entity_id, time);
+                        vehicle.health = Some(health.into());
+                        vehicle.position = Some(create.position.clone());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            BattleEvent::EntityProperty(property_event) => match property_event.property {
+                EntityProperty::Vehicle(VehicleProperties::Health(health)) => {
+                    self.vehicle_mut(property_event.entity_id, time).health = Some(health.into());
+                    true
+                }
+                _ => false,
+            },
+            BattleEvent::EntityMethod(method_event) => match &method_event.method {
+                EntityMethod::Vehicle(VehicleMethods::OnHealthChanged(health_changed)) => {
+                    self.vehicle_mut(method_event.entity_id, time).health =
+                        Some(health_changed.new_health.into());
+                    true
+                }
+                EntityMethod::Avatar(AvatarMethods::UpdateArena(update)) => {
+                    self.apply_arena_update(time, &update.update_data)
+                }
+                _ => false,
+            },
+            BattleEvent::Position(position) if self.vehicles.contains_key(&position.entity_id) => {
+                self.vehicle_mut(position.entity_id, time).position = Some(position.position.clone());
+                true
+            }
+            BattleEvent::ArenaPeriod(period_event) => {
+                self.period = Some(period_event.period);
+                true
+            }
+            _ => false,
+        };
+
+        if applied {
+            self.time = time;
+            self.history.push((time, event.clone()));
+        }
+
+        applied
+    }
+
+    fn apply_arena_update(&mut self, time: f32, update: &ArenaUpdateData) -> bool {
+        match update {
+            ArenaUpdateData::VehicleKilled(killed) => {
+                let vehicle = self.vehicle_mut(killed.victim_id, time);
+                vehicle.alive = false;
+                vehicle.killer_id = Some(killed.killer_id);
+            }
+            ArenaUpdateData::VehicleRecovered(recovered) => {
+                let vehicle = self.vehicle_mut(recovered.vehicle_id, time);
+                vehicle.alive = true;
+                vehicle.killer_id = None;
+            }
+            ArenaUpdateData::BasePoints(base_points) => {
+                let base = self.base_mut(base_points.team, base_points.base_id);
+                base.points = base_points.points;
+                base.invaders_count = base_points.invaders_cnt;
+            }
+            ArenaUpdateData::BaseCaptured(base_captured) => {
+                self.base_mut(base_captured.team, base_captured.base_id).captured = true;
+            }
+            ArenaUpdateData::Period(period) => self.period = Some(period.period),
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn vehicle_mut(&mut self, vehicle_id: i32, time: f32) -> &mut VehicleState {
+        let roster = &self.roster;
+        let vehicle = self
+            .vehicles
+            .entry(vehicle_id)
+            .or_insert_with(|| VehicleState::new(vehicle_id, roster.get(&vehicle_id).cloned()));
+        vehicle.last_update = time;
+
+        vehicle
+    }
+
+    fn base_mut(&mut self, team: i32, base_id: i32) -> &mut BaseState {
+        self.bases.entry((team, base_id)).or_insert(BaseState {
+            team,
+            base_id,
+            points: 0,
+            invaders_count: None,
+            captured: false,
+        })
+    }
+
+    /// Time of the last event that changed the state
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// `None` until the arena sent its first period
+    pub fn period(&self) -> Option<ArenaPeriod> {
+        self.period
+    }
+
+    pub fn roster(&self) -> &HashMap<i32, RosterEntry> {
+        &self.roster
+    }
+
+    pub fn vehicle(&self, vehicle_id: i32) -> Option<&VehicleState> {
+        self.vehicles.get(&vehicle_id)
+    }
+
+    pub fn vehicles(&self) -> impl Iterator<Item = &VehicleState> {
+        self.vehicles.values()
+    }
+
+    pub fn bases(&self) -> impl Iterator<Item = &BaseState> {
+        self.bases.values()
+    }
+
+    /// Snapshot of a team. Vehicles that are not in the roster are not counted since we do not know their
+    /// team
+    pub fn team(&self, team: i32) -> TeamState {
+        let vehicles: Vec<_> = self
+            .vehicles
+            .values()
+            .filter(|vehicle| vehicle.team() == Some(team))
+            .collect();
+        let alive = vehicles.iter().filter(|vehicle| vehicle.alive);
+
+        TeamState {
+            team,
+            vehicles_total: vehicles.len(),
+            vehicles_alive: alive.clone().count(),
+            total_health: alive
+                .filter_map(|vehicle| vehicle.health)
+                .map(|health| health.max(0))
+                .sum(),
+            bases: self
+                .bases
+                .values()
+                .filter(|base| base.team == team)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Snapshot of every team of the roster, sorted by team number
+    pub fn teams(&self) -> Vec<TeamState> {
+        let mut teams: Vec<_> = self.roster.values().map(|entry| entry.team).collect();
+        teams.sort_unstable();
+        teams.dedup();
+
+        teams.into_iter().map(|team| self.team(team)).collect()
+    }
+
+    /// State of the battle after the events received until `time` (inclusive)
+    pub fn state_at(&self, time: f32) -> ArenaState {
+        let mut arena = ArenaState::new(self.roster.clone());
+
+        for (event_time, event) in self
+            .history
+            .iter()
+            .take_while(|(event_time, _)| *event_time <= time)
+        {
+            arena.apply(*event_time, event);
+        }
+
+        arena
+    }
+}
+
+impl VehicleState {
+    fn new(vehicle_id: i32, roster: Option<RosterEntry>) -> Self {
+        VehicleState {
+            vehicle_id,
+            roster,
+            health: None,
+            position: None,
+            alive: true,
+            killer_id: None,
+            last_update: 0.0,
+        }
+    }
+
+    /// `None` if the vehicle is not in the roster
+    pub fn team(&self) -> Option<i32> {
+        self.roster.as_ref().map(|entry| entry.team)
+    }
+}
+
+/// The `vehicles` of the JSON are keyed by the ID of the vehicle entity of each player
+fn get_roster(json: &JsonVal) -> Result<HashMap<i32, RosterEntry>, ReplayError> {
+    let mut roster = HashMap::new();
+
+    for (vehicle_id, vehicle) in as_map("/vehicles", json)? {
+        let vehicle_id = vehicle_id
+            .parse::<i32>()
+            .map_err(|err| ReplayError::Other(err.to_string()))?;
+
+        let entry = RosterEntry {
+            vehicle_id,
+            name: as_string("/name", vehicle)?,
+            clan_abbrev: as_string("/clanAbbrev", vehicle).unwrap_or_default(),
+            vehicle_type: as_string("/vehicleType", vehicle)?,
+            team: as_i64("/team", vehicle)? as i32,
+            max_health: as_i64("/maxHealth", vehicle).ok().map(|health| health as i32),
+        };
+        roster.insert(vehicle_id, entry);
+    }
+
+    Ok(roster)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(vehicle_id: i32, team: i32) -> RosterEntry {
+        RosterEntry {
+            vehicle_id,
+            name: format!("player{vehicle_id}"),
+            clan_abbrev: String::new(),
+            vehicle_type: "czech:Cz04_T50_51".to_string(),
+            team,
+            max_health: Some(1800),
+        }
+    }
+
+    #[test]
+    fn folds_events_and_rebuilds_past_state() {
+        let roster = [entry(1, 1), entry(2, 2)]
+            .into_iter()
+            .map(|entry| (entry.vehicle_id, entry))
+            .collect();
+        let mut arena = ArenaState::new(roster);
+
+        let health = |entity_id, health| {
+            BattleEvent::EntityProperty(crate::events::EntityPropertyEvent {
+                entity_id,
+                property: EntityProperty::Vehicle(VehicleProperties::Health(health)),
+            })
+        };
+        assert!(arena.apply(1.0, &health(1, 1800)));
+        assert!(arena.apply(1.0, &health(2, 1800)));
+        assert!(arena.apply(2.0, &health(2, 900)));
+        assert!(!arena.apply(
+            2.5,
+            &BattleEvent::Unimplemented {
+                packet_type: crate::PacketType::Unknown(0xff),
+                size:        0,
+            }
+        ));
+
+        assert_eq!(arena.vehicle(2).unwrap().health, Some(900));
+        assert_eq!(arena.team(2).total_health, 900);
+        assert_eq!(arena.state_at(1.5).team(2).total_health, 1800);
+        assert_eq!(arena.teams().len(), 2);
+        assert_eq!(arena.time(), 2.0);
+    }
+
+    fn arena_update(update_type: u8, value: serde_pickle::Value) -> BattleEvent {
+        let pickle = serde_pickle::value_to_vec(&value, serde_pickle::SerOptions::new()).unwrap();
+        let mut data = vec![update_type];
+        crate::packet_parser::write_byte_array(&pickle, &mut data);
+
+        BattleEvent::EntityMethod(crate::events::EntityMethodEvent {
+            entity_id: 0,
+            method:    EntityMethod::Avatar(AvatarMethods::UpdateArena(
+                crate::events::UpdateArena::from(&data, [1, 16, 1, 0]).unwrap(),
+            )),
+        })
+    }
+
+    fn ints(values: &[i64]) -> serde_pickle::Value {
+        serde_pickle::Value::Tuple(
+            values
+                .iter()
+                .map(|value| serde_pickle::Value::I64(*value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn kills_and_recovers_vehicles() {
+        let mut arena = ArenaState::new([(1, entry(1, 1)), (2, entry(2, 2))].into());
+
+        // `VehicleKilled`: victim, killer, equipment and attack reason
+        assert!(arena.apply(10.0, &arena_update(6, ints(&[2, 1, 0, 0]))));
+        let vehicle = arena.vehicle(2).unwrap();
+        assert_eq!((vehicle.alive, vehicle.killer_id), (false, Some(1)));
+        assert_eq!(arena.team(2).vehicles_alive, 0);
+
+        // `VehicleRecovered`
+        assert!(arena.apply(20.0, &arena_update(28, serde_pickle::Value::I64(2))));
+        let vehicle = arena.vehicle(2).unwrap();
+        assert_eq!((vehicle.alive, vehicle.killer_id), (true, None));
+        assert!(!arena.state_at(15.0).vehicle(2).unwrap().alive);
+    }
+
+    #[test]
+    fn tracks_bases_period_and_positions() {
+        let mut arena = ArenaState::new([(1, entry(1, 1))].into());
+
+        // `BasePoints`: team, base, points, time left, invaders and whether the capture stopped
+        assert!(arena.apply(5.0, &arena_update(8, ints(&[1, 1, 40, 60, 2, 0]))));
+        assert!(arena.apply(6.0, &arena_update(9, ints(&[1, 1]))));
+        let base = &arena.team(1).bases[0];
+        assert_eq!(
+            (base.points, base.invaders_count, base.captured),
+            (40, Some(2), true)
+        );
+
+        assert!(arena.apply(
+            7.0,
+            &BattleEvent::ArenaPeriod(crate::events::ArenaPeriodEvent {
+                period: ArenaPeriod::AfterBattle,
+            })
+        ));
+        assert_eq!(arena.period(), Some(ArenaPeriod::AfterBattle));
+        assert_eq!(arena.state_at(6.5).period(), None);
+
+        let position = |entity_id| {
+            BattleEvent::Position(crate::events::Position {
+                entity_id,
+                space_id: None,
+                vehicle_id: entity_id,
+                position: Vector3 {
+                    x: 1.0,
+                    z: 2.0,
+                    y: 3.0,
+                },
+                position_error: Vector3 {
+                    x: 0.0,
+                    z: 0.0,
+                    y: 0.0,
+                },
+                yaw: 0.0,
+                pitch: 0.0,
+                roll: 0.0,
+                is_volatile: 0,
+            })
+        };
+        assert!(arena.apply(8.0, &position(1)));
+        assert_eq!(arena.vehicle(1).unwrap().position.as_ref().unwrap().z, 2.0);
+
+        // Entities that are not vehicles of the battle do not get a state
+        assert!(!arena.apply(8.0, &position(99)));
+        assert!(arena.vehicle(99).is_none());
+    }
+
+    #[test]
+    fn reads_roster_from_json() {
+        let json = serde_json::json!({
+            "vehicles": {
+                "9383752": {
+                    "name": "_Dacite",
+                    "clanAbbrev": "CZ4R",
+                    "vehicleType": "czech:Cz04_T50_51",
+                    "team": 2,
+                },
+                "9383726": {
+                    "name": "Dracola69_",
+                    "vehicleType": "czech:Cz04_T50_51",
+                    "team": 2,
+                    "maxHealth": 1800,
+                },
+            }
+        });
+
+        let roster = get_roster(&json).unwrap();
+        assert_eq!(roster[&9383752].clan_abbrev, "CZ4R");
+        assert_eq!(roster[&9383752].max_health, None);
+        assert_eq!(roster[&9383726].max_health, Some(1800));
+
+        let arena = ArenaState::new(roster);
+        assert_eq!(arena.team(2).vehicles_total, 2);
+        assert!(get_roster(&serde_json::json!({})).is_err());
+    }
+}
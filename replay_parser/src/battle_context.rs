@@ -1,21 +1,24 @@
 use std::collections::HashMap;
 
-use byteorder::{ReadBytesExt, LE};
+use serde::Serialize;
+use wot_types::ArenaPeriod;
 
-use crate::PacketStream;
+use crate::events::parse_arena_period;
+use crate::{Packet, PacketStream, PacketType, ReplayError};
 
 /// Store information about a battle so that it can be used for debugging.
 pub struct BattleContext {
     players:    HashMap<i32, String>,
-    start_time: f32,
+    start_time: Option<f32>,
 }
 
 impl BattleContext {
     pub fn from(json: &[serde_json::Value], binary_stream: &[u8]) -> Self {
         let players = get_player_list(&json[0]);
 
+        let version = crate::utils::parse_version(&json[0]).unwrap_or_default();
         let packet_stream = PacketStream::new(binary_stream);
-        let start_time = get_battle_start_time(packet_stream);
+        let start_time = get_battle_start_time(packet_stream, version).ok();
 
         BattleContext { players, start_time }
     }
@@ -24,7 +27,8 @@ impl BattleContext {
         self.players.get(&id).map(|name| name.to_owned())
     }
 
-    pub fn get_start_time(&self) -> f32 {
+    /// `None` if the replay does not contain the start of the battle
+    pub fn get_start_time(&self) -> Option<f32> {
         self.start_time
     }
 }
@@ -42,13 +46,102 @@ fn get_player_list(json: &serde_json::Value) -> HashMap<i32, String> {
     player_list
 }
 
-pub fn get_battle_start_time(packet_stream: PacketStream) -> f32 {
+/// A period of the arena (loading, countdown, battle and post-battle) and when it started and ended, in the
+/// same time as [Packet::time](crate::Packet::time)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ArenaPeriodSpan {
+    pub period: ArenaPeriod,
+    pub start:  f32,
+
+    /// Start of the next period. `None` for the last period of the replay
+    pub end: Option<f32>,
+}
+
+/// Periods of the arena found in the `0x16` packets, in the order they were entered
+pub fn get_arena_periods(
+    packet_stream: PacketStream, version: [u16; 4],
+) -> Result<Vec<ArenaPeriodSpan>, ReplayError> {
+    let mut periods: Vec<ArenaPeriodSpan> = Vec::new();
+
     for packet in packet_stream {
-        let packet = packet.unwrap();
-        if packet.packet_type() == 0x16 && packet.payload().read_u32::<LE>().unwrap() == 3 {
-            return packet.time();
+        let packet = packet?;
+
+        if PacketType::from_raw(packet.packet_type(), version) != PacketType::ArenaPeriod {
+            continue;
+        }
+
+        let period = parse_period_packet(&packet, version)?;
+
+        if let Some(previous) = periods.last_mut() {
+            previous.end = Some(packet.time());
         }
+
+        periods.push(ArenaPeriodSpan {
+            period,
+            start: packet.time(),
+            end: None,
+        });
     }
 
-    -1.0
+    Ok(periods)
+}
+
+/// Time at which the arena entered `ArenaPeriod::Battle`. The stream is read until that period, so an
+/// error in the packets that come after it is not returned
+pub fn get_battle_start_time(packet_stream: PacketStream, version: [u16; 4]) -> Result<f32, ReplayError> {
+    for packet in packet_stream {
+        let packet = packet?;
+
+        if PacketType::from_raw(packet.packet_type(), version) == PacketType::ArenaPeriod
+            && parse_period_packet(&packet, version)? == ArenaPeriod::Battle
+        {
+            return Ok(packet.time());
+        }
+    }
+
+    Err(ReplayError::MissingBattleStart)
+}
+
+fn parse_period_packet(packet: &Packet, version: [u16; 4]) -> Result<ArenaPeriod, ReplayError> {
+    parse_arena_period(packet.payload(), version).map_err(|error| ReplayError::PacketParseError {
+        packet_id: packet.id(),
+        packet_type: packet.packet_type(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period_packet(period: u32, time: f32) -> Vec<u8> {
+        let mut packet = Vec::new();
+        packet.extend(4u32.to_le_bytes());
+        packet.extend(0x16u32.to_le_bytes());
+        packet.extend(time.to_le_bytes());
+        packet.extend(period.to_le_bytes());
+
+        packet
+    }
+
+    #[test]
+    fn stops_at_battle_start() {
+        // A period that does not exist, after the start of the battle
+        let mut buffer = period_packet(2, 1.0);
+        buffer.extend(period_packet(3, 5.0));
+        buffer.extend(period_packet(9, 6.0));
+
+        let version = [1, 16, 1, 0];
+        assert_eq!(
+            get_battle_start_time(PacketStream::new(&buffer), version).unwrap(),
+            5.0
+        );
+        assert!(get_arena_periods(PacketStream::new(&buffer), version).is_err());
+
+        let buffer = period_packet(2, 1.0);
+        assert!(matches!(
+            get_battle_start_time(PacketStream::new(&buffer), version),
+            Err(ReplayError::MissingBattleStart)
+        ));
+    }
 }
@@ -0,0 +1,128 @@
+//! The binary portion of a `.wotreplay` is zlib compressed and then encrypted with Blowfish (ECB mode). On
+//! top of that, each decrypted block is XOR'ed with the previous (already XOR'ed) block.
+
+use std::io::Read;
+
+use blowfish::cipher::KeyInit;
+use blowfish::{
+    cipher::{BlockDecrypt, BlockEncrypt},
+    Blowfish,
+};
+use byteorder::BE;
+
+use crate::ReplayError;
+
+pub const BLOCK_SIZE: usize = 8;
+
+/// Same key for every replay
+const WOT_BLOWFISH_KEY: &str = "DE72BEA0DE04BEB1DEFEBEEFDEADBEEF";
+
+pub fn wot_blowfish() -> Blowfish<BE> {
+    let mut wot_blowfish_key = [0; 16];
+    hex::decode_to_slice(WOT_BLOWFISH_KEY, &mut wot_blowfish_key).unwrap();
+
+    Blowfish::new_from_slice(&wot_blowfish_key).unwrap()
+}
+
+pub fn decrypt(input_blocks: &[u8]) -> Result<Vec<u8>, ReplayError> {
+    let bf = wot_blowfish();
+
+    let output_len = input_blocks.len() + (input_blocks.len() % BLOCK_SIZE);
+    let mut output_blocks = input_blocks.to_vec();
+    output_blocks.resize(output_len, 0);
+
+    for i in (0..output_blocks.len()).step_by(BLOCK_SIZE) {
+        let block = &mut output_blocks[i..(i + BLOCK_SIZE)];
+        bf.decrypt_block(block.into());
+    }
+
+    Ok(output_blocks)
+}
+
+#[inline]
+pub fn xor_decrypted(mut decrypted: Vec<u8>) -> Vec<u8> {
+    for i in BLOCK_SIZE..decrypted.len() {
+        decrypted[i] ^= decrypted[i - BLOCK_SIZE];
+    }
+
+    decrypted
+}
+
+/// Inverse of [xor_decrypted]. Each block is XOR'ed with the previous plaintext block
+pub fn xor_encrypt(mut plaintext: Vec<u8>) -> Vec<u8> {
+    for i in (BLOCK_SIZE..plaintext.len()).rev() {
+        plaintext[i] ^= plaintext[i - BLOCK_SIZE];
+    }
+
+    plaintext
+}
+
+/// Inverse of [decrypt]. The input is zero padded to a multiple of the block size
+pub fn encrypt(mut input_blocks: Vec<u8>) -> Vec<u8> {
+    let bf = wot_blowfish();
+
+    let padding = (BLOCK_SIZE - input_blocks.len() % BLOCK_SIZE) % BLOCK_SIZE;
+    input_blocks.resize(input_blocks.len() + padding, 0);
+
+    for block in input_blocks.chunks_exact_mut(BLOCK_SIZE) {
+        bf.encrypt_block(block.into());
+    }
+
+    input_blocks
+}
+
+/// Incremental version of [decrypt] followed by [xor_decrypted]. Reads whole blocks from the inner reader
+/// and hands out the decrypted bytes. A trailing partial block is treated as the end of the input.
+pub struct BlockDecryptor<R> {
+    inner:      R,
+    cipher:     Blowfish<BE>,
+    prev_block: [u8; BLOCK_SIZE],
+}
+
+impl<R: Read> BlockDecryptor<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            cipher: wot_blowfish(),
+            prev_block: [0; BLOCK_SIZE],
+        }
+    }
+
+    /// Fill `output` with as many whole decrypted blocks as possible and return the number of bytes
+    /// written. `0` means the encrypted input is exhausted.
+    pub fn read_blocks(&mut self, output: &mut [u8]) -> std::io::Result<usize> {
+        let max_len = output.len() - (output.len() % BLOCK_SIZE);
+        let filled = read_full(&mut self.inner, &mut output[..max_len])?;
+        let filled = filled - (filled % BLOCK_SIZE);
+
+        for block in output[..filled].chunks_exact_mut(BLOCK_SIZE) {
+            self.cipher.decrypt_block(block.into());
+
+            // XOR with the previous block. The first block is XOR'ed with zeroes, leaving it untouched
+            for (byte, prev) in block.iter_mut().zip(self.prev_block.iter()) {
+                *byte ^= prev;
+            }
+            self.prev_block.copy_from_slice(block);
+        }
+
+        Ok(filled)
+    }
+}
+
+/// Like `read_exact` but stops at EOF instead of returning an error. Returns the number of bytes read
+fn read_full<R: Read>(reader: &mut R, mut buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut total = 0;
+    while !buf.is_empty() {
+        match reader.read(buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                total += n;
+                buf = &mut buf[n..];
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(total)
+}
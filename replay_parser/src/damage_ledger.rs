@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use wot_types::AttackReason;
+
+use crate::events::{EntityMethod, EntityProperty, OnHealthChanged, VehicleMethods, VehicleProperties};
+use crate::{BattleEvent, ReplayError, ReplayParser};
+
+/// Fire ticks of a vehicle that are further apart than this (in seconds) are considered to be different
+/// fires. A fire deals damage about every second
+const FIRE_TICK_GAP: f32 = 2.0;
+
+/// Every instance of damage of the replay, built from the `OnHealthChanged` calls, and the totals of each
+/// vehicle.
+///
+/// Like the game, damage is attributed to the vehicle that caused it: the server already sends the other
+/// vehicle as the attacker of a ram, and fire damage that is sent without an attacker is attributed to the
+/// last vehicle that damaged the burning vehicle before the fire started.
+///
+/// ```no_run
+/// # use wot_replay_parser::{DamageLedger, ReplayParser};
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+/// let (ledger, _errors) = DamageLedger::from_replay(&replay).unwrap();
+///
+/// for (vehicle_id, totals) in ledger.totals() {
+///     println!("{vehicle_id}: dealt {} received {}", totals.dealt, totals.received);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DamageLedger {
+    instances: Vec<DamageInstance>,
+    totals:    HashMap<i32, DamageTotals>,
+
+    /// Last known health of each vehicle, for versions where `OnHealthChanged` does not have `old_health`
+    health: HashMap<i32, i32>,
+
+    /// Last vehicle that damaged each vehicle
+    last_attacker: HashMap<i32, i32>,
+
+    /// Vehicle the current fire of each vehicle is attributed to and time of its last tick
+    fires: HashMap<i32, (Option<i32>, f32)>,
+}
+
+/// Damage received by a vehicle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DamageInstance {
+    /// Time of the damage (same as [Packet::time](crate::Packet::time))
+    pub time:      f32,
+    pub victim_id: i32,
+
+    /// Vehicle the damage is attributed to. `None` if the damage was not caused by a vehicle (ex: drowning,
+    /// falling)
+    pub attacker_id: Option<i32>,
+
+    /// Health lost by the victim. Damage beyond the remaining health (ex: ammo rack explosion) is not
+    /// counted
+    pub amount: i32,
+
+    /// `None` if the reason is not known in this version of the parser. See `reason_id`
+    pub reason:    Option<AttackReason>,
+    pub reason_id: u8,
+}
+
+/// Damage dealt and received by a vehicle
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DamageTotals {
+    pub dealt:    i32,
+    pub received: i32,
+
+    /// Damage dealt by reason. Damage with an unknown reason is only counted in `dealt`
+    pub dealt_by_reason: HashMap<AttackReason, i32>,
+
+    /// Damage received by reason. Damage with an unknown reason is only counted in `received`
+    pub received_by_reason: HashMap<AttackReason, i32>,
+
+    /// Damage received from each vehicle
+    pub received_by_attacker: HashMap<i32, i32>,
+}
+
+impl DamageLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply every event of the replay that changes the health of a vehicle. Like
+    /// [ArenaState::from_replay](crate::ArenaState::from_replay), packets that cannot be parsed are skipped
+    /// and returned with the ledger
+    pub fn from_replay(replay: &ReplayParser) -> Result<(Self, Vec<ReplayError>), ReplayError> {
+        let mut ledger = DamageLedger::new();
+        let mut errors = Vec::new();
+
+        for event in replay.event_stream()?.timed() {
+            match event {
+                Ok((time, event)) => {
+                    ledger.apply(time, &event);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Ok((ledger, errors))
+    }
+
+    /// Apply an event to the ledger. Returns the damage caused by this event, if any. A change of health
+    /// whose amount cannot be known (no `old_health` and no health seen before) is not recorded
+    pub fn apply(&mut self, time: f32, event: &BattleEvent) -> Option<&DamageInstance> {
+        match event {
+            BattleEvent::EntityCreate(create) => {
+                for property in &create.properties {
+                    if let EntityProperty::Vehicle(VehicleProperties::Health(health)) = property {
+                        self.health.insert(create.entity_id, (*health).into());
+                    }
+                }
+
+                None
+            }
+            BattleEvent::EntityProperty(property_event) => {
+                if let EntityProperty::Vehicle(VehicleProperties::Health(health)) = property_event.property {
+                    self.health.insert(property_event.entity_id, health.into());
+                }
+
+                None
+            }
+            BattleEvent::EntityMethod(method_event) => match &method_event.method {
+                EntityMethod::Vehicle(VehicleMethods::OnHealthChanged(health_changed)) => {
+                    self.apply_health_changed(time, method_event.entity_id, health_changed)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn apply_health_changed(
+        &mut self, time: f32, victim_id: i32, health_changed: &OnHealthChanged,
+    ) -> Option<&DamageInstance> {
+        let new_health = i32::from(health_changed.new_health);
+        let old_health = health_changed
+            .old_health
+            .map(i32::from)
+            .or_else(|| self.health.get(&victim_id).copied());
+        self.health.insert(victim_id, new_health);
+
+        let amount = old_health? - new_health.max(0);
+        if amount <= 0 {
+            return None;
+        }
+
+        let reason = health_changed.reason();
+        let attacker_id = self.attribute(time, victim_id, health_changed.attacker_id, reason);
+
+        let instance = DamageInstance {
+            time,
+            victim_id,
+            attacker_id,
+            amount,
+            reason,
+            reason_id: health_changed.attack_reason,
+        };
+        self.add_to_totals(&instance);
+        self.instances.push(instance);
+
+        self.instances.last()
+    }
+
+    /// Vehicle the damage is attributed to
+    fn attribute(
+        &mut self, time: f32, victim_id: i32, attacker_id: i32, reason: Option<AttackReason>,
+    ) -> Option<i32> {
+        let attacker_id = (attacker_id > 0 && attacker_id != victim_id).then_some(attacker_id);
+
+        if reason != Some(AttackReason::Fire) {
+            if let Some(attacker_id) = attacker_id {
+                self.last_attacker.insert(victim_id, attacker_id);
+            }
+
+            return attacker_id;
+        }
+
+        let starter = match (attacker_id, self.fires.get(&victim_id)) {
+            (Some(attacker_id), _) => Some(attacker_id),
+            (None, Some((starter, last_tick))) if time - last_tick <= FIRE_TICK_GAP => *starter,
+            (None, _) => self.last_attacker.get(&victim_id).copied(),
+        };
+        self.fires.insert(victim_id, (starter, time));
+
+        starter
+    }
+
+    fn add_to_totals(&mut self, instance: &DamageInstance) {
+        let victim = self.totals.entry(instance.victim_id).or_default();
+        victim.received += instance.amount;
+        if let Some(reason) = instance.reason {
+            *victim.received_by_reason.entry(reason).or_default() += instance.amount;
+        }
+        if let Some(attacker_id) = instance.attacker_id {
+            *victim.received_by_attacker.entry(attacker_id).or_default() += instance.amount;
+        }
+
+        if let Some(attacker_id) = instance.attacker_id {
+            let attacker = self.totals.entry(attacker_id).or_default();
+            attacker.dealt += instance.amount;
+            if let Some(reason) = instance.reason {
+                *attacker.dealt_by_reason.entry(reason).or_default() += instance.amount;
+            }
+        }
+    }
+
+    /// Every instance of damage, in the order they were received
+    pub fn instances(&self) -> &[DamageInstance] {
+        &self.instances
+    }
+
+    /// Totals of each vehicle that dealt or received damage, keyed by vehicle ID
+    pub fn totals(&self) -> &HashMap<i32, DamageTotals> {
+        &self.totals
+    }
+
+    /// Totals of a vehicle. `None` if it did not deal or receive damage
+    pub fn vehicle_totals(&self, vehicle_id: i32) -> Option<&DamageTotals> {
+        self.totals.get(&vehicle_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EntityMethodEvent, EntityPropertyEvent};
+
+    fn health_changed(
+        victim_id: i32, old_health: Option<i16>, new_health: i16, attacker_id: i32, reason: u8,
+    ) -> BattleEvent {
+        BattleEvent::EntityMethod(EntityMethodEvent {
+            entity_id: victim_id,
+            method:    EntityMethod::Vehicle(VehicleMethods::OnHealthChanged(OnHealthChanged {
+                new_health,
+                old_health,
+                attacker_id,
+                attack_reason: reason,
+            })),
+        })
+    }
+
+    #[test]
+    fn attributes_fire_to_last_attacker() {
+        let mut ledger = DamageLedger::new();
+
+        let shot = ledger
+            .apply(1.0, &health_changed(1, Some(1000), 600, 2, 0))
+            .unwrap();
+        assert_eq!(shot.reason, Some(AttackReason::Shot));
+
+        let fire = ledger
+            .apply(2.0, &health_changed(1, Some(600), 550, 1, 1))
+            .unwrap();
+        assert_eq!(fire.attacker_id, Some(2));
+
+        // A shot from another vehicle while burning does not change who started the fire
+        ledger.apply(2.5, &health_changed(1, Some(550), 300, 3, 0));
+        let fire = ledger
+            .apply(3.0, &health_changed(1, Some(300), -20, 0, 1))
+            .unwrap();
+        assert_eq!((fire.attacker_id, fire.amount), (Some(2), 300));
+
+        let victim = ledger.vehicle_totals(1).unwrap();
+        assert_eq!(victim.received, 1000);
+        assert_eq!(victim.received_by_attacker[&2], 750);
+        assert_eq!(
+            ledger.vehicle_totals(2).unwrap().dealt_by_reason[&AttackReason::Fire],
+            350
+        );
+    }
+
+    #[test]
+    fn attributes_ram_to_the_other_vehicle() {
+        let mut ledger = DamageLedger::new();
+
+        // Both vehicles of a ram lose health, each with the other one as the attacker
+        let ram = ledger
+            .apply(1.0, &health_changed(1, Some(1000), 880, 2, 2))
+            .unwrap();
+        assert_eq!((ram.attacker_id, ram.reason), (Some(2), Some(AttackReason::Ram)));
+        ledger.apply(1.0, &health_changed(2, Some(1500), 1450, 1, 2));
+
+        // A fire started by the ram is attributed to the rammer
+        let fire = ledger
+            .apply(2.0, &health_changed(1, Some(880), 830, 0, 1))
+            .unwrap();
+        assert_eq!(fire.attacker_id, Some(2));
+
+        assert_eq!(
+            ledger.vehicle_totals(2).unwrap().dealt_by_reason[&AttackReason::Ram],
+            120
+        );
+        assert_eq!(
+            ledger.vehicle_totals(1).unwrap().dealt_by_reason[&AttackReason::Ram],
+            50
+        );
+        assert_eq!(ledger.vehicle_totals(1).unwrap().received, 170);
+    }
+
+    #[test]
+    fn uses_last_known_health_without_old_health() {
+        let mut ledger = DamageLedger::new();
+
+        // Nothing is known about the health of the vehicle before this change
+        assert!(ledger.apply(1.0, &health_changed(1, None, 900, 2, 0)).is_none());
+        assert!(ledger.instances().is_empty());
+
+        let shot = ledger.apply(2.0, &health_changed(1, None, 650, 2, 0)).unwrap();
+        assert_eq!(shot.amount, 250);
+
+        // The `Health` property also gives the health of the vehicle
+        let property = BattleEvent::EntityProperty(EntityPropertyEvent {
+            entity_id: 1,
+            property:  EntityProperty::Vehicle(VehicleProperties::Health(600)),
+        });
+        assert!(ledger.apply(3.0, &property).is_none());
+        let shot = ledger.apply(4.0, &health_changed(1, None, -30, 3, 0)).unwrap();
+        assert_eq!(shot.amount, 600);
+
+        assert_eq!(ledger.vehicle_totals(1).unwrap().received, 850);
+    }
+
+    #[test]
+    fn splits_fires_after_gap() {
+        let mut ledger = DamageLedger::new();
+
+        ledger.apply(1.0, &health_changed(1, Some(1000), 700, 2, 0));
+        let fire = ledger
+            .apply(2.0, &health_changed(1, Some(700), 650, 0, 1))
+            .unwrap();
+        assert_eq!(fire.attacker_id, Some(2));
+
+        // Vehicle 3 hits the burning vehicle. Ticks exactly `FIRE_TICK_GAP` apart are still the fire of 2
+        ledger.apply(3.0, &health_changed(1, Some(650), 500, 3, 0));
+        let fire = ledger
+            .apply(2.0 + FIRE_TICK_GAP, &health_changed(1, Some(500), 450, 0, 1))
+            .unwrap();
+        assert_eq!(fire.attacker_id, Some(2));
+
+        // Just past the gap, it is a new fire, attributed to the last vehicle that damaged it
+        let time = 2.0 + 2.0 * FIRE_TICK_GAP + 0.1;
+        let fire = ledger
+            .apply(time, &health_changed(1, Some(450), 400, 0, 1))
+            .unwrap();
+        assert_eq!(fire.attacker_id, Some(3));
+    }
+}
@@ -0,0 +1,97 @@
+// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
+use phf::phf_map;
+
+use super::EntityType;
+
+pub static ARENA_ENTITY_METHODS: phf::Map<&str, &[(EntityType, &[&str])]> = phf_map! {
+    "0_9_13_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_14_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_15_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_16_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_17_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_18_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_19_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_20_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_21_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_22_0" => &[(EntityType::AreaDestructibles, &[])],
+    "0_9_23_0" => &[(EntityType::AreaDestructibles, &[])],
+    "1_0_0_0" => &[(EntityType::AreaDestructibles, &[])],
+    "1_0_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_0_2_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_1_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_2_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[]), (EntityType::EventPointsPickup, &[])],
+    "1_3_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_4_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_4_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_5_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_5_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_6_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_6_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_7_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_7_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_8_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_9_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_10_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_10_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_11_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_11_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_12_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_12_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_13_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_14_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_14_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_15_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_16_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_16_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_17_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_17_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_18_0_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+    "1_18_1_0" => &[(EntityType::AreaDestructibles, &[]), (EntityType::DestructibleEntity, &["OnHealthChanged", "ShowDamageFromExplosion", "ShowDamageFromShot"]), (EntityType::SectorBase, &[]), (EntityType::Sector, &["ShowBomb"]), (EntityType::ProtectionZone, &[]), (EntityType::BasicMine, &[]), (EntityType::StepRepairPoint, &[])],
+};
+
+pub static ARENA_ENTITY_PROPS: phf::Map<&str, &[(EntityType, &[&str])]> = phf_map! {
+    "0_9_13_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_14_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_15_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_16_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_17_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_18_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_19_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_20_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_21_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_22_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "0_9_23_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "1_0_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"])],
+    "1_0_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_0_2_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_1_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_2_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"]), (EntityType::EventPointsPickup, &["IsRemoving", "ModelType", "EventPoints", "VehicleID"])],
+    "1_3_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_4_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_4_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_5_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_5_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_6_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_6_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_7_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_7_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_8_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_9_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_10_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_10_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_11_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_11_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_12_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_12_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_13_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_14_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_14_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_15_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_16_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_16_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_17_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_17_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_18_0_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+    "1_18_1_0" => &[(EntityType::AreaDestructibles, &["DestroyedModules", "DestroyedFragiles", "FallenColumns", "FallenTrees"]), (EntityType::DestructibleEntity, &["IsActive", "Team", "DestructibleEntityID", "IsDestructibleDestroyed", "TypeID", "Health", "MaxHealth", "LinkedMapActivities", "DamageStickers"]), (EntityType::SectorBase, &["IsActive", "Team", "BaseID", "SectorID", "PointsPercentage", "CapturingStopped", "IsCaptured", "InvadersCount", "MaxPoints", "OnDamageCooldownTime", "Radius", "ExpectedCaptureTime"]), (EntityType::Sector, &["GroupID", "SectorID", "PlayerGroup", "IDInPlayerGroup", "Team", "State", "LengthX", "LengthZ", "TransitionTime", "EndOfTransitionPeriod"]), (EntityType::ProtectionZone, &["ZoneID", "Team", "IsActive", "LengthX", "LengthZ"]), (EntityType::BasicMine, &["IsDetonated", "IsMarkerEnabled", "EquipmentID", "OwnerVehicleID"]), (EntityType::StepRepairPoint, &["Team", "Radius"])],
+};
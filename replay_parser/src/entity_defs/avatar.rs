@@ -1,6 +1,7 @@
+// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
 use phf::phf_map;
 
-
 pub static AVATAR_METHODS: phf::Map<&str, &[&str]> = phf_map! {
     "0_9_12_0" => &["OnAutoAimVehicleLost", "OnKickedFromArena", "UpdateVehicleOptionalDeviceStatus", "OnRoundFinished", "UpdateVehicleSetting", "UpdateResourceAmount", "ReceiveHorn", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleAmmo", "ShowOwnVehicleHitDirection", "UpdateGasAttackState", "ShowVehicleDamageInfo", "UpdateVehicleGunReloadTime", "UpdateVehicleMiscStatus", "OnSwitchViewpoint", "StopTracer", "UpdateGunMarker", "UpdateOwnVehiclePosition", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdateBomberTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "Update", "OnKickedFromServer", "OnTokenReceived", "OnIGRTypeChanged", "ReceiveAccountStats", "RedrawVehicleOnRespawn", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvent", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats"],
     "0_9_13_0" => &["OnAutoAimVehicleLost", "OnKickedFromArena", "UpdateVehicleOptionalDeviceStatus", "OnRoundFinished", "UpdateVehicleSetting", "UpdateResourceAmount", "ReceiveHorn", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleAmmo", "ShowOwnVehicleHitDirection", "UpdateGasAttackState", "ShowVehicleDamageInfo", "UpdateVehicleGunReloadTime", "UpdateVehicleMiscStatus", "OnSwitchViewpoint", "StopTracer", "UpdateGunMarker", "UpdateOwnVehiclePosition", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdateBomberTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "Update", "OnKickedFromServer", "OnTokenReceived", "OnIGRTypeChanged", "ReceiveAccountStats", "RedrawVehicleOnRespawn", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvent", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats"],
@@ -30,6 +31,7 @@ pub static AVATAR_METHODS: phf::Map<&str, &[&str]> = phf_map! {
     "1_7_1_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "SyncVehicleAttrs", "ExplodeVehicleBeforeRespawn", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress"],
     "1_8_0_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "SyncVehicleAttrs", "ExplodeVehicleBeforeRespawn", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress"],
     "1_9_0_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "SyncVehicleAttrs", "ExplodeVehicleBeforeRespawn", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnStepRepairPointAction", "WelcomeToSector", "UpdateVehicleGunReloadTime", "ShowVehicleDamageInfo", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "ExternalTrigger", "ShowHint", "HideHint", "RemoveVehicle", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress", "UpdateScenarioTimer", "SendPlayerBattleLogNotification", "SendEquipmentBattleLogNotification", "NotifyClients"],
+    "1_9_1_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "SyncVehicleAttrs", "ExplodeVehicleBeforeRespawn", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress"],
     "1_10_0_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "CloseChooseSpawnKeyPointsWindow", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "ExplodeVehicleBeforeRespawn", "RemoveVehicle", "ReceiveSpawnKeyPointsCloseTime", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "SyncVehicleAttrs", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnLootAction", "OnVehicleHealthChanged", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "UpdateTeammateLives", "OnTeamLivesRestored", "ReceiveAvailableSpawnKeyPoints", "ReceiveTeamSpawnKeyPoints", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress", "UpdateSpawnList"],
     "1_10_1_0" => &["BroadcastAFKWarning", "NotifyCannotStartRecovering", "NotifyCancelled", "CloseChooseSpawnKeyPointsWindow", "ShowAuraSoulsWarningHint", "ShowAuraHealthWarningHint", "HideAuraWarningHint", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "ExplodeVehicleBeforeRespawn", "ReceiveSpawnKeyPointsCloseTime", "ShowAuraVictimMarkerIcon", "HideAuraVictimMarkerIcon", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "SyncVehicleAttrs", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnLootAction", "OnVehicleHealthChanged", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "RemoveVehicle", "ReceiveAvailableSpawnKeyPoints", "ReceiveTeamSpawnKeyPoints", "NotifyClients", "ShowHint", "HideHint", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress", "UpdateScenarioTimer", "SendPlayerBattleLogNotification", "SendEquipmentBattleLogNotification", "UpdateSpawnList"],
     "1_11_0_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "CloseChooseSpawnKeyPointsWindow", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRoundFinished", "ExplodeVehicleBeforeRespawn", "RemoveVehicle", "ReceiveSpawnKeyPointsCloseTime", "UpdateTargetVehicleID", "ShowDestructibleShotResults", "OnDestructibleDestroyed", "UpdateResourceAmount", "OnSectorBaseAction", "UpdateVehicleOptionalDeviceStatus", "OnRepairPointAction", "SyncVehicleAttrs", "UpdateVehicleHealth", "UpdateVehicleSetting", "UpdateGasAttackState", "OnLootAction", "OnVehicleHealthChanged", "OnStepRepairPointAction", "ShowVehicleDamageInfo", "WelcomeToSector", "UpdateVehicleGunReloadTime", "UpdateState", "UpdateVehicleClipReloadTime", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "UpdateGunMarker", "UpdateOwnVehiclePosition", "BattleEventsSummary", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "UpdatePlaneTrajectory", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "ReceiveAvailableSpawnKeyPoints", "ReceiveTeamSpawnKeyPoints", "ExternalTrigger", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "UpdateDualGunState", "OnBootcampEvent", "UpdateVehicleMiscStatus", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnBattleEvents", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress", "UpdateSpawnList"],
@@ -51,8 +53,7 @@ pub static AVATAR_METHODS: phf::Map<&str, &[&str]> = phf_map! {
     "1_20_0_0" => &["NotifyCannotStartRecovering", "NotifyCancelled", "UpdatePlayerLives", "EnteringProtectionZone", "LeavingProtectionZone", "ProtectionZoneShooting", "OnSectorShooting", "OnAutoAimVehicleLost", "OnKickedFromArena", "OnXPUpdated", "OnRankUpdate", "ExplodeVehicleBeforeRespawn", "RemoveVehicle", "UpdateTargetVehicleID", "OnDestructibleDestroyed", "UpdateResourceAmount", "UpdateVehicleQuickShellChanger", "OnSectorBaseAction", "OnRepairPointAction", "UpdateVehicleHealth", "UpdateVehicleSetting", "OnVehicleHealthChanged", "OnStepRepairPointAction", "WelcomeToSector", "EnemySPGHit", "UpdateState", "OnCombatEquipmentShotLaunched", "UpdateVehicleAmmo", "OnSwitchViewpoint", "StopTracer", "OnCollisionWithVehicle", "OnFrictionWithVehicle", "ShowOwnVehicleHitDirection", "EnemySPGShotSound", "ShowHittingArea", "ShowCarpetBombing", "UpdateTargetingInfo", "ShowTracer", "OnChatAction", "MessengerOnActionByServerChat2", "OnCmdResponse", "OnCmdResponseExt", "ProcessInvitations", "OnTokenReceived", "UpdateTeamsHealthPercentage", "RedrawVehicleOnRespawn", "UpdateRespawnVehicles", "UpdateRespawnCooldowns", "UpdateRespawnInfo", "UpdateVehicleLimits", "OnTeamLivesRestored", "ExternalTrigger", "ShowDestructibleShotResults", "Update", "OnKickedFromServer", "OnIGRTypeChanged", "ReceiveAccountStats", "OnBootcampEvent", "ShowOtherVehicleDamagedDevices", "ShowShotResults", "ShowDevelopmentInfo", "ExplodeProjectile", "OnRoundFinished", "OnBattleEvents", "BattleEventsSummary", "UpdateArena", "UpdatePositions", "ReceivePhysicsDebugInfo", "UpdateCarriedFlagPositions", "ReceiveNotification", "UpdateAvatarPrivateStats", "OnSmoke", "UpdateQuestProgress", "HandleScriptEventFromServer", "SetUpdatedGoodiesSnapshot", "UpdateSpawnList"],
 };
 
-
-pub static AVATAR_PROPS: phf::Map<&str, &'static [&'static str]> = phf_map! {
+pub static AVATAR_PROPS: phf::Map<&str, &[&str]> = phf_map! {
     "0_9_12_0" => &["ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "IsOwnVehicleContactingWorld", "NormalisedRPMPacked", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "Name", "ArenaExtraData", "ClientCtx"],
     "0_9_13_0" => &["ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "IsOwnVehicleContactingWorld", "NormalisedRPMPacked", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "Name", "ArenaExtraData", "ClientCtx"],
     "0_9_14_0" => &["ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "Name", "ArenaExtraData", "ClientCtx"],
@@ -65,22 +66,23 @@ pub static AVATAR_PROPS: phf::Map<&str, &'static [&'static str]> = phf_map! {
     "0_9_21_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
     "0_9_22_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
     "0_9_23_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_0_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_0_1_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_0_2_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_1_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx", "QuestProgressDescriptor"],
-    "1_2_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_3_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_4_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_4_1_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_5_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_5_1_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_6_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_6_1_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
-    "1_7_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
-    "1_7_1_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx", "PlayLimits"],
-    "1_8_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
-    "1_9_0_0" =>  &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
+    "1_0_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_0_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_0_2_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_1_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx", "QuestProgressDescriptor"],
+    "1_2_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_3_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_4_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_4_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_5_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_5_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_6_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_6_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "ArenaExtraData", "ClientCtx"],
+    "1_7_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
+    "1_7_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx", "PlayLimits"],
+    "1_8_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
+    "1_9_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
+    "1_9_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
     "1_10_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
     "1_10_1_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
     "1_11_0_0" => &["IsObserverFPV", "ObserverFPVControlMode", "NumOfObservers", "ArenaBonusType", "ArenaGuiType", "WeatherPresetID", "TkillIsSuspected", "Team", "IsObserverBothTeams", "IsGunLocked", "OwnVehicleGear", "IsHistoricallyAccurate", "DenunciationsLeft", "ArenaTypeID", "PlayerVehicleID", "ArenaUniqueID", "OwnVehicleAuxPhysicsData", "PlayLimits", "RemoteCamera", "Name", "SessionID", "ArenaExtraData", "ClientCtx"],
@@ -0,0 +1,7708 @@
+// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
+use phf::phf_map;
+
+use super::DefType::{self, *};
+use super::{EntitySignatures, EntityType};
+
+/// Signatures of the entities of each version
+pub static ENTITY_SIGNATURES: phf::Map<&str, &[(EntityType, EntitySignatures)]> = phf_map! {
+    "0_9_13_0" => &[(EntityType::Account, ACCOUNT_0), (EntityType::Avatar, AVATAR_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_0), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_0), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0)],
+    "0_9_14_0" => &[(EntityType::Account, ACCOUNT_1), (EntityType::Avatar, AVATAR_1), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_1), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_0), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0)],
+    "0_9_15_0" => &[(EntityType::Account, ACCOUNT_2), (EntityType::Avatar, AVATAR_2), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_1), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_2), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_0)],
+    "0_9_16_0" => &[(EntityType::Account, ACCOUNT_3), (EntityType::Avatar, AVATAR_3), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_3), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0)],
+    "0_9_17_0" => &[(EntityType::Account, ACCOUNT_4), (EntityType::Avatar, AVATAR_4), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_4), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0)],
+    "0_9_18_0" => &[(EntityType::Account, ACCOUNT_5), (EntityType::Avatar, AVATAR_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_5), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0)],
+    "0_9_19_0" => &[(EntityType::Account, ACCOUNT_6), (EntityType::Avatar, AVATAR_6), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_5), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_1)],
+    "0_9_20_0" => &[(EntityType::Account, ACCOUNT_7), (EntityType::Avatar, AVATAR_7), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_2), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_6), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::OfflineFlag, OFFLINE_FLAG_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::BossLandmine, BOSS_LANDMINE_0), (EntityType::HalloweenHealthPowerup, HALLOWEEN_HEALTH_POWERUP_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_2), (EntityType::HalloweenSupplyDrop, HANGAR_VEHICLE_0)],
+    "0_9_21_0" => &[(EntityType::Account, ACCOUNT_8), (EntityType::Avatar, AVATAR_8), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_7), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_0), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::LootBoxObject, LOOT_BOX_OBJECT_0), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_0), (EntityType::DormantSelectableObject, DORMANT_SELECTABLE_OBJECT_0), (EntityType::GroundLightNewYearSceneObject, GROUND_LIGHT_NEW_YEAR_SCENE_OBJECT_0), (EntityType::PosterSelectableObject, HANGAR_VEHICLE_0)],
+    "0_9_22_0" => &[(EntityType::Account, ACCOUNT_9), (EntityType::Avatar, AVATAR_8), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_7), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_0), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_0), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_0), (EntityType::DormantSelectableObject, DORMANT_SELECTABLE_OBJECT_0), (EntityType::GroundLightNewYearSceneObject, GROUND_LIGHT_NEW_YEAR_SCENE_OBJECT_0), (EntityType::PosterSelectableObject, HANGAR_VEHICLE_0)],
+    "0_9_23_0" => &[(EntityType::Account, ACCOUNT_10), (EntityType::Avatar, AVATAR_9), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_8), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HANGAR_VEHICLE_0), (EntityType::ClientSelectableEasterEgg, HANGAR_VEHICLE_0)],
+    "1_0_0_0" => &[(EntityType::Account, ACCOUNT_10), (EntityType::Avatar, AVATAR_9), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_0), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_8), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HANGAR_VEHICLE_0), (EntityType::ClientSelectableEasterEgg, HANGAR_VEHICLE_0)],
+    "1_0_1_0" => &[(EntityType::Account, ACCOUNT_11), (EntityType::Avatar, AVATAR_10), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_9), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HANGAR_VEHICLE_0), (EntityType::ClientSelectableEasterEgg, HANGAR_VEHICLE_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_0), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0)],
+    "1_0_2_0" => &[(EntityType::Account, ACCOUNT_12), (EntityType::Avatar, AVATAR_10), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_9), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_0), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0)],
+    "1_1_0_0" => &[(EntityType::Account, ACCOUNT_13), (EntityType::Avatar, AVATAR_11), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_9), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, HANGAR_VEHICLE_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_0), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0)],
+    "1_2_0_0" => &[(EntityType::Account, ACCOUNT_14), (EntityType::Avatar, AVATAR_12), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_10), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_1), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_0), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::EventPointsPickup, EVENT_POINTS_PICKUP_0), (EntityType::EventPointsBase, EVENT_POINTS_BASE_0)],
+    "1_3_0_0" => &[(EntityType::Account, ACCOUNT_15), (EntityType::Avatar, AVATAR_13), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_11), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_1), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_1), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_1), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::CameraTarget, CAMERA_TARGET_0), (EntityType::NewYearVisualObject, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_1), (EntityType::NewYearToyObject, NEW_YEAR_TOY_OBJECT_0), (EntityType::NewYearPoster, NEW_YEAR_POSTER_0)],
+    "1_4_0_0" => &[(EntityType::Account, ACCOUNT_16), (EntityType::Avatar, AVATAR_13), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_11), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0)],
+    "1_4_1_0" => &[(EntityType::Account, ACCOUNT_17), (EntityType::Avatar, AVATAR_13), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_12), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::HangarDog, HANGAR_DOG_0), (EntityType::DogBowl, HANGAR_VEHICLE_0), (EntityType::HangarPortalGates, HANGAR_PORTAL_GATES_0)],
+    "1_5_0_0" => &[(EntityType::Account, ACCOUNT_18), (EntityType::Avatar, AVATAR_13), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_12), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0)],
+    "1_5_1_0" => &[(EntityType::Account, ACCOUNT_19), (EntityType::Avatar, AVATAR_14), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_13), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0), (EntityType::CustomizableHB1SceneObject, CAMERA_TARGET_0), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::ItemPickup, ITEM_PICKUP_0), (EntityType::HB1HangarVehicle, H_B1_HANGAR_VEHICLE_0), (EntityType::AnimatedModelObject, ANIMATED_MODEL_OBJECT_0), (EntityType::AnimatedScene, ANIMATED_SCENE_0), (EntityType::AttackBomber, ATTACK_BOMBER_0)],
+    "1_6_0_0" => &[(EntityType::Account, ACCOUNT_20), (EntityType::Avatar, AVATAR_15), (EntityType::ArenaInfo, ARENA_INFO_0), (EntityType::TeamBase, TEAM_BASE_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_14), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0), (EntityType::ClientSelectableFrontLineHangarObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableMarathonHangarObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableAdventCalendarHangarObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRacingHangarObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableMiniGamesHangarObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableMusicStageObject, CLIENT_SELECTABLE_MUSIC_STAGE_OBJECT_0), (EntityType::MusicStageCameraObject, MUSIC_STAGE_CAMERA_OBJECT_0), (EntityType::MusicStageCameraTarget, MUSIC_STAGE_CAMERA_TARGET_0), (EntityType::MusicStage, MUSIC_STAGE_0), (EntityType::RaceSoundZone, GAME_OBJECT_ENTITY_0)],
+    "1_6_1_0" => &[(EntityType::Account, ACCOUNT_21), (EntityType::Avatar, AVATAR_16), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_15), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0)],
+    "1_7_0_0" => &[(EntityType::Account, ACCOUNT_22), (EntityType::Avatar, AVATAR_17), (EntityType::ArenaInfo, HANGAR_VEHICLE_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_15), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_1), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_1), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_2), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::CameraTarget, CAMERA_TARGET_0), (EntityType::NewYearVisualObject, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_2), (EntityType::NewYearToyObject, NEW_YEAR_TOY_OBJECT_1), (EntityType::PianoMusician, PIANO_MUSICIAN_0), (EntityType::NewYearTalismanBaseObject, NEW_YEAR_TALISMAN_BASE_OBJECT_0), (EntityType::NewYearTalismanObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanCamera, NEW_YEAR_TALISMAN_CAMERA_0), (EntityType::NewYearTalismanEntryObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanPreviewObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanPreviewCamera, NEW_YEAR_TALISMAN_PREVIEW_CAMERA_0)],
+    "1_7_1_0" => &[(EntityType::Account, ACCOUNT_23), (EntityType::Avatar, AVATAR_18), (EntityType::ArenaInfo, HANGAR_VEHICLE_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_15), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::HeroTank, HERO_TANK_2), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0)],
+    "1_8_0_0" => &[(EntityType::Account, ACCOUNT_24), (EntityType::Avatar, AVATAR_19), (EntityType::ArenaInfo, HANGAR_VEHICLE_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_15), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::HeroTank, HERO_TANK_2), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0)],
+    "1_9_0_0" => &[(EntityType::Avatar, AVATAR_20), (EntityType::ArenaInfo, HANGAR_VEHICLE_0), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_3), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_16), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_0), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_3), (EntityType::ClientSelectableCameraVehicle, CLIENT_SELECTABLE_CAMERA_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_3), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::CustomizableSE20SceneObject, CAMERA_TARGET_0), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_0), (EntityType::ItemPickup, ITEM_PICKUP_0), (EntityType::EventControlPoint, EVENT_CONTROL_POINT_0), (EntityType::SE20HangarVehicle, H_B1_HANGAR_VEHICLE_0), (EntityType::SE20ClientSelectableObject, S_E20_CLIENT_SELECTABLE_OBJECT_0), (EntityType::AnimatedScene, ANIMATED_SCENE_0), (EntityType::ActionEffects, ACTION_EFFECTS_0), (EntityType::AttackBomber, ATTACK_BOMBER_1), (EntityType::DeathZone, DEATH_ZONE_0), (EntityType::PersonalDeathZonesHelper, PERSONAL_DEATH_ZONES_HELPER_0), (EntityType::SE20HeroTank, HANGAR_VEHICLE_0)],
+    "1_10_0_0" => &[(EntityType::Avatar, AVATAR_21), (EntityType::ArenaInfo, ARENA_INFO_1), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_17), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_4), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::PhotoZone, HANGAR_VEHICLE_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_1), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableAdventCalendarObject, HANGAR_VEHICLE_0), (EntityType::EventPortal, HANGAR_VEHICLE_0), (EntityType::GameEventEntryPoint, NEW_YEAR_POSTER_0)],
+    "1_10_1_0" => &[(EntityType::Avatar, AVATAR_22), (EntityType::ArenaInfo, ARENA_INFO_2), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_18), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_3), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_1), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::EnvironmentSwitcher, HANGAR_VEHICLE_0), (EntityType::HalloweenHangarTank, HALLOWEEN_HANGAR_TANK_0), (EntityType::HalloweenHangarTankHeadlight, HALLOWEEN_HANGAR_TANK_HEADLIGHT_0), (EntityType::ItemPickup, ITEM_PICKUP_0), (EntityType::EventControlPoint, EVENT_CONTROL_POINT_0)],
+    "1_11_0_0" => &[(EntityType::Avatar, AVATAR_23), (EntityType::ArenaInfo, ARENA_INFO_3), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_17), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_1), (EntityType::HeroTank, HERO_TANK_5), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_1), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_2), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_1), (EntityType::CameraTarget, CAMERA_TARGET_0), (EntityType::NewYearVisualObject, NEW_YEAR_VISUAL_OBJECT_1), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_2), (EntityType::NewYearToyObject, NEW_YEAR_TOY_OBJECT_2), (EntityType::PianoMusician, PIANO_MUSICIAN_0), (EntityType::NewYearTalismanBaseObject, NEW_YEAR_TALISMAN_BASE_OBJECT_0), (EntityType::NewYearTalismanObject, NEW_YEAR_TALISMAN_OBJECT_0), (EntityType::NewYearTalismanCamera, NEW_YEAR_TALISMAN_CAMERA_0), (EntityType::NewYearBaseEntryObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanEntryObject, NEW_YEAR_TALISMAN_ENTRY_OBJECT_0), (EntityType::NewYearCelebrityEntryObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanPreviewObject, HANGAR_VEHICLE_0), (EntityType::NewYearTalismanPreviewCamera, NEW_YEAR_TALISMAN_PREVIEW_CAMERA_0), (EntityType::NewYearCelebrityObject, NEW_YEAR_CELEBRITY_OBJECT_0), (EntityType::NewYearIcicleObject, NEW_YEAR_ICICLE_OBJECT_0), (EntityType::NewYearIciclesIllumination, NEW_YEAR_ICICLES_ILLUMINATION_0)],
+    "1_11_1_0" => &[(EntityType::Avatar, AVATAR_23), (EntityType::ArenaInfo, ARENA_INFO_3), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_19), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_1), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_0)],
+    "1_12_0_0" => &[(EntityType::Avatar, AVATAR_24), (EntityType::ArenaInfo, ARENA_INFO_4), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_20), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::GameObjectEntity, GAME_OBJECT_ENTITY_1), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_0), (EntityType::ClientSelectableBlackMarketObject, HANGAR_VEHICLE_0)],
+    "1_12_1_0" => &[(EntityType::Avatar, AVATAR_24), (EntityType::ArenaInfo, ARENA_INFO_4), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_21), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_0)],
+    "1_13_0_0" => &[(EntityType::Avatar, AVATAR_25), (EntityType::ArenaInfo, ARENA_INFO_4), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_22), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_0)],
+    "1_14_0_0" => &[(EntityType::Avatar, AVATAR_26), (EntityType::ArenaInfo, ARENA_INFO_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_23), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::EmptyEntity, HANGAR_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::HangarBirthdayPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0), (EntityType::EventVehicle, EVENT_VEHICLE_0), (EntityType::EventPortal, EVENT_PORTAL_0)],
+    "1_14_1_0" => &[(EntityType::Avatar, AVATAR_27), (EntityType::ArenaInfo, ARENA_INFO_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_24), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_3), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::YearHareAffairEntryPoint, YEAR_HARE_AFFAIR_ENTRY_POINT_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0), (EntityType::EnvironmentSwitcher, HANGAR_VEHICLE_0), (EntityType::HalloweenHangarTank, HALLOWEEN_HANGAR_TANK_1), (EntityType::HalloweenHangarTankHeadlight, HALLOWEEN_HANGAR_TANK_HEADLIGHT_0), (EntityType::ItemPickup, ITEM_PICKUP_0), (EntityType::EventControlPoint, EVENT_CONTROL_POINT_0)],
+    "1_15_0_0" => &[(EntityType::Avatar, AVATAR_28), (EntityType::ArenaInfo, ARENA_INFO_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_25), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_1), (EntityType::HeroTank, HERO_TANK_5), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0), (EntityType::CustomizableNewYearSceneObject, CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_2), (EntityType::CameraAnchorObject, CAMERA_ANCHOR_OBJECT_2), (EntityType::CameraTarget, CAMERA_TARGET_0), (EntityType::NewYearVisualObject, NEW_YEAR_VISUAL_OBJECT_1), (EntityType::NewYearSelectableObject, NEW_YEAR_SELECTABLE_OBJECT_3), (EntityType::NewYearToyObject, NEW_YEAR_TOY_OBJECT_2), (EntityType::NewYearBaseEntryObject, NEW_YEAR_BASE_ENTRY_OBJECT_0), (EntityType::NewYearCelebrityEntryObject, NEW_YEAR_POSTER_0), (EntityType::NewYearCelebrityObject, NEW_YEAR_CELEBRITY_OBJECT_1), (EntityType::NewYearJukeboxObject, NEW_YEAR_JUKEBOX_OBJECT_0), (EntityType::NewYearJukeboxSelectableObject, NEW_YEAR_JUKEBOX_SELECTABLE_OBJECT_0), (EntityType::NewYearHeroTank, CAMERA_TARGET_0)],
+    "1_16_0_0" => &[(EntityType::Avatar, AVATAR_28), (EntityType::ArenaInfo, ARENA_INFO_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_25), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0)],
+    "1_16_1_0" => &[(EntityType::Avatar, AVATAR_29), (EntityType::ArenaInfo, ARENA_INFO_5), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_26), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_0), (EntityType::AreaOfEffect, AREA_OF_EFFECT_0), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRTSObject, CLIENT_SELECTABLE_R_T_S_OBJECT_0), (EntityType::ClientSelectableRTSLeaderboard, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_2), (EntityType::BasicMine, BASIC_MINE_0)],
+    "1_17_0_0" => &[(EntityType::Avatar, AVATAR_30), (EntityType::ArenaInfo, ARENA_INFO_6), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_27), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_1), (EntityType::AreaOfEffect, AREA_OF_EFFECT_1), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::AttackArtilleryFort, HANGAR_VEHICLE_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableCGFObject, CLIENT_SELECTABLE_C_G_F_OBJECT_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0)],
+    "1_17_1_0" => &[(EntityType::Avatar, AVATAR_31), (EntityType::ArenaInfo, ARENA_INFO_7), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_27), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_0), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_1), (EntityType::AreaOfEffect, AREA_OF_EFFECT_1), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::AttackArtilleryFort, ATTACK_ARTILLERY_FORT_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::ClientSelectableWotAnniversaryObject, HANGAR_VEHICLE_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0)],
+    "1_18_0_0" => &[(EntityType::Avatar, AVATAR_32), (EntityType::ArenaInfo, ARENA_INFO_7), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_28), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_1), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::EmptyEntity, HANGAR_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamInfo, TEAM_INFO_1), (EntityType::AreaOfEffect, AREA_OF_EFFECT_1), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::AttackArtilleryFort, ATTACK_ARTILLERY_FORT_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0), (EntityType::EventVehicle, EVENT_VEHICLE_1), (EntityType::EventPortal, EVENT_PORTAL_0)],
+    "1_18_1_0" => &[(EntityType::Avatar, AVATAR_33), (EntityType::ArenaInfo, ARENA_INFO_7), (EntityType::ClientSelectableObject, CLIENT_SELECTABLE_OBJECT_4), (EntityType::HangarVehicle, HANGAR_VEHICLE_0), (EntityType::Vehicle, VEHICLE_28), (EntityType::AreaDestructibles, AREA_DESTRUCTIBLES_1), (EntityType::OfflineEntity, HANGAR_VEHICLE_0), (EntityType::Flock, FLOCK_0), (EntityType::FlockExotic, FLOCK_EXOTIC_1), (EntityType::Login, LOGIN_1), (EntityType::DetachedTurret, DETACHED_TURRET_1), (EntityType::BootcampAccount, BOOTCAMP_ACCOUNT_0), (EntityType::DebugDrawEntity, DEBUG_DRAW_ENTITY_0), (EntityType::ClientSelectableCameraObject, CLIENT_SELECTABLE_CAMERA_OBJECT_4), (EntityType::ClientSelectableCameraVehicle, NEW_YEAR_VISUAL_OBJECT_0), (EntityType::ClientSelectableWebLinksOpener, CLIENT_SELECTABLE_WEB_LINKS_OPENER_0), (EntityType::ClientSelectableEasterEgg, CLIENT_SELECTABLE_EASTER_EGG_2), (EntityType::EmptyEntity, HANGAR_VEHICLE_0), (EntityType::HeroTank, HERO_TANK_2), (EntityType::PlatoonTank, PLATOON_TANK_0), (EntityType::PlatoonLighting, PLATOON_LIGHTING_0), (EntityType::SectorBase, SECTOR_BASE_0), (EntityType::Sector, SECTOR_0), (EntityType::DestructibleEntity, DESTRUCTIBLE_ENTITY_1), (EntityType::StepRepairPoint, STEP_REPAIR_POINT_0), (EntityType::ProtectionZone, PROTECTION_ZONE_0), (EntityType::HangarPoster, NEW_YEAR_POSTER_0), (EntityType::TeamBaseRecapturable, TEAM_BASE_RECAPTURABLE_0), (EntityType::TeamInfo, TEAM_INFO_1), (EntityType::AreaOfEffect, AREA_OF_EFFECT_2), (EntityType::AttackBomber, HANGAR_VEHICLE_0), (EntityType::AttackArtilleryFort, ATTACK_ARTILLERY_FORT_0), (EntityType::PersonalDeathZone, HANGAR_VEHICLE_0), (EntityType::ClientSelectableRankedObject, HANGAR_VEHICLE_0), (EntityType::ClientSelectableHangarsSwitcher, CLIENT_SELECTABLE_HANGARS_SWITCHER_0), (EntityType::StaticDeathZone, STATIC_DEATH_ZONE_1), (EntityType::BasicMine, BASIC_MINE_0), (EntityType::ApplicationPoint, APPLICATION_POINT_0)],
+};
+
+const TYPE_0: DefType = FixedDict { is_nullable: false, fields: &[("clusterCCU", U32), ("regionCCU", U32)] };
+
+const TYPE_1: DefType = FixedDict { is_nullable: false, fields: &[("requestID", I64), ("action", U8), ("actionResponse", U8), ("time", F64), ("sentTime", F64), ("channel", I32), ("originator", I64), ("originatorNickName", String), ("group", U8), ("data", Pickle), ("flags", U8)] };
+
+const TYPE_2: DefType = FixedDict { is_nullable: false, fields: &[("int32Arg1", I32), ("int64Arg1", I64), ("floatArg1", F64), ("strArg1", String), ("strArg2", String)] };
+
+const TYPE_3: DefType = FixedDict { is_nullable: false, fields: &[("id", I32), ("typeID", I32), ("roundLength", I32), ("roundStart", F32)] };
+
+const TYPE_4: DefType = Array(&TYPE_3);
+
+const TYPE_5: DefType = Array(&U32);
+
+const TYPE_6: DefType = FixedDict { is_nullable: true, fields: &[("classes", TYPE_5)] };
+
+const TYPE_7: DefType = FixedDict { is_nullable: true, fields: &[("divisions", TYPE_5), ("divisionsTotal", TYPE_5)] };
+
+const TYPE_8: DefType = FixedDict { is_nullable: true, fields: &[("classes", TYPE_5), ("players", U32)] };
+
+const ACCOUNT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[TYPE_6, TYPE_7, TYPE_8]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91300", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_9: DefType = Array(&U8);
+
+const TYPE_10: DefType = Array(&U64);
+
+const TYPE_11: DefType = Array(&I16);
+
+const AVATAR_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[U8, U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("UpdateVehicleSetting", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleAmmo", &[I32, U16, U8, I16]),
+        ("ShowOwnVehicleHitDirection", &[I32, F32, U8]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("UpdateGunMarker", &[Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvent", &[U8, TYPE_10]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("IsOwnVehicleContactingWorld", U8),
+        ("NormalisedRPMPacked", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelName", String),
+        ("SelectionId", String),
+        ("MouseOverSoundName", String),
+        ("ClickSoundName", String),
+    ],
+};
+
+const HANGAR_VEHICLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+    ],
+};
+
+const TYPE_12: DefType = FixedArray(&U8, 2);
+
+const TYPE_13: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8)] };
+
+const VEHICLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("Gear", I8),
+        ("IsCrewActive", U8),
+        ("TrackScrolling", U16),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("PublicInfo", TYPE_13),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const TYPE_14: DefType = Array(&U16);
+
+const AREA_DESTRUCTIBLES_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DestroyedModules", TYPE_14),
+        ("DestroyedFragiles", TYPE_14),
+        ("FallenColumns", TYPE_14),
+        ("FallenTrees", TYPE_5),
+    ],
+};
+
+const FLOCK_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelCount", U8),
+        ("FlyAroundCenter", U8),
+        ("YawSpeed", F32),
+        ("PitchSpeed", F32),
+        ("RollSpeed", F32),
+        ("AnimSpeedMin", F32),
+        ("AnimSpeedMax", F32),
+        ("Height", F32),
+        ("Radius", F32),
+        ("DeadZoneRadius", F32),
+        ("SpeedAtBottom", F32),
+        ("SpeedAtTop", F32),
+        ("DecisionTime", F32),
+        ("ModelName", String),
+        ("ModelName2", String),
+    ],
+};
+
+const FLOCK_EXOTIC_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelCount", U8),
+        ("AnimSpeedMax", F32),
+        ("AnimSpeedMin", F32),
+        ("Speed", F32),
+        ("AccelerationTime", F32),
+        ("TriggerRadius", F32),
+        ("SpawnRadius", F32),
+        ("SpawnHeight", F32),
+        ("FlightRadius", F32),
+        ("FlightHeight", F32),
+        ("FlightAngleMin", F32),
+        ("FlightAngleMax", F32),
+        ("FlightOffsetFromOrigin", F32),
+        ("LifeTime", F32),
+        ("RespawnTime", F32),
+        ("InitSpeedRandom", Vector2),
+        ("SpeedRandom", Vector2),
+        ("ExplosionRadius", Vector2),
+        ("ModelName", String),
+        ("ModelName2", String),
+    ],
+};
+
+const LOGIN_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnKickedFromServer", &[I32]),
+        ("ReceiveLoginQueueNumber", &[U64]),
+    ],
+    properties: &[
+        ("AccountDBIDS", String),
+    ],
+};
+
+const DETACHED_TURRET_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnStaticCollision", &[F32, Vector3, Vector3]),
+        ("ShowDamageFromShot", &[TYPE_10, U8]),
+    ],
+    properties: &[
+        ("IsUnderWater", U8),
+        ("IsCollidingWithWorld", U8),
+        ("VehicleID", I32),
+        ("VehicleCompDescr", String),
+    ],
+};
+
+const OFFLINE_FLAG_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("FlagID", I32),
+    ],
+};
+
+const ACCOUNT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91400", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_15: DefType = FixedDict { is_nullable: false, fields: &[("circularVisionRadius", U16)] };
+
+const AVATAR_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[U8, U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateVehicleSetting", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleAmmo", &[I32, U16, U8, I16]),
+        ("ShowOwnVehicleHitDirection", &[I32, F32, U8]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("UpdateGunMarker", &[Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvent", &[U8, TYPE_10]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const TYPE_16: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("index", U8), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8)] };
+
+const VEHICLE_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const ACCOUNT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91510", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[U8, U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateVehicleSetting", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleAmmo", &[I32, U16, U8, I16]),
+        ("ShowOwnVehicleHitDirection", &[I32, F32, U8]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("UpdateGunMarker", &[Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvent", &[U8, TYPE_10]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelName", String),
+        ("SelectionId", String),
+        ("MouseOverSoundName", String),
+        ("ClickSoundName", String),
+        ("ReleaseSoundName", String),
+    ],
+};
+
+const VEHICLE_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("SecondGunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const FLOCK_EXOTIC_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelCount", U8),
+        ("AnimSpeedMax", F32),
+        ("AnimSpeedMin", F32),
+        ("Speed", F32),
+        ("AccelerationTime", F32),
+        ("TriggerRadius", F32),
+        ("SpawnRadius", F32),
+        ("SpawnHeight", F32),
+        ("FlightRadius", F32),
+        ("FlightHeight", F32),
+        ("FlightAngleMin", F32),
+        ("FlightAngleMax", F32),
+        ("FlightOffsetFromOrigin", F32),
+        ("LifeTime", F32),
+        ("RespawnTime", F32),
+        ("InitSpeedRandom", Vector2),
+        ("SpeedRandom", Vector2),
+        ("ExplosionRadius", Vector2),
+        ("ModelName", String),
+        ("ModelName2", String),
+        ("FlightSound", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CameraShiftX", F32),
+        ("CameraShiftY", F32),
+        ("CameraShiftZ", F32),
+        ("CameraPivotX", F32),
+        ("CameraPivotY", F32),
+        ("CameraPivotZ", F32),
+        ("CameraYaw", F32),
+        ("CameraPitch", F32),
+        ("CameraObjectAspect", F32),
+    ],
+};
+
+const ACCOUNT_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91600", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_17: DefType = FixedDict { is_nullable: false, fields: &[("damage", U32), ("trackAssist", U32), ("radioAssist", U32), ("tankings", U32), ("lastKillerID", I32), ("lastDeathReasonID", U8)] };
+
+const TYPE_18: DefType = FixedDict { is_nullable: false, fields: &[("eventType", U8), ("targetID", I32), ("details", U32), ("count", U16)] };
+
+const TYPE_19: DefType = Array(&TYPE_18);
+
+const AVATAR_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[U8, U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateVehicleSetting", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleAmmo", &[I32, U16, U8, I16]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8]),
+        ("StopTracer", &[I32, Vector3]),
+        ("BattleEventsSummary", &[TYPE_17]),
+        ("UpdateGunMarker", &[Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const ACCOUNT_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91710", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_20: DefType = FixedDict { is_nullable: false, fields: &[("time", F64), ("shotPoint", Vector3)] };
+
+const TYPE_21: DefType = FixedDict { is_nullable: false, fields: &[("time", F64), ("relTranslation", Vector3), ("shotPoint", Vector3)] };
+
+const TYPE_22: DefType = FixedDict { is_nullable: false, fields: &[("time", F64), ("camMatrixTranslation", Vector3), ("camMatrixRotation", Vector3), ("zoom", U8)] };
+
+const AVATAR_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_17]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCameraStrategic", TYPE_20),
+        ("RemoteCameraArcade", TYPE_21),
+        ("RemoteCameraSniper", TYPE_22),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const ACCOUNT_5: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ReceiveClubUpdate", &[I64, String, String]),
+        ("ReceiveClubNotification", &[String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ShowGUI", &[String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("ResponseFortPublicInfo", &[I32, I8, Pickle]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnFortReply", &[U64, I32, String]),
+        ("OnFortUpdate", &[String, String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91800", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_23: DefType = FixedDict { is_nullable: false, fields: &[("damage", U32), ("trackAssist", U32), ("radioAssist", U32), ("stunAssist", U32), ("tankings", U32), ("lastKillerID", I32), ("lastDeathReasonID", U8)] };
+
+const TYPE_24: DefType = FixedDict { is_nullable: false, fields: &[("time", F64), ("shotPoint", Vector3), ("translation", Vector3), ("rotation", Vector3)] };
+
+const AVATAR_5: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_23]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCameraStrategic", TYPE_20),
+        ("RemoteCameraArcade", TYPE_21),
+        ("RemoteCameraSniper", TYPE_22),
+        ("RemoteCameraArty", TYPE_24),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_5: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const ACCOUNT_6: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion91910", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_25: DefType = FixedDict { is_nullable: false, fields: &[("time", F64), ("shotPoint", Vector3), ("zoom", U8)] };
+
+const AVATAR_6: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("ReceiveHorn", &[I32, U8, U8]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, F32]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_23]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const BOOTCAMP_ACCOUNT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("FinishBootcamp", &[]),
+        ("OnBootcampEnqueued", &[U64, U32, I32]),
+    ],
+    properties: &[
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CameraPivotX", F32),
+        ("CameraPivotY", F32),
+        ("CameraPivotZ", F32),
+        ("CameraYaw", F32),
+        ("CameraPitch", F32),
+        ("CameraObjectAspect", F32),
+        ("CameraMaxDistance", F32),
+        ("YawLimitMin", F32),
+        ("YawLimitMax", F32),
+        ("PitchLimitMin", F32),
+        ("PitchLimitMax", F32),
+    ],
+};
+
+const ACCOUNT_7: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion92010", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_26: DefType = Array(&F32);
+
+const AVATAR_7: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("UpdateEvilEyeStatus", &[U8, U8, U8]),
+        ("UpdateLeviathanProgress", &[U32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("UpdateVehicleSetting", &[I32, U8, I32, U8]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32, U8]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, F32, F32, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_23]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32, U8]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32, U8]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelName", String),
+        ("SelectionId", String),
+        ("MouseOverSoundName", String),
+        ("ClickSoundName", String),
+        ("CameraFlyToSoundName", String),
+        ("CameraFlyFromSoundName", String),
+    ],
+};
+
+const VEHICLE_6: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsDisappeared", U8),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("GunAnglesPacked", TYPE_14),
+        ("PublicInfo", TYPE_16),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const BOSS_LANDMINE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnActivate", &[]),
+        ("OnExplode", &[I32]),
+    ],
+    properties: &[
+        ("Normal", Vector3),
+    ],
+};
+
+const HALLOWEEN_HEALTH_POWERUP_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnConsume", &[]),
+    ],
+    properties: &[
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EnableYawLimits", U8),
+        ("CameraShiftX", F32),
+        ("CameraShiftY", F32),
+        ("CameraShiftZ", F32),
+        ("CameraPivotX", F32),
+        ("CameraPivotY", F32),
+        ("CameraPivotZ", F32),
+        ("CameraYaw", F32),
+        ("CameraPitch", F32),
+        ("CameraUpcomingDuration", F32),
+        ("CameraBackwardDuration", F32),
+        ("CameraMinDistance", F32),
+        ("CameraMaxDistance", F32),
+        ("YawLimitMin", F32),
+        ("YawLimitMax", F32),
+        ("PitchLimitMin", F32),
+        ("PitchLimitMax", F32),
+    ],
+};
+
+const ACCOUNT_8: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion92100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_27: DefType = Array(&String);
+
+const TYPE_28: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("crewCompactDescrs", TYPE_27), ("marksOnGun", U8)] };
+
+const TYPE_29: DefType = Array(&TYPE_28);
+
+const TYPE_30: DefType = FixedDict { is_nullable: false, fields: &[("vehTypeCompDescr", U16), ("endOfCooldownPiT", F32)] };
+
+const TYPE_31: DefType = Array(&TYPE_30);
+
+const TYPE_32: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("respawnType", U8), ("autoRespawnPiT", F32), ("manualRespawnPiT", F32)] };
+
+const TYPE_33: DefType = FixedDict { is_nullable: false, fields: &[("group", U8), ("vehTypeCompDescrs", TYPE_14)] };
+
+const TYPE_34: DefType = Array(&TYPE_33);
+
+const AVATAR_8: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("UpdatePlayerLives", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_23]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("UpdateRespawnVehicles", &[TYPE_29]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const TYPE_35: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("outfit", String), ("index", U8), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8)] };
+
+const VEHICLE_7: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U8]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("PublicInfo", TYPE_35),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SlotsRadius", F32),
+        ("AnchorName", String),
+        ("BlankModel", String),
+    ],
+};
+
+const CAMERA_ANCHOR_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Fov", U32),
+        ("YawConstraints", Vector2),
+        ("PitchConstraints", Vector2),
+        ("DistanceConstraints", Vector2),
+        ("AnchorName", String),
+    ],
+};
+
+const LOOT_BOX_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ConfigPath", String),
+    ],
+};
+
+const NEW_YEAR_SELECTABLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SingleTargetCaps", U32),
+        ("AnchorName", String),
+    ],
+};
+
+const DORMANT_SELECTABLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsSelectable", U8),
+    ],
+};
+
+const GROUND_LIGHT_NEW_YEAR_SCENE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("HardPointCount", U32),
+        ("BaseModelName", String),
+        ("HardPointPrefix", String),
+    ],
+};
+
+const ACCOUNT_9: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion92200", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const ACCOUNT_10: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion92300", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_9: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("UpdatePlayerLives", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32]),
+        ("BattleEventsSummary", &[TYPE_23]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdateBomberTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String]),
+        ("UpdateRespawnVehicles", &[TYPE_29]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_19]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_8: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("PublicInfo", TYPE_35),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+    ],
+};
+
+const TYPE_36: DefType = FixedArray(&U8, 3);
+
+const TYPE_37: DefType = Array(&TYPE_36);
+
+const TYPE_38: DefType = FixedArray(&U8, 5);
+
+const TYPE_39: DefType = Array(&TYPE_38);
+
+const AREA_DESTRUCTIBLES_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DestroyedModules", TYPE_37),
+        ("DestroyedFragiles", TYPE_37),
+        ("FallenColumns", TYPE_37),
+        ("FallenTrees", TYPE_39),
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_OBJECT_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EnableYawLimits", U8),
+        ("CameraYaw", F32),
+        ("CameraPitch", F32),
+        ("CameraUpcomingDuration", F32),
+        ("CameraBackwardDuration", F32),
+        ("CameraObjectAspect", F32),
+        ("CameraMaxDistance", F32),
+        ("YawLimitMin", F32),
+        ("YawLimitMax", F32),
+        ("PitchLimitMin", F32),
+        ("PitchLimitMax", F32),
+        ("CameraShift", Vector3),
+        ("CameraPivot", Vector3),
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_VEHICLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehicleGunPitch", F32),
+        ("VehicleTurretYaw", F32),
+        ("ModelName", String),
+    ],
+};
+
+const ACCOUNT_11: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion10100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_40: DefType = FixedDict { is_nullable: false, fields: &[("damage", U32), ("trackAssist", U32), ("radioAssist", U32), ("stunAssist", U32), ("smokeAssist", U32), ("inspireAssist", U32), ("tankings", U32), ("lastKillerID", I32), ("lastDeathReasonID", U8)] };
+
+const TYPE_41: DefType = Array(&I32);
+
+const TYPE_42: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("crewCompactDescrs", TYPE_27), ("marksOnGun", U8), ("vehAmmo", TYPE_41), ("settings", U16)] };
+
+const TYPE_43: DefType = Array(&TYPE_42);
+
+const TYPE_44: DefType = FixedDict { is_nullable: false, fields: &[("eventType", U8), ("targetID", I32), ("details", U64), ("count", U16)] };
+
+const TYPE_45: DefType = Array(&TYPE_44);
+
+const TYPE_46: DefType = FixedDict { is_nullable: false, fields: &[("smokeID", U16), ("equipmentID", U16), ("endTime", F64)] };
+
+const TYPE_47: DefType = Array(&TYPE_46);
+
+const AVATAR_10: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_OBJECT_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EdgeMode", U8),
+        ("ModelName", String),
+        ("SelectionId", String),
+        ("MouseOverSoundName", String),
+        ("ClickSoundName", String),
+    ],
+};
+
+const TYPE_48: DefType = FixedDict { is_nullable: true, fields: &[("radius", F32), ("startTime", F64), ("endTime", F64), ("inactivationDelay", F32)] };
+
+const TYPE_49: DefType = FixedDict { is_nullable: true, fields: &[("startTime", F64), ("endTime", F64), ("inactivationStartTime", F64), ("inactivationEndTime", F64)] };
+
+const VEHICLE_9: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("PublicInfo", TYPE_35),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const SECTOR_BASE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("Team", U8),
+        ("BaseID", U8),
+        ("SectorID", U8),
+        ("PointsPercentage", U8),
+        ("CapturingStopped", U8),
+        ("IsCaptured", U8),
+        ("InvadersCount", U8),
+        ("MaxPoints", F32),
+        ("OnDamageCooldownTime", F32),
+        ("Radius", F32),
+        ("ExpectedCaptureTime", F32),
+    ],
+};
+
+const SECTOR_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowBomb", &[Vector3]),
+    ],
+    properties: &[
+        ("GroupID", U8),
+        ("SectorID", U8),
+        ("PlayerGroup", U8),
+        ("IDInPlayerGroup", U8),
+        ("Team", U8),
+        ("State", U8),
+        ("LengthX", F32),
+        ("LengthZ", F32),
+        ("TransitionTime", F32),
+        ("EndOfTransitionPeriod", F32),
+    ],
+};
+
+const DESTRUCTIBLE_ENTITY_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnHealthChanged", &[I16, I32, U8, I32]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("ShowDamageFromShot", &[TYPE_10, U8]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("Team", U8),
+        ("DestructibleEntityID", U8),
+        ("IsDestructibleDestroyed", U8),
+        ("TypeID", U8),
+        ("Health", F32),
+        ("LinkedMapActivities", String),
+        ("DamageStickers", TYPE_10),
+    ],
+};
+
+const STEP_REPAIR_POINT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Team", U8),
+        ("Radius", F32),
+    ],
+};
+
+const PROTECTION_ZONE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ZoneID", U8),
+        ("Team", U8),
+        ("IsActive", U8),
+        ("LengthX", F32),
+        ("LengthZ", F32),
+    ],
+};
+
+const ACCOUNT_12: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion10200", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const HERO_TANK_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+    ],
+};
+
+const CLIENT_SELECTABLE_EASTER_EGG_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MultiLanguageSupport", U8),
+        ("ImageName", String),
+    ],
+};
+
+const ACCOUNT_13: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion11000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_11: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("QuestProgressDescriptor", Pickle),
+    ],
+};
+
+const ACCOUNT_14: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion12000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_12: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("CreateArenaObstacle", &[Pickle]),
+        ("DestroyArenaObstacle", &[String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("OnEventPointsEvent", &[Pickle]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_10: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, F32, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("BotKind", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("SteeringAngle", F32),
+        ("StunInfo", F64),
+        ("PublicInfo", TYPE_35),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const TYPE_50: DefType = Array(&Vector3);
+
+const TYPE_51: DefType = FixedDict { is_nullable: false, fields: &[("points", TYPE_50), ("width", F32)] };
+
+const TYPE_52: DefType = Array(&TYPE_51);
+
+const TYPE_53: DefType = FixedDict { is_nullable: false, fields: &[("position", Vector3), ("size", Vector3)] };
+
+const TYPE_54: DefType = Array(&TYPE_53);
+
+const TYPE_55: DefType = FixedDict { is_nullable: false, fields: &[("position", Vector3), ("radius", Vector3)] };
+
+const TYPE_56: DefType = Array(&TYPE_55);
+
+const TYPE_57: DefType = FixedDict { is_nullable: false, fields: &[("position", Vector3), ("text", String), ("color", Vector4), ("textSize", F32)] };
+
+const TYPE_58: DefType = Array(&TYPE_57);
+
+const TYPE_59: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("version", U32), ("destroyTime", F32), ("lines", TYPE_52), ("cubes", TYPE_54), ("spheres", TYPE_56), ("texts", TYPE_58)] };
+
+const TYPE_60: DefType = Array(&TYPE_59);
+
+const DEBUG_DRAW_ENTITY_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DrawObjects", TYPE_60),
+    ],
+};
+
+const HERO_TANK_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+        ("EffectsXmlPath", String),
+    ],
+};
+
+const EVENT_POINTS_PICKUP_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsRemoving", U8),
+        ("ModelType", U8),
+        ("EventPoints", U16),
+        ("VehicleID", U32),
+    ],
+};
+
+const EVENT_POINTS_BASE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Team", U8),
+        ("Radius", U8),
+    ],
+};
+
+const ACCOUNT_15: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion13000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_13: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_11: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_35),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const CLIENT_SELECTABLE_EASTER_EGG_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MultiLanguageSupport", U8),
+        ("ImageName", String),
+        ("OutlineModelName", String),
+        ("AnimationSequence", String),
+        ("ViewSoundConfig", String),
+    ],
+};
+
+const DESTRUCTIBLE_ENTITY_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnHealthChanged", &[I16, I32, U8, I32]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("ShowDamageFromShot", &[TYPE_10, U8]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("Team", U8),
+        ("DestructibleEntityID", U8),
+        ("IsDestructibleDestroyed", U8),
+        ("TypeID", U8),
+        ("Health", F32),
+        ("MaxHealth", F32),
+        ("LinkedMapActivities", String),
+        ("DamageStickers", TYPE_10),
+    ],
+};
+
+const CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SlotCount", U32),
+        ("Slot0", Vector3),
+        ("Slot1", Vector3),
+        ("Slot2", Vector3),
+        ("Slot3", Vector3),
+        ("Slot4", Vector3),
+        ("Slot5", Vector3),
+        ("Slot6", Vector3),
+        ("Slot7", Vector3),
+        ("Slot8", Vector3),
+    ],
+};
+
+const CAMERA_TARGET_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AnchorName", String),
+    ],
+};
+
+const NEW_YEAR_VISUAL_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelName", String),
+    ],
+};
+
+const NEW_YEAR_SELECTABLE_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EdgeMode", U8),
+        ("IsSelectable", U8),
+        ("OutlineModelName", String),
+        ("AnchorName", String),
+    ],
+};
+
+const NEW_YEAR_TOY_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AppearanceDelay", F32),
+        ("HangingEffectName", String),
+        ("RegularEffectName", String),
+        ("AnimationSequence", String),
+    ],
+};
+
+const NEW_YEAR_POSTER_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MinAlpha", F32),
+        ("MaxAlphaDistance", F32),
+    ],
+};
+
+const ACCOUNT_16: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion14000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const CLIENT_SELECTABLE_EASTER_EGG_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MultiLanguageSupport", U8),
+        ("ImageName", String),
+        ("OutlineModelName", String),
+        ("AnimationSequence", String),
+    ],
+};
+
+const ACCOUNT_17: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion14100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_61: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("outfit", String), ("index", U8), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8), ("crewGroup", U16), ("commanderSkinID", U16)] };
+
+const VEHICLE_12: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_61),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const CLIENT_SELECTABLE_WEB_LINKS_OPENER_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Url", String),
+    ],
+};
+
+const HANGAR_DOG_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CollisionModel", String),
+        ("Animation", String),
+    ],
+};
+
+const HANGAR_PORTAL_GATES_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MouseOverMusic", String),
+    ],
+};
+
+const ACCOUNT_18: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion15000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const GAME_OBJECT_ENTITY_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Prefab", String),
+    ],
+};
+
+const ACCOUNT_19: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion15100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_62: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("isEnable", U8), ("settings", U16)] };
+
+const TYPE_63: DefType = Array(&TYPE_62);
+
+const AVATAR_14: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("OnLineOfFrontPenetrated", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("SetLastStandActive", &[U8, U16]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("DisplayLastStandOnVehicle", &[U8, I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("VehicleEnteredLoFEffect", &[Vector3]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U16]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("AddRespawnGroup", &[U8, Vector3, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("EventUpdateRespawnVehicles", &[TYPE_63]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ShowHint", &[String]),
+        ("HideHint", &[String]),
+        ("NotifyClients", &[String]),
+        ("OnHealthDataChanged", &[String, U16]),
+        ("SetConfigForCurrentMap", &[String]),
+        ("OnVehicleDangerousZoneTriggered", &[I32, String, U16, Vector3]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_13: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("LastStandEnabled", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_61),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const ITEM_PICKUP_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsPickedUp", U8),
+        ("VisualAppearanceID", String),
+    ],
+};
+
+const H_B1_HANGAR_VEHICLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehicleIndex", I8),
+        ("MarkerHeightFactor", F32),
+        ("VehicleType", String),
+    ],
+};
+
+const ANIMATED_MODEL_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelPath", String),
+        ("StateMachinePath", String),
+        ("StateMachineBoolParamName", String),
+    ],
+};
+
+const ANIMATED_SCENE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SequenceDuration", F32),
+        ("Repeat", I32),
+        ("RepeatDelay", F32),
+        ("DelayDeviation", F32),
+        ("Offset", Vector3),
+        ("YprDeviation", Vector3),
+        ("OffsetDeviation", Vector3),
+        ("SequencePath", String),
+    ],
+};
+
+const ATTACK_BOMBER_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EquipmentID", I32),
+        ("Delay", F32),
+    ],
+};
+
+const ACCOUNT_20: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion16000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_15: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("OnWrongWay", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const TYPE_64: DefType = Array(&I8);
+
+const TYPE_65: DefType = FixedArray(&I32, 2);
+
+const TYPE_66: DefType = Array(&TYPE_65);
+
+const TYPE_67: DefType = FixedDict { is_nullable: false, fields: &[("vehicleID", I32), ("finishTime", F32)] };
+
+const TYPE_68: DefType = Array(&TYPE_67);
+
+const ARENA_INFO_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnCheckpoint", &[I32, String]),
+    ],
+    properties: &[
+        ("RepairNodePosition", Vector3),
+        ("RepairCounters", TYPE_64),
+        ("RaceList", TYPE_66),
+        ("FinishTimeList", TYPE_68),
+    ],
+};
+
+const TEAM_BASE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnCaptured", &[U8, U8]),
+        ("UpdateCapturingInfo", &[U8, U8, TYPE_14]),
+    ],
+    properties: &[
+    ],
+};
+
+const VEHICLE_14: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnDropCapturePointsOnDamagedByEnemy", &[]),
+        ("ShowShooting", &[U8]),
+        ("OnFestivalRaceRepair", &[U8, U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("IsCapturingTeamBase", U8),
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("IsSurfaceContact", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("TeamBasePoints", U16),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("RacePosition", I32),
+        ("RaceFinishTime", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("LastCheckpointID", String),
+        ("PublicInfo", TYPE_61),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const CLIENT_SELECTABLE_MUSIC_STAGE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("InitialCameraName", String),
+        ("EnvironmentName", String),
+    ],
+};
+
+const MUSIC_STAGE_CAMERA_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Fov", U32),
+        ("YawConstraints", Vector2),
+        ("PitchConstraints", Vector2),
+        ("DistanceConstraints", Vector2),
+        ("CameraName", String),
+    ],
+};
+
+const MUSIC_STAGE_CAMERA_TARGET_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CameraName", String),
+    ],
+};
+
+const MUSIC_STAGE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Animation", String),
+    ],
+};
+
+const ACCOUNT_21: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion16100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_69: DefType = FixedDict { is_nullable: false, fields: &[("id", U8), ("leftTime", I16), ("baseTime", I16)] };
+
+const TYPE_70: DefType = Array(&TYPE_69);
+
+const AVATAR_16: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_15: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_61),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+    ],
+};
+
+const ACCOUNT_22: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion17000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_17: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const CUSTOMIZABLE_NEW_YEAR_SCENE_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EditorModelName", String),
+    ],
+};
+
+const NEW_YEAR_SELECTABLE_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EdgeMode", U8),
+        ("IsSelectable", U8),
+        ("IgnoreDistanceOnPicking", U8),
+        ("OutlineModelName", String),
+        ("AnchorName", String),
+    ],
+};
+
+const NEW_YEAR_TOY_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AppearanceDelay", F32),
+        ("RegularEffectName", String),
+        ("HangingEffectName", String),
+        ("AnimationSequence", String),
+        ("HangingAnimationSequence", String),
+    ],
+};
+
+const PIANO_MUSICIAN_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AnimationStateMachine", String),
+        ("EffectFairytale", String),
+        ("EffectNewYear", String),
+        ("EffectChristmas", String),
+        ("EffectOriental", String),
+    ],
+};
+
+const NEW_YEAR_TALISMAN_BASE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("TalismanName", String),
+        ("CollisionModelName", String),
+        ("HoverEffect", String),
+        ("CongratsEffect", String),
+        ("StateMachine", String),
+    ],
+};
+
+const NEW_YEAR_TALISMAN_CAMERA_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Fov", F32),
+        ("NearStart", F32),
+        ("NearDist", F32),
+        ("FarStart", F32),
+        ("FarDist", F32),
+        ("GroupName", String),
+    ],
+};
+
+const NEW_YEAR_TALISMAN_PREVIEW_CAMERA_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Time", F32),
+        ("Fov", F32),
+        ("NearStart", F32),
+        ("NearDist", F32),
+        ("FarStart", F32),
+        ("FarDist", F32),
+        ("GroupName", String),
+    ],
+};
+
+const ACCOUNT_23: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion17100", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const AVATAR_18: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_32]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("PlayLimits", Pickle),
+    ],
+};
+
+const HERO_TANK_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+        ("VehicleTurretYaw", F32),
+        ("VehicleGunPitch", F32),
+    ],
+};
+
+const ACCOUNT_24: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnArenaCreated", &[]),
+        ("OnPrebattleLeft", &[]),
+        ("ResyncDossiers", &[]),
+        ("ReloadShop", &[]),
+        ("OnBootcampAccountMigrationComplete", &[]),
+        ("ChooseBootcampStart", &[]),
+        ("OnEnqueued", &[U8]),
+        ("OnDequeued", &[U8]),
+        ("OnKickedFromQueue", &[U8]),
+        ("OnPrebattleJoinFailure", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnKickedFromPrebattle", &[U8]),
+        ("OnCenterIsLongDisconnected", &[U8]),
+        ("OnPrebattleJoined", &[I32]),
+        ("OnUnitCallOk", &[I32]),
+        ("ReceiveServerStats", &[TYPE_0]),
+        ("OnEntityCheckOutEnqueued", &[U64]),
+        ("OnUnitAssemblerSuccess", &[U64, I32]),
+        ("OnTutorialEnqueued", &[U64, U32, I32]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnEnqueueFailure", &[U8, U8, String]),
+        ("OnIGRTypeChanged", &[String]),
+        ("OnArenaJoinFailure", &[U8, String]),
+        ("ReceiveActiveArenas", &[TYPE_4]),
+        ("ReceiveQueueInfo", &[Pickle]),
+        ("UpdatePrebattle", &[U8, String]),
+        ("Update", &[String]),
+        ("OnUnitUpdate", &[U64, String, String]),
+        ("OnUnitNotify", &[U64, I32, String, Pickle]),
+        ("OnUnitError", &[I32, U64, I32, String]),
+        ("OnUnitBrowserError", &[I32, String]),
+        ("OnUnitBrowserResultsSet", &[String]),
+        ("OnUnitBrowserResultsUpdate", &[String]),
+        ("OnGlobalMapUpdate", &[String, String]),
+        ("OnGlobalMapReply", &[U64, I32, String]),
+        ("OnSendPrebattleInvites", &[I64, String, I64, String, U64, U8]),
+        ("OnClanInfoReceived", &[I64, String, String, String, String]),
+        ("ReceiveNotification", &[String]),
+        ("ShowGUI", &[String]),
+    ],
+    properties: &[
+        ("RequiredVersion18000", String),
+        ("Name", String),
+        ("InitialServerSettings", Pickle),
+    ],
+};
+
+const TYPE_71: DefType = FixedDict { is_nullable: false, fields: &[("position", Vector3), ("isEnemyNear", U8)] };
+
+const TYPE_72: DefType = Array(&TYPE_71);
+
+const TYPE_73: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("respawnType", U8), ("autoRespawnPiT", F32), ("manualRespawnPiT", F32), ("respawnZones", TYPE_72), ("chosenRespawnZone", Vector3)] };
+
+const TYPE_74: DefType = FixedDict { is_nullable: false, fields: &[("curfew", I32), ("weeklyPlayLimit", I32), ("dailyPlayLimit", I32)] };
+
+const AVATAR_19: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const AVATAR_20: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("SyncVehicleAttrs", &[TYPE_15]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U16]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("ShowHint", &[String, String]),
+        ("HideHint", &[String]),
+        ("RemoveVehicle", &[I32, String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_14, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateScenarioTimer", &[Pickle]),
+        ("SendPlayerBattleLogNotification", &[String, Pickle]),
+        ("SendEquipmentBattleLogNotification", &[I32, String, U8]),
+        ("NotifyClients", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_16: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ReceiveHorn", &[I8, U8]),
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_61),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("Inspired", TYPE_49),
+        ("Buffs", TYPE_14),
+    ],
+};
+
+const HERO_TANK_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+        ("VehicleTurretYaw", F32),
+        ("VehicleGunPitch", F32),
+        ("MarkerStyleId", U32),
+    ],
+};
+
+const EVENT_CONTROL_POINT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnChangeState", &[I32, I32, String]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("Radius", F32),
+        ("FilterByPlayerID", I32),
+    ],
+};
+
+const S_E20_CLIENT_SELECTABLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("BoundingSphereSize", Vector3),
+        ("BoundingSphereOffset", Vector3),
+    ],
+};
+
+const ACTION_EFFECTS_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("PlayEffect", &[String, Vector3]),
+    ],
+    properties: &[
+        ("EquipmentID", I32),
+    ],
+};
+
+const ATTACK_BOMBER_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("PlayEffect", &[Vector3]),
+    ],
+    properties: &[
+        ("AvatarID", I32),
+        ("EquipmentID", I32),
+        ("Delay", F32),
+    ],
+};
+
+const TYPE_75: DefType = FixedDict { is_nullable: false, fields: &[("vehicleId", I32), ("nextStrikeTime", U32)] };
+
+const TYPE_76: DefType = Array(&TYPE_75);
+
+const DEATH_ZONE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnDeathZoneDamage", &[Pickle]),
+        ("OnDeathZoneNotification", &[Pickle]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("ZoneIndex", I32),
+        ("DeathzoneSize", Vector2),
+        ("DeathzonePosition", Vector3),
+        ("ZoneId", String),
+        ("VehiclesUnderFire", TYPE_76),
+    ],
+};
+
+const PERSONAL_DEATH_ZONES_HELPER_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnEnterDZ", &[I32]),
+        ("OnLeaveDZ", &[I32]),
+    ],
+    properties: &[
+        ("WarningTimeShouldEndAt", F32),
+        ("WarningDuration", F32),
+    ],
+};
+
+const TYPE_77: DefType = FixedDict { is_nullable: false, fields: &[("circularVisionRadius", U16), ("gunPiercing", F32)] };
+
+const TYPE_78: DefType = FixedArray(&U32, 2);
+
+const TYPE_79: DefType = Array(&TYPE_78);
+
+const TYPE_80: DefType = FixedDict { is_nullable: false, fields: &[("guid", String), ("position", Vector2)] };
+
+const TYPE_81: DefType = Array(&TYPE_80);
+
+const TYPE_82: DefType = FixedDict { is_nullable: false, fields: &[("vehID", I32), ("guid", String)] };
+
+const TYPE_83: DefType = Array(&TYPE_82);
+
+const AVATAR_21: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("CloseChooseSpawnKeyPointsWindow", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("ReceiveSpawnKeyPointsCloseTime", &[F32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("SyncVehicleAttrs", &[TYPE_77]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnLootAction", &[I32, U8, U8, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("UpdateTeammateLives", &[TYPE_79]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ReceiveAvailableSpawnKeyPoints", &[TYPE_81]),
+        ("ReceiveTeamSpawnKeyPoints", &[TYPE_83]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const TYPE_84: DefType = FixedDict { is_nullable: false, fields: &[("id", I32), ("position", Vector3)] };
+
+const TYPE_85: DefType = Array(&TYPE_84);
+
+const TYPE_86: DefType = FixedDict { is_nullable: false, fields: &[("id", I32), ("position", Vector3), ("isUpcoming", U8)] };
+
+const TYPE_87: DefType = Array(&TYPE_86);
+
+const ARENA_INFO_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("BossHealth", I16),
+        ("VehiclesAverageBattleLevel", F32),
+        ("PowerPoints", U32),
+        ("LootPositions", TYPE_85),
+        ("WavesPositions", TYPE_87),
+        ("GroupdropPositions", TYPE_85),
+        ("WtConfig", String),
+        ("BonusByQuestID", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_OBJECT_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsOver3DSound", U8),
+        ("IsClick3DSound", U8),
+        ("EdgeMode", U8),
+        ("ModelName", String),
+        ("SelectionId", String),
+        ("MouseOverSoundName", String),
+        ("ClickSoundName", String),
+    ],
+};
+
+const TYPE_88: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("outfit", String), ("index", U8), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8), ("crewGroup", U16), ("commanderSkinID", U16), ("maxHealth", U16)] };
+
+const TYPE_89: DefType = FixedDict { is_nullable: true, fields: &[("endTime", F64), ("period", F32), ("groupId", U8), ("attackReasonID", U8)] };
+
+const TYPE_90: DefType = FixedDict { is_nullable: true, fields: &[("primary", U8), ("startTime", F64), ("endTime", F64), ("inactivationStartTime", F64), ("inactivationEndTime", F64), ("equipmentID", U16)] };
+
+const TYPE_91: DefType = FixedDict { is_nullable: true, fields: &[("senderKey", String), ("startTime", F64), ("endTime", F64), ("inactivationStartTime", F64), ("inactivationEndTime", F64)] };
+
+const TYPE_92: DefType = FixedDict { is_nullable: true, fields: &[("senderKey", String), ("startTime", F64), ("endTime", F64), ("inactivationStartTime", F64), ("inactivationEndTime", F64), ("isInfluenceZone", U8)] };
+
+const VEHICLE_17: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_88),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+    ],
+};
+
+const DETACHED_TURRET_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnStaticCollision", &[F32, Vector3, Vector3]),
+        ("ShowDamageFromShot", &[TYPE_10, U8]),
+    ],
+    properties: &[
+        ("IsUnderWater", U8),
+        ("IsCollidingWithWorld", U8),
+        ("VehicleID", I32),
+        ("VehicleCompDescr", String),
+        ("OutfitCD", String),
+    ],
+};
+
+const CLIENT_SELECTABLE_CAMERA_OBJECT_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EnableYawLimits", U8),
+        ("CameraYaw", F32),
+        ("CameraPitch", F32),
+        ("CameraUpcomingDuration", F32),
+        ("CameraBackwardDuration", F32),
+        ("CameraObjectAspect", F32),
+        ("CameraMaxDistance", F32),
+        ("YawLimitMin", F32),
+        ("YawLimitMax", F32),
+        ("PitchLimitMin", F32),
+        ("PitchLimitMax", F32),
+        ("MovementYDelta", F32),
+        ("CameraShift", Vector3),
+        ("CameraPivot", Vector3),
+    ],
+};
+
+const HERO_TANK_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsBoss", U8),
+        ("IsEvent", U8),
+        ("MarkerHeightFactor", F32),
+        ("VehicleTurretYaw", F32),
+        ("VehicleGunPitch", F32),
+    ],
+};
+
+const GAME_OBJECT_ENTITY_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Prefab", String),
+        ("Data", Pickle),
+    ],
+};
+
+const CLIENT_SELECTABLE_HANGARS_SWITCHER_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DestHangar", String),
+    ],
+};
+
+const AVATAR_22: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("BroadcastAFKWarning", &[]),
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("CloseChooseSpawnKeyPointsWindow", &[]),
+        ("ShowAuraSoulsWarningHint", &[]),
+        ("ShowAuraHealthWarningHint", &[]),
+        ("HideAuraWarningHint", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ReceiveSpawnKeyPointsCloseTime", &[F32]),
+        ("ShowAuraVictimMarkerIcon", &[I32]),
+        ("HideAuraVictimMarkerIcon", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("SyncVehicleAttrs", &[TYPE_77]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnLootAction", &[I32, U8, U8, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("RemoveVehicle", &[I32, String]),
+        ("ReceiveAvailableSpawnKeyPoints", &[TYPE_81]),
+        ("ReceiveTeamSpawnKeyPoints", &[TYPE_83]),
+        ("NotifyClients", &[String]),
+        ("ShowHint", &[String, String]),
+        ("HideHint", &[String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_14, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateScenarioTimer", &[Pickle]),
+        ("SendPlayerBattleLogNotification", &[String, Pickle]),
+        ("SendEquipmentBattleLogNotification", &[U16, String, U8]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const ARENA_INFO_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehiclesAverageBattleLevel", F32),
+        ("LootPositions", TYPE_85),
+        ("BonusByQuestID", String),
+    ],
+};
+
+const VEHICLE_18: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnHealthChanged", &[I16, I32, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_88),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("Buffs", TYPE_14),
+    ],
+};
+
+const HALLOWEEN_HANGAR_TANK_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CameraStaticCollisions", U8),
+        ("StyleID", I32),
+        ("MarkerHeightFactor", F32),
+        ("VehicleType", String),
+        ("HighlightTriggerName", String),
+    ],
+};
+
+const HALLOWEEN_HANGAR_TANK_HEADLIGHT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ModelName", String),
+        ("AnimationSequence", String),
+    ],
+};
+
+const AVATAR_23: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("CloseChooseSpawnKeyPointsWindow", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("ReceiveSpawnKeyPointsCloseTime", &[F32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("SyncVehicleAttrs", &[TYPE_77]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnLootAction", &[I32, U8, U8, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ReceiveAvailableSpawnKeyPoints", &[TYPE_81]),
+        ("ReceiveTeamSpawnKeyPoints", &[TYPE_83]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const TYPE_93: DefType = FixedDict { is_nullable: false, fields: &[("position", Vector3), ("velocity", Vector3), ("ypr", Vector3)] };
+
+const TYPE_94: DefType = FixedDict { is_nullable: false, fields: &[("markerID", I32), ("markerType", U8), ("visibility", U8)] };
+
+const TYPE_95: DefType = Array(&TYPE_94);
+
+const TYPE_96: DefType = FixedDict { is_nullable: false, fields: &[("vehicleID", I32), ("positionData", TYPE_93), ("markersData", TYPE_95)] };
+
+const TYPE_97: DefType = Array(&TYPE_96);
+
+const ARENA_INFO_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehiclesAverageBattleLevel", F32),
+        ("VehiclesAreaMarkerParams", TYPE_97),
+        ("LootPositions", TYPE_85),
+        ("BonusByQuestID", String),
+    ],
+};
+
+const HERO_TANK_5: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+        ("VehicleTurretYaw", F32),
+        ("VehicleGunPitch", F32),
+        ("EffectsXmlPath", String),
+    ],
+};
+
+const TYPE_98: DefType = FixedDict { is_nullable: false, fields: &[("vehicleId", I32), ("nextStrikeTime", F32), ("waveDuration", F32)] };
+
+const TYPE_99: DefType = Array(&TYPE_98);
+
+const STATIC_DEATH_ZONE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnEntityEnteredInZone", &[I32]),
+        ("OnEntityLeftZone", &[I32]),
+        ("OnDeathZoneNotification", &[U8, I32, F32, F32]),
+        ("OnDeathZoneDamage", &[I32, String]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("ZoneIndex", I32),
+        ("DeathzoneSize", Vector3),
+        ("ZoneId", String),
+        ("VehiclesUnderFire", TYPE_99),
+    ],
+};
+
+const CAMERA_ANCHOR_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DofEnabled", U8),
+        ("Fov", U32),
+        ("NearStart", F32),
+        ("NearDist", F32),
+        ("FarStart", F32),
+        ("FarDist", F32),
+        ("YawConstraints", Vector2),
+        ("PitchConstraints", Vector2),
+        ("DistanceConstraints", Vector2),
+        ("AnchorName", String),
+    ],
+};
+
+const NEW_YEAR_VISUAL_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Scale", Vector3),
+        ("ModelName", String),
+    ],
+};
+
+const NEW_YEAR_TOY_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AppearanceDelay", F32),
+        ("MinAlpha", F32),
+        ("MaxAlphaDistance", F32),
+        ("RegularEffectName", String),
+        ("HangingEffectName", String),
+        ("AnimationSequence", String),
+        ("HangingAnimationSequence", String),
+    ],
+};
+
+const NEW_YEAR_TALISMAN_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("GiftIdleL1", String),
+        ("GiftIdleL2", String),
+        ("GiftIdleL3", String),
+        ("GiftIdleL4", String),
+        ("GiftIdleL5", String),
+    ],
+};
+
+const NEW_YEAR_TALISMAN_ENTRY_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ProgressUpAnimation", String),
+        ("BillboardAnimation", String),
+    ],
+};
+
+const NEW_YEAR_CELEBRITY_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CollisionModelName", String),
+        ("StateMachine", String),
+    ],
+};
+
+const NEW_YEAR_ICICLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ObjectID", U8),
+        ("CollisionModelName", String),
+        ("EffectNormalState", String),
+        ("EffectTrueState", String),
+        ("EffectFalseState", String),
+    ],
+};
+
+const NEW_YEAR_ICICLES_ILLUMINATION_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("ObjectID", U8),
+        ("StateMachine", String),
+    ],
+};
+
+const VEHICLE_19: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_88),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+    ],
+};
+
+const PLATOON_TANK_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("MarkerHeightFactor", F32),
+        ("VehicleTurretYaw", F32),
+        ("VehicleGunPitch", F32),
+        ("SlotIndex", I32),
+    ],
+};
+
+const PLATOON_LIGHTING_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("AnimationStateMachine", String),
+    ],
+};
+
+const AVATAR_24: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("SyncVehicleAttrs", &[TYPE_77]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U8]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, U8, U8]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const ARENA_INFO_4: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehiclesAreaMarkerParams", TYPE_97),
+    ],
+};
+
+const VEHICLE_20: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_88),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+    ],
+};
+
+const TYPE_100: DefType = FixedDict { is_nullable: false, fields: &[("name", String), ("compDescr", String), ("outfit", String), ("index", U8), ("team", U8), ("prebattleID", I32), ("marksOnGun", U8), ("crewGroups", TYPE_14), ("commanderSkinID", U16), ("maxHealth", U16)] };
+
+const VEHICLE_21: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+    ],
+};
+
+const TEAM_INFO_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("TeamID", I32),
+    ],
+};
+
+const AVATAR_25: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("UpdateVehicleOptionalDeviceStatus", &[I32, U8, U8]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("SyncVehicleAttrs", &[TYPE_77]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("UpdateVehicleGunReloadTime", &[I32, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U16]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("UpdateVehicleClipReloadTime", &[I32, F32, F32, F32, U8, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("UpdateGunMarker", &[I32, Vector3, Vector3, F32]),
+        ("UpdateOwnVehiclePosition", &[Vector3, Vector3, F32, F32]),
+        ("BattleEventsSummary", &[TYPE_40]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("UpdatePlaneTrajectory", &[U16, U8, F64, Vector3, Vector2, F64, Vector3, Vector2, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_43]),
+        ("UpdateRespawnCooldowns", &[TYPE_31]),
+        ("UpdateRespawnInfo", &[TYPE_73]),
+        ("UpdateVehicleLimits", &[TYPE_34]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("UpdateDualGunState", &[I32, U8, TYPE_9, TYPE_70]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("UpdateVehicleMiscStatus", &[I32, U8, I32, TYPE_26]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_45]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_47]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("IsHistoricallyAccurate", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+    ],
+};
+
+const VEHICLE_22: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("QuickShellChangerFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_90),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+    ],
+};
+
+const AREA_OF_EFFECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("PlayEffect", &[String, Vector3, F32]),
+    ],
+    properties: &[
+        ("EquipmentID", I32),
+        ("LaunchTime", F32),
+        ("StrikeTime", F32),
+    ],
+};
+
+const TYPE_101: DefType = Array(&TYPE_5);
+
+const TYPE_102: DefType = FixedDict { is_nullable: false, fields: &[("devicesSetups", TYPE_101), ("eqsSetups", TYPE_101), ("shellsSetups", TYPE_101), ("boostersSetups", TYPE_101)] };
+
+const TYPE_103: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("crewCompactDescrs", TYPE_27), ("settings", U16), ("vehSetups", TYPE_102), ("vehSetupsIndexes", TYPE_9), ("vehPostProgression", TYPE_41), ("customRoleSlotTypeId", U8)] };
+
+const TYPE_104: DefType = Array(&TYPE_103);
+
+const TYPE_105: DefType = FixedDict { is_nullable: true, fields: &[("vehTypeCompDescr", U16), ("endOfCooldownPiT", F32)] };
+
+const TYPE_106: DefType = Array(&TYPE_105);
+
+const TYPE_107: DefType = FixedDict { is_nullable: true, fields: &[("position", Vector3), ("isEnemyNear", U8)] };
+
+const TYPE_108: DefType = Array(&TYPE_107);
+
+const TYPE_109: DefType = FixedDict { is_nullable: true, fields: &[("compDescr", String), ("respawnType", U8), ("autoRespawnPiT", F32), ("manualRespawnPiT", F32), ("respawnZones", TYPE_108), ("chosenRespawnZone", Vector3), ("vehSetupsIndexes", TYPE_9)] };
+
+const TYPE_110: DefType = FixedDict { is_nullable: true, fields: &[("group", U8), ("vehTypeCompDescrs", TYPE_14)] };
+
+const TYPE_111: DefType = Array(&TYPE_110);
+
+const TYPE_112: DefType = FixedDict { is_nullable: true, fields: &[("eventType", U8), ("targetID", I32), ("details", U64), ("count", U16)] };
+
+const TYPE_113: DefType = Array(&TYPE_112);
+
+const TYPE_114: DefType = FixedDict { is_nullable: true, fields: &[("damage", U32), ("trackAssist", U32), ("radioAssist", U32), ("stunAssist", U32), ("smokeAssist", U32), ("inspireAssist", U32), ("tankings", U32), ("lastKillerID", I32), ("lastDeathReasonID", U8)] };
+
+const TYPE_115: DefType = FixedDict { is_nullable: true, fields: &[("smokeID", U16), ("equipmentID", U16), ("endTime", F32), ("team", U8)] };
+
+const TYPE_116: DefType = Array(&TYPE_115);
+
+const TYPE_117: DefType = Array(&TYPE_41);
+
+const TYPE_118: DefType = FixedDict { is_nullable: false, fields: &[("vehTypeCompDescrs", TYPE_41), ("compDescrs", TYPE_117)] };
+
+const AVATAR_26: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_104]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_116]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+        ("ClientData", Pickle),
+    ],
+};
+
+const TYPE_119: DefType = FixedDict { is_nullable: true, fields: &[("position", Vector3), ("velocity", Vector3), ("ypr", Vector3)] };
+
+const TYPE_120: DefType = FixedDict { is_nullable: true, fields: &[("markerID", I32), ("markerType", U8), ("visibility", U8)] };
+
+const TYPE_121: DefType = Array(&TYPE_120);
+
+const TYPE_122: DefType = FixedDict { is_nullable: true, fields: &[("positionData", TYPE_119), ("markersData", TYPE_121)] };
+
+const TYPE_123: DefType = Array(&TYPE_122);
+
+const TYPE_124: DefType = FixedDict { is_nullable: true, fields: &[("equipmentID", U16), ("team", U8), ("curTime", F64), ("curPos", Vector3), ("curDir", Vector2), ("nextTime", F64), ("nextPos", Vector3), ("nextDir", Vector2), ("isEndOfFlight", U8)] };
+
+const ARENA_INFO_5: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehiclesAreaMarkerParams", TYPE_123),
+        ("PlaneTrajectory", TYPE_124),
+    ],
+};
+
+const TYPE_125: DefType = FixedDict { is_nullable: true, fields: &[("primary", U8), ("startTime", F64), ("endTime", F64), ("inactivationStartTime", F64), ("inactivationEndTime", F64), ("inactivationSource", U8), ("equipmentID", U16)] };
+
+const TYPE_126: DefType = FixedDict { is_nullable: true, fields: &[("position", Vector3), ("direction", Vector3), ("speed", F32), ("rotationSpeed", F32)] };
+
+const VEHICLE_23: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+    ],
+};
+
+const CLIENT_SELECTABLE_C_G_F_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("UsedPrefab", String),
+    ],
+};
+
+const TYPE_127: DefType = FixedDict { is_nullable: true, fields: &[("nextStrikeTime", F32), ("waveDuration", F32)] };
+
+const TYPE_128: DefType = Array(&TYPE_127);
+
+const STATIC_DEATH_ZONE_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnEntityEnteredInZone", &[I32]),
+        ("OnEntityLeftZone", &[I32]),
+        ("OnDeathZoneNotification", &[U8, I32, F32, F32]),
+        ("OnDeathZoneDamage", &[I32, String]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("ZoneIndex", I32),
+        ("DeathzoneSize", Vector3),
+        ("ZoneId", String),
+        ("VehiclesUnderFire", TYPE_128),
+    ],
+};
+
+const BASIC_MINE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsDetonated", U8),
+        ("IsMarkerEnabled", U8),
+        ("EquipmentID", U32),
+        ("OwnerVehicleID", U32),
+    ],
+};
+
+const EVENT_VEHICLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EventType", String),
+    ],
+};
+
+const EVENT_PORTAL_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("OutlineModelName", String),
+    ],
+};
+
+const TYPE_129: DefType = FixedDict { is_nullable: false, fields: &[("compDescr", String), ("crewCompactDescrs", TYPE_27), ("settings", U16), ("vehSetups", TYPE_102), ("vehSetupsIndexes", TYPE_9), ("vehPostProgression", TYPE_41), ("customRoleSlotTypeId", U8), ("vehDisabledSetupSwitches", TYPE_9)] };
+
+const TYPE_130: DefType = Array(&TYPE_129);
+
+const AVATAR_27: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("BroadcastAFKWarning", &[]),
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("ShowAuraSoulsWarningHint", &[]),
+        ("ShowAuraHealthWarningHint", &[]),
+        ("HideAuraWarningHint", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("ShowAuraVictimMarkerIcon", &[I32]),
+        ("HideAuraVictimMarkerIcon", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("ShowVehicleDamageInfo", &[I32, U8, U8, I32, U16]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("RemoveVehicle", &[I32, String]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("NotifyClients", &[String]),
+        ("ShowHint", &[String, String]),
+        ("HideHint", &[String]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_14, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_116]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateScenarioTimer", &[Pickle]),
+        ("SendPlayerBattleLogNotification", &[String, Pickle]),
+        ("SendEquipmentBattleLogNotification", &[U32, String, U8]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+        ("ClientData", Pickle),
+    ],
+};
+
+const VEHICLE_24: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("ShowNotDamage", &[I32]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("DisabledSwitches", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+        ("Buffs", TYPE_14),
+    ],
+};
+
+const YEAR_HARE_AFFAIR_ENTRY_POINT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("OutlineModelName", String),
+        ("ActivePrefab", String),
+        ("InactivePrefab", String),
+    ],
+};
+
+const HALLOWEEN_HANGAR_TANK_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("IsKingReward", U8),
+        ("CameraStaticCollisions", U8),
+        ("StyleID", I32),
+        ("MarkerHeightFactor", F32),
+        ("VehicleType", String),
+        ("HighlightTriggerName", String),
+    ],
+};
+
+const AVATAR_28: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_116]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+    ],
+};
+
+const VEHICLE_25: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("MasterVehID", U32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("DisabledSwitches", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+    ],
+};
+
+const TYPE_131: DefType = FixedDict { is_nullable: false, fields: &[("point", Vector3), ("anchorName", String), ("flightSpeed", F32)] };
+
+const TYPE_132: DefType = Array(&TYPE_131);
+
+const CAMERA_ANCHOR_OBJECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("DofEnabled", U8),
+        ("Fov", U32),
+        ("NearStart", F32),
+        ("NearDist", F32),
+        ("FarStart", F32),
+        ("FarDist", F32),
+        ("YawConstraints", Vector2),
+        ("PitchConstraints", Vector2),
+        ("DistanceConstraints", Vector2),
+        ("AnchorName", String),
+        ("FlightPoints", TYPE_132),
+    ],
+};
+
+const NEW_YEAR_SELECTABLE_OBJECT_3: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("EdgeMode", U8),
+        ("IsSelectable", U8),
+        ("IgnoreDistanceOnPicking", U8),
+        ("SelectionGroupIdx", U8),
+        ("OutlineModelName", String),
+        ("AnchorName", String),
+    ],
+};
+
+const NEW_YEAR_BASE_ENTRY_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SelectionGroupIdx", U8),
+    ],
+};
+
+const NEW_YEAR_CELEBRITY_OBJECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SelectionGroupIdx", U8),
+        ("CollisionModelName", String),
+        ("StateMachine", String),
+    ],
+};
+
+const NEW_YEAR_JUKEBOX_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("StateMachine", String),
+    ],
+};
+
+const NEW_YEAR_JUKEBOX_SELECTABLE_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("SideName", String),
+    ],
+};
+
+const TYPE_133: DefType = FixedDict { is_nullable: true, fields: &[("damage", U32), ("damageSupply", U32), ("trackAssist", U32), ("radioAssist", U32), ("stunAssist", U32), ("smokeAssist", U32), ("inspireAssist", U32), ("tankings", U32), ("lastKillerID", I32), ("lastDeathReasonID", U8)] };
+
+const TYPE_134: DefType = FixedDict { is_nullable: true, fields: &[("smokeID", F64), ("equipmentID", U16), ("endTime", F32), ("team", U8), ("expiring", U8)] };
+
+const TYPE_135: DefType = FixedDict { is_nullable: false, fields: &[("queryID", U32), ("resultCode", U8), ("packedPosition", Vector3)] };
+
+const TYPE_136: DefType = Array(&TYPE_135);
+
+const AVATAR_29: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("StopVehicleControl", &[]),
+        ("OnStartVehicleControlFailed", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateTargetVehicleID", &[I32, I32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("UpdateGasAttackState", &[U8, F32, F32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("OnRTSEvent", &[U8, I32, Vector3]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[I32, TYPE_113]),
+        ("BattleEventsSummary", &[I32, TYPE_133]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_134]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("OnRTSQueryResult", &[TYPE_136]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("IsAICommander", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+        ("AiRosterVehicles", TYPE_41),
+    ],
+};
+
+const VEHICLE_26: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("ArenaBonusType", U8),
+        ("Debuff", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("AvatarID", I32),
+        ("MasterVehID", U32),
+        ("ArenaTypeID", I32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("ArenaUniqueID", U64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("DisabledSwitches", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+    ],
+};
+
+const CLIENT_SELECTABLE_R_T_S_OBJECT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("CameraYaw1v1", F32),
+        ("CameraPitch1v1", F32),
+        ("CameraYawRange1v1", F32),
+        ("CameraYaw1v7", F32),
+        ("CameraPitch1v7", F32),
+        ("CameraYawRange1v7", F32),
+    ],
+};
+
+const TYPE_137: DefType = FixedDict { is_nullable: true, fields: &[("vehicleId", I32), ("nextStrikeTime", F32), ("waveDuration", F32)] };
+
+const TYPE_138: DefType = Array(&TYPE_137);
+
+const STATIC_DEATH_ZONE_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnEntityEnteredInZone", &[I32]),
+        ("OnEntityLeftZone", &[I32]),
+        ("OnDeathZoneNotification", &[U8, I32, F32, F32]),
+        ("OnDeathZoneDamage", &[I32, String]),
+    ],
+    properties: &[
+        ("IsActive", U8),
+        ("ZoneIndex", I32),
+        ("DeathzoneSize", Vector3),
+        ("ZoneId", String),
+        ("VehiclesUnderFire", TYPE_138),
+    ],
+};
+
+const AVATAR_30: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("ShowDestructibleShotResults", &[U8, TYPE_5]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_134]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_74),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+    ],
+};
+
+const ARENA_INFO_6: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F32]),
+    ],
+    properties: &[
+        ("VehiclesAreaMarkerParams", TYPE_123),
+        ("PlaneTrajectory", TYPE_124),
+    ],
+};
+
+const VEHICLE_27: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("ArenaBonusType", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("AvatarID", I32),
+        ("MasterVehID", U32),
+        ("ArenaTypeID", I32),
+        ("Debuff", I32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("ArenaUniqueID", U64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("DisabledSwitches", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+    ],
+};
+
+const TEAM_INFO_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnCombatEquipmentUsed", &[I32, I32]),
+    ],
+    properties: &[
+        ("TeamID", I32),
+    ],
+};
+
+const AREA_OF_EFFECT_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("PlayEffect", &[String, Vector3, F32]),
+    ],
+    properties: &[
+        ("EquipmentID", I32),
+        ("LaunchTime", F64),
+        ("StrikeTime", F64),
+    ],
+};
+
+const TYPE_139: DefType = FixedDict { is_nullable: false, fields: &[("curfew", I32), ("weeklyPlayLimit", I32), ("dailyPlayLimit", I32), ("sessionLimit", I32)] };
+
+const AVATAR_31: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("OnRoundFinished", &[I8, U8]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_134]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("HandleScriptEventFromServer", &[String, String, String, String, String]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_139),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+    ],
+};
+
+const TYPE_140: DefType = FixedDict { is_nullable: true, fields: &[("vehicleID", I32), ("positionData", TYPE_119), ("markersData", TYPE_121)] };
+
+const TYPE_141: DefType = Array(&TYPE_140);
+
+const ARENA_INFO_7: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F32]),
+    ],
+    properties: &[
+        ("VehiclesAreaMarkerParams", TYPE_141),
+        ("PlaneTrajectory", TYPE_124),
+    ],
+};
+
+const ATTACK_ARTILLERY_FORT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("Team", I32),
+    ],
+};
+
+const AVATAR_32: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnRoundFinished", &[I8, U8, Pickle]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_134]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("HandleScriptEventFromServer", &[String, String, String, String, String]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_139),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+    ],
+};
+
+const VEHICLE_28: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("ShowShooting", &[U8, U8]),
+        ("OnPushed", &[F32, F32]),
+        ("OnHealthChanged", &[I16, I16, I32, U8]),
+        ("ShowAmmoBayEffect", &[U8, F32, F32]),
+        ("OnExtraHitted", &[I16, Vector3]),
+        ("ShowRammingEffect", &[F32, Vector3]),
+        ("ShowDamageFromExplosion", &[I32, Vector3, U8, U8]),
+        ("OnStaticCollision", &[F32, Vector3, Vector3, U8, F32, I8, U16]),
+        ("UpdateLaserSight", &[I32, U8, String]),
+        ("ShowDamageFromShot", &[I32, TYPE_10, U8, U8, U8]),
+    ],
+    properties: &[
+        ("BurnoutLevel", U8),
+        ("IsStrafing", U8),
+        ("PhysicsMode", U8),
+        ("SiegeState", U8),
+        ("IsCrewActive", U8),
+        ("CustomRoleSlotTypeId", U8),
+        ("ArenaBonusType", U8),
+        ("IsSpeedCapturing", U8),
+        ("IsBlockingCapture", U8),
+        ("IsMyVehicle", U8),
+        ("GunAnglesPacked", U16),
+        ("Health", I16),
+        ("EngineMode", TYPE_12),
+        ("AvatarID", I32),
+        ("MasterVehID", U32),
+        ("ArenaTypeID", I32),
+        ("Debuff", I32),
+        ("QuickShellChangerFactor", F32),
+        ("OnRespawnReloadTimeFactor", F32),
+        ("WheelsState", U64),
+        ("StunInfo", F64),
+        ("ArenaUniqueID", U64),
+        ("SteeringAngles", TYPE_9),
+        ("WheelsScroll", TYPE_9),
+        ("PublicInfo", TYPE_100),
+        ("DamageStickers", TYPE_10),
+        ("PublicStateModifiers", TYPE_9),
+        ("CrewCompactDescrs", TYPE_27),
+        ("Enhancements", Pickle),
+        ("Setups", Pickle),
+        ("SetupsIndexes", Pickle),
+        ("VehPerks", Pickle),
+        ("VehPostProgression", TYPE_41),
+        ("DisabledSwitches", TYPE_41),
+        ("InspiringEffect", TYPE_48),
+        ("HealingEffect", TYPE_48),
+        ("DotEffect", TYPE_89),
+        ("Inspired", TYPE_125),
+        ("Healing", TYPE_91),
+        ("HealOverTime", TYPE_92),
+        ("OwnVehiclePosition", TYPE_126),
+    ],
+};
+
+const LOGIN_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnKickedFromServer", &[I32]),
+        ("ReceiveLoginQueueNumber", &[U64]),
+        ("SetPeripheryRoutingGroup", &[String, Pickle]),
+    ],
+    properties: &[
+        ("AccountDBIDS", String),
+    ],
+};
+
+const EVENT_VEHICLE_1: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehicleGunPitch", F32),
+        ("VehicleTurretYaw", F32),
+        ("EventType", String),
+    ],
+};
+
+const TYPE_142: DefType = FixedDict { is_nullable: true, fields: &[("type", U8), ("value", U16), ("isPercentage", U8)] };
+
+const TYPE_143: DefType = FixedDict { is_nullable: true, fields: &[("state", U8), ("finishTime", F64), ("count", U16)] };
+
+const TYPE_144: DefType = FixedDict { is_nullable: true, fields: &[("goodieID", U32), ("lifetime", U16), ("useby", U64), ("resource", TYPE_142), ("stateInfo", TYPE_143)] };
+
+const TYPE_145: DefType = Array(&TYPE_144);
+
+const AVATAR_33: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("NotifyCannotStartRecovering", &[]),
+        ("NotifyCancelled", &[]),
+        ("UpdatePlayerLives", &[U8]),
+        ("EnteringProtectionZone", &[U8]),
+        ("LeavingProtectionZone", &[U8]),
+        ("ProtectionZoneShooting", &[U8]),
+        ("OnSectorShooting", &[U8]),
+        ("OnAutoAimVehicleLost", &[U8]),
+        ("OnKickedFromArena", &[U8]),
+        ("OnXPUpdated", &[I16]),
+        ("OnRankUpdate", &[I16]),
+        ("ExplodeVehicleBeforeRespawn", &[I32]),
+        ("RemoveVehicle", &[I32]),
+        ("UpdateTargetVehicleID", &[I32]),
+        ("ShowDestructibleShotResults", &[U8, I32]),
+        ("OnDestructibleDestroyed", &[U8, I32]),
+        ("UpdateResourceAmount", &[U8, U32]),
+        ("UpdateVehicleQuickShellChanger", &[I32, U8]),
+        ("OnSectorBaseAction", &[U8, U8, F32]),
+        ("OnRepairPointAction", &[U8, U8, F32]),
+        ("UpdateVehicleHealth", &[I32, I16, I8, U8, U8]),
+        ("UpdateVehicleSetting", &[I32, U8, I32]),
+        ("OnVehicleHealthChanged", &[I32, I16, I32, U8]),
+        ("OnStepRepairPointAction", &[I32, U8, F32, U16]),
+        ("WelcomeToSector", &[U8, U8, U8, U8, F32, F32]),
+        ("EnemySPGHit", &[Vector3]),
+        ("UpdateState", &[U8, I32, I32, F32]),
+        ("OnCombatEquipmentShotLaunched", &[U16, Vector3]),
+        ("UpdateVehicleAmmo", &[I32, I32, U16, U8, U8, I16, I16]),
+        ("OnSwitchViewpoint", &[I32, Vector3]),
+        ("StopTracer", &[I32, Vector3]),
+        ("OnCollisionWithVehicle", &[Vector3, F32]),
+        ("OnFrictionWithVehicle", &[I32, Vector3, U8]),
+        ("ShowOwnVehicleHitDirection", &[F32, I32, U16, U32, U8, U8, I32, U8]),
+        ("EnemySPGShotSound", &[Vector3, Vector3]),
+        ("ShowHittingArea", &[U16, Vector3, Vector3, F64]),
+        ("ShowCarpetBombing", &[U16, Vector3, Vector3, F64]),
+        ("UpdateTargetingInfo", &[F32, F32, F32, F32, F32, F32, F32, F32, F32]),
+        ("ShowTracer", &[I32, I32, U8, U8, Vector3, Vector3, F32, F32, U8]),
+        ("OnChatAction", &[TYPE_1]),
+        ("MessengerOnActionByServerChat2", &[I16, U16, TYPE_2]),
+        ("OnCmdResponse", &[I16, I16, String]),
+        ("OnCmdResponseExt", &[I16, I16, String, String]),
+        ("ProcessInvitations", &[Pickle]),
+        ("OnTokenReceived", &[U16, U8, String]),
+        ("UpdateTeamsHealthPercentage", &[TYPE_9]),
+        ("RedrawVehicleOnRespawn", &[I32, String, String]),
+        ("UpdateRespawnVehicles", &[TYPE_130]),
+        ("UpdateRespawnCooldowns", &[TYPE_106]),
+        ("UpdateRespawnInfo", &[TYPE_109]),
+        ("UpdateVehicleLimits", &[TYPE_111]),
+        ("OnTeamLivesRestored", &[TYPE_9]),
+        ("ExternalTrigger", &[String, Pickle]),
+        ("Update", &[String]),
+        ("OnKickedFromServer", &[String, U8, U32]),
+        ("OnIGRTypeChanged", &[String]),
+        ("ReceiveAccountStats", &[U32, String]),
+        ("OnBootcampEvent", &[TYPE_10]),
+        ("ShowOtherVehicleDamagedDevices", &[I32, TYPE_9, TYPE_9]),
+        ("ShowShotResults", &[TYPE_10]),
+        ("ShowDevelopmentInfo", &[U8, String]),
+        ("ExplodeProjectile", &[I32, U8, U8, Vector3, Vector3, TYPE_5]),
+        ("OnRoundFinished", &[I8, U8, Pickle]),
+        ("OnBattleEvents", &[TYPE_113]),
+        ("BattleEventsSummary", &[TYPE_114]),
+        ("UpdateArena", &[U8, String]),
+        ("UpdatePositions", &[TYPE_9, TYPE_11]),
+        ("ReceivePhysicsDebugInfo", &[String]),
+        ("UpdateCarriedFlagPositions", &[TYPE_9, TYPE_11]),
+        ("ReceiveNotification", &[String]),
+        ("UpdateAvatarPrivateStats", &[String]),
+        ("OnSmoke", &[TYPE_134]),
+        ("UpdateQuestProgress", &[String, Pickle]),
+        ("HandleScriptEventFromServer", &[String, String, String, String, String]),
+        ("SetUpdatedGoodiesSnapshot", &[TYPE_145]),
+        ("UpdateSpawnList", &[String]),
+    ],
+    properties: &[
+        ("IsObserverFPV", U8),
+        ("ObserverFPVControlMode", U8),
+        ("NumOfObservers", U8),
+        ("ArenaBonusType", U8),
+        ("ArenaGuiType", U8),
+        ("WeatherPresetID", U8),
+        ("TkillIsSuspected", U8),
+        ("Team", U8),
+        ("IsObserverBothTeams", U8),
+        ("ObservableTeamID", U8),
+        ("IsGunLocked", U8),
+        ("OwnVehicleGear", U8),
+        ("CustomizationDisplayType", U8),
+        ("DenunciationsLeft", I16),
+        ("ArenaTypeID", I32),
+        ("PlayerVehicleID", I32),
+        ("ArenaUniqueID", U64),
+        ("OwnVehicleAuxPhysicsData", U64),
+        ("PlayLimits", TYPE_139),
+        ("RemoteCamera", TYPE_25),
+        ("Name", String),
+        ("SessionID", String),
+        ("ArenaExtraData", Pickle),
+        ("ClientCtx", String),
+        ("AmmoViews", TYPE_118),
+        ("GoodiesSnapshot", TYPE_145),
+    ],
+};
+
+const TEAM_BASE_RECAPTURABLE_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("OnCaptureStop", &[]),
+        ("OnCaptured", &[]),
+        ("OnCaptureStart", &[I32]),
+    ],
+    properties: &[
+        ("Team", U8),
+        ("BaseID", U8),
+        ("InvadersCount", U8),
+        ("InvadersTeam", U8),
+        ("Radius", F32),
+        ("Points", F32),
+        ("CaptureTimeLeft", F32),
+    ],
+};
+
+const AREA_OF_EFFECT_2: EntitySignatures = EntitySignatures {
+    methods:    &[
+        ("PlayEffect", &[String, Vector3, F32]),
+    ],
+    properties: &[
+        ("VehicleID", I32),
+        ("EquipmentID", I32),
+        ("LaunchTime", F64),
+        ("StrikeTime", F64),
+    ],
+};
+
+const APPLICATION_POINT_0: EntitySignatures = EntitySignatures {
+    methods:    &[
+    ],
+    properties: &[
+        ("VehicleID", I32),
+        ("EquipmentID", I32),
+        ("LaunchTime", F32),
+        ("Level", I32),
+    ],
+};
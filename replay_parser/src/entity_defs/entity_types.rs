@@ -1,10 +1,9 @@
-use phf::phf_map;
-use EntityType::*;
-
 use super::{AVATAR_PROPS, VEHICLE_PROPS};
 use crate::packet_parser::prelude::*;
 
-#[derive(Debug, Serialize, Copy, Clone, Deserialize, strum::Display)]
+#[derive(
+    Debug, Serialize, Copy, Clone, PartialEq, Eq, Hash, Deserialize, strum::Display, strum::EnumString,
+)]
 pub enum EntityType {
     HangarPoster,
     NewYearVisualObject,
@@ -120,6 +119,10 @@ pub enum EntityType {
     NewYearHeroTank,
     NewYearBaseEntryObject,
     SE20HeroTank,
+
+    /// Entity that was added to the game after this crate was released. Only found when the definitions are
+    /// loaded at runtime with [DefinitionRegistry](crate::DefinitionRegistry)
+    Unknown,
 }
 
 
@@ -141,59 +144,3 @@ impl EntityType {
         }
     }
 }
-
-/// A map that stores entity_type id of an entity for each version
-pub static ENTITY_TYPE_MAP: phf::Map<&str, &'static [EntityType]> = phf_map! {
-    "0_9_12_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_13_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_14_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_15_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag, ClientSelectableCameraObject],
-    "0_9_16_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_17_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_18_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag],
-    "0_9_19_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag, BootcampAccount, ClientSelectableCameraObject],
-    "0_9_20_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, OfflineFlag, BootcampAccount, BossLandmine, HalloweenHealthPowerup, ClientSelectableCameraObject, HalloweenSupplyDrop],
-    "0_9_21_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, CustomizableNewYearSceneObject, CameraAnchorObject, LootBoxObject, NewYearSelectableObject, DormantSelectableObject, GroundLightNewYearSceneObject, PosterSelectableObject],
-    "0_9_22_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, CustomizableNewYearSceneObject, CameraAnchorObject, NewYearSelectableObject, DormantSelectableObject, GroundLightNewYearSceneObject, PosterSelectableObject],
-    "0_9_23_0" => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg],
-    "1_0_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg],
-    "1_0_1_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone],
-    "1_0_2_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone],
-    "1_1_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone],
-    "1_2_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, EventPointsPickup, EventPointsBase],
-    "1_3_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, CustomizableNewYearSceneObject, CameraAnchorObject, CameraTarget, NewYearVisualObject, NewYearSelectableObject, NewYearToyObject, NewYearPoster],
-    "1_4_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster],
-    "1_4_1_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, HangarDog, DogBowl, HangarPortalGates],
-    "1_5_0_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity],
-    "1_5_1_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity, CustomizableHB1SceneObject, CameraAnchorObject, ItemPickup, HB1HangarVehicle, AnimatedModelObject, AnimatedScene, AttackBomber],
-    "1_6_0_0"  => &[Account, Avatar, ArenaInfo, TeamBase, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity, ClientSelectableFrontLineHangarObject, ClientSelectableMarathonHangarObject, ClientSelectableAdventCalendarHangarObject, ClientSelectableRacingHangarObject, ClientSelectableMiniGamesHangarObject, ClientSelectableMusicStageObject, MusicStageCameraObject, MusicStageCameraTarget, MusicStage, RaceSoundZone],
-    "1_6_1_0"  => &[Account, Avatar, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity],
-    "1_7_0_0"  => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity, CustomizableNewYearSceneObject, CameraAnchorObject, CameraTarget, NewYearVisualObject, NewYearSelectableObject, NewYearToyObject, PianoMusician, NewYearTalismanBaseObject, NewYearTalismanObject, NewYearTalismanCamera, NewYearTalismanEntryObject, NewYearTalismanPreviewObject, NewYearTalismanPreviewCamera],
-    "1_7_1_0"  => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity],
-    "1_8_0_0"  => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, GameObjectEntity],
-    "1_9_0_0"  => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, HeroTank, ClientSelectableEasterEgg, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, ClientSelectableWebLinksOpener, CustomizableSE20SceneObject, CameraAnchorObject, GameObjectEntity, ItemPickup, EventControlPoint, SE20HangarVehicle, SE20ClientSelectableObject, AnimatedScene, ActionEffects, AttackBomber, DeathZone, PersonalDeathZonesHelper, SE20HeroTank],
-    "1_10_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, PhotoZone, GameObjectEntity, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableAdventCalendarObject, EventPortal, GameEventEntryPoint],
-    "1_10_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, GameObjectEntity, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, EnvironmentSwitcher, HalloweenHangarTank, HalloweenHangarTankHeadlight, ItemPickup, EventControlPoint],
-    "1_11_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, GameObjectEntity, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, CustomizableNewYearSceneObject, CameraAnchorObject, CameraTarget, NewYearVisualObject, NewYearSelectableObject, NewYearToyObject, PianoMusician, NewYearTalismanBaseObject, NewYearTalismanObject, NewYearTalismanCamera, NewYearBaseEntryObject, NewYearTalismanEntryObject, NewYearCelebrityEntryObject, NewYearTalismanPreviewObject, NewYearTalismanPreviewCamera, NewYearCelebrityObject, NewYearIcicleObject, NewYearIciclesIllumination],
-    "1_12_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, GameObjectEntity, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, ClientSelectableBlackMarketObject],
-    "1_12_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone],
-    "1_13_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone],
-    "1_14_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, EmptyEntity, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, HangarBirthdayPoster, TeamInfo, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine, EventVehicle, EventPortal],
-    "1_14_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, YearHareAffairEntryPoint, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine, EnvironmentSwitcher, HalloweenHangarTank, HalloweenHangarTankHeadlight, ItemPickup, EventControlPoint],
-    "1_15_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine, CustomizableNewYearSceneObject, CameraAnchorObject, CameraTarget, NewYearVisualObject, NewYearSelectableObject, NewYearToyObject, NewYearBaseEntryObject, NewYearCelebrityEntryObject, NewYearCelebrityObject, NewYearJukeboxObject, NewYearJukeboxSelectableObject, NewYearHeroTank],
-    "1_16_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine],
-    "1_16_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableRTSObject, ClientSelectableRTSLeaderboard, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine],
-    "1_17_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableCGFObject, StaticDeathZone, BasicMine],
-    "1_17_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, ClientSelectableWotAnniversaryObject, StaticDeathZone, BasicMine],
-    "1_18_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, EmptyEntity, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, BasicMine, EventVehicle, EventPortal],
-    "1_18_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, EmptyEntity, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamBaseRecapturable, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, BasicMine, ApplicationPoint],
-    "1_19_0_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, EmptyEntity, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, BasicMine, ApplicationPoint, NewYearVisualObject, NewYearJukeboxObject],
-    "1_19_1_0" => &[Account, Avatar, ArenaInfo, ClientSelectableObject, HangarVehicle, Vehicle, AreaDestructibles, OfflineEntity, Flock, FlockExotic, Login, DetachedTurret, BootcampAccount, DebugDrawEntity, ClientSelectableCameraObject, ClientSelectableCameraVehicle, ClientSelectableWebLinksOpener, ClientSelectableEasterEgg, EmptyEntity, HeroTank, PlatoonTank, PlatoonLighting, SectorBase, Sector, DestructibleEntity, StepRepairPoint, ProtectionZone, HangarPoster, TeamInfo, AreaOfEffect, AttackBomber, AttackArtilleryFort, PersonalDeathZone, ClientSelectableRankedObject, ClientSelectableHangarsSwitcher, StaticDeathZone, BasicMine, ApplicationPoint],
-};
-
-pub fn find_entity_type(version: &str, entity_type_id: usize) -> Option<EntityType> {
-    // Bigword uses non-zero index here, that is why we subtract 1
-    ENTITY_TYPE_MAP
-        .get(version)
-        .and_then(|ent_array| ent_array.get(entity_type_id - 1).copied())
-}
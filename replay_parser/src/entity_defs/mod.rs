@@ -4,55 +4,26 @@ pub use avatar::{AVATAR_METHODS, AVATAR_PROPS};
 mod vehicle;
 pub use vehicle::{VEHICLE_METHODS, VEHICLE_PROPS};
 
+mod arena_entities;
+pub use arena_entities::{ARENA_ENTITY_METHODS, ARENA_ENTITY_PROPS};
+
 mod entity_types;
-pub use entity_types::{find_entity_type, EntityType, ENTITY_TYPE_MAP};
-
-
-pub const VERSIONS: &'static [[u16; 4]] = &[
-    [0, 9, 12, 0],
-    [0, 9, 13, 0],
-    [0, 9, 14, 0],
-    [0, 9, 15, 0],
-    [0, 9, 16, 0],
-    [0, 9, 17, 0],
-    [0, 9, 18, 0],
-    [0, 9, 19, 0],
-    [0, 9, 20, 0],
-    [0, 9, 21, 0],
-    [0, 9, 22, 0],
-    [0, 9, 23, 0],
-    [1, 0, 0, 0],
-    [1, 0, 1, 0],
-    [1, 0, 02, 0],
-    [1, 1, 0, 0],
-    [1, 2, 0, 0],
-    [1, 3, 0, 0],
-    [1, 4, 0, 0],
-    [1, 4, 1, 0],
-    [1, 5, 0, 0],
-    [1, 5, 1, 0],
-    [1, 6, 0, 0],
-    [1, 6, 1, 0],
-    [1, 7, 0, 0],
-    [1, 7, 1, 0],
-    [1, 8, 0, 0],
-    [1, 9, 0, 0],
-    [1, 10, 0, 0],
-    [1, 10, 1, 0],
-    [1, 11, 0, 0],
-    [1, 11, 1, 0],
-    [1, 12, 0, 0],
-    [1, 12, 1, 0],
-    [1, 13, 0, 0],
-    [1, 14, 0, 0],
-    [1, 14, 1, 0],
-    [1, 15, 0, 0],
-    [1, 16, 0, 0],
-    [1, 16, 1, 0],
-    [1, 17, 0, 0],
-    [1, 17, 1, 0],
-    [1, 18, 0, 0],
-    [1, 18, 1, 0],
-    [1, 19, 0, 0],
-    [1, 19, 1, 0],
-];
+pub use entity_types::EntityType;
+
+mod entity_type_map;
+pub use entity_type_map::ENTITY_TYPE_MAP;
+
+mod entity_signatures;
+pub use entity_signatures::ENTITY_SIGNATURES;
+
+mod versions;
+pub use versions::VERSIONS;
+
+mod version_profile;
+pub use version_profile::VersionProfile;
+
+mod registry;
+pub use registry::DefinitionRegistry;
+
+mod signatures;
+pub use signatures::{DefType, EntitySignatures, Signatures};
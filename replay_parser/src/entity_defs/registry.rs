@@ -0,0 +1,306 @@
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+use wot_def_file_parser::{
+    find_versions, EntitySignature, EntityTables, OpaqueType, VersionDefinitions, WotType,
+};
+
+use super::{DefType, EntitySignatures, EntityType, Signatures, VersionProfile};
+use crate::utils::version_as_string;
+use crate::ReplayError;
+
+/// Entity definitions loaded at runtime from the `.def` and `alias.xml` files of the game, for patches that
+/// were released after this crate. The directory is expected to have the same layout as
+/// `definition_parser/definitions`, i.e. one directory per version (ex. `1_20_0_0`) that contains
+/// `entities.xml`, `alias.xml`, the `.def` files of the entities and an `interfaces` directory.
+///
+/// Like the compiled-in tables, the registry also keeps the signatures of the methods and properties of every
+/// entity so that the ones we do not have a parser for are decoded into
+/// [WotValue](crate::wot_types::WotValue) (see `EntityMethod::Generic` and `EntityProperty::Generic`).
+///
+/// The tables live for the rest of the program so that a [VersionProfile] can hold them like the compiled-in
+/// ones. They are interned: loading the same definitions again, in this registry or in another one, reuses
+/// the tables that were already loaded instead of allocating new ones. Create the registry once and pass it
+/// to [Context::with_registry](crate::Context::with_registry) or
+/// [ReplayParser::event_stream_with_registry](crate::ReplayParser::event_stream_with_registry)
+/// ## Example
+/// ```no_run
+/// # use wot_replay_parser::*;
+/// let registry = DefinitionRegistry::load("definitions").unwrap();
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+///
+/// for event in replay.event_stream_with_registry(&registry).unwrap() {
+///     println!("{:?}", event);
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DefinitionRegistry {
+    profiles: HashMap<[u16; 4], VersionProfile>,
+}
+
+impl DefinitionRegistry {
+    /// Load every version found in `def_dir`. Directories that are not named after a version are ignored
+    pub fn load<P: AsRef<Path>>(def_dir: P) -> Result<Self, ReplayError> {
+        let mut registry = Self::default();
+
+        for version in find_versions(&def_dir.as_ref().to_string_lossy())? {
+            registry.load_version(&def_dir, version)?;
+        }
+
+        Ok(registry)
+    }
+
+    /// Load every version found in the directory pointed to by the `DEF_DIR` environment variable
+    pub fn from_env() -> Result<Self, ReplayError> {
+        Self::load(wot_def_file_parser::utils::get_definitions_root())
+    }
+
+    /// Load a single version from `def_dir`. Does nothing if that version is already loaded
+    pub fn load_version<P: AsRef<Path>>(&mut self, def_dir: P, version: [u16; 4]) -> Result<(), ReplayError> {
+        if self.profiles.contains_key(&version) {
+            return Ok(());
+        }
+
+        let def_dir = def_dir.as_ref().to_string_lossy();
+        let xml_error = |err| ReplayError::XmlFileError(format!("{}: {err}", version_as_string(version)));
+
+        let definitions = VersionDefinitions::load(&def_dir, version).map_err(xml_error)?;
+
+        let mut profile = VersionProfile::new(version);
+        profile.set_avatar_methods(intern_names(definitions.avatar_methods));
+        profile.avatar_props = Some(intern_names(definitions.avatar_props));
+        profile.vehicle_methods = Some(intern_names(definitions.vehicle_methods));
+        profile.vehicle_props = Some(intern_names(definitions.vehicle_props));
+
+        // Without `entities.xml`, the entity types and the tables that depend on them are the compiled-in
+        // ones
+        if let Some(entity_names) = &definitions.entity_types {
+            let entity_types = entity_names
+                .iter()
+                .map(|name| EntityType::from_str(name).unwrap_or(EntityType::Unknown))
+                .collect::<Vec<_>>();
+
+            if definitions.has_def_files {
+                profile.signatures =
+                    Some(load_signatures(&def_dir, version, &entity_types).map_err(xml_error)?);
+                profile.arena_methods = Some(intern_arena_tables(&definitions.arena_entities, |entity| {
+                    &entity.methods
+                }));
+                profile.arena_props = Some(intern_arena_tables(&definitions.arena_entities, |entity| {
+                    &entity.props
+                }));
+            }
+            profile.entity_types = Some(intern_slice(entity_types));
+        }
+
+        self.profiles.insert(version, profile);
+
+        Ok(())
+    }
+
+    /// Definitions for `version`, if they were loaded. The last part of the version is ignored
+    pub fn profile(&self, mut version: [u16; 4]) -> Option<&VersionProfile> {
+        version[3] = 0;
+
+        self.profiles.get(&version)
+    }
+
+    /// Versions that were loaded
+    pub fn versions(&self) -> impl Iterator<Item = [u16; 4]> + '_ {
+        self.profiles.keys().copied()
+    }
+}
+
+/// Signatures of the entities in `entity_types`. An entity whose definition cannot be read is left out: its
+/// methods and properties stay `NotImplemented`
+fn load_signatures(
+    def_dir: &str, version: [u16; 4], entity_types: &[EntityType],
+) -> wot_def_file_parser::Result<&'static Signatures> {
+    let names = entity_types
+        .iter()
+        .filter(|entity_type| !matches!(entity_type, EntityType::Unknown))
+        .map(|entity_type| entity_type.to_string())
+        .collect::<Vec<_>>();
+
+    let signatures = EntitySignature::load_all(def_dir, version, &names)?
+        .into_iter()
+        .filter_map(|entity| {
+            let entity_type = EntityType::from_str(&entity.name).ok()?;
+
+            let methods = entity
+                .methods
+                .into_iter()
+                .map(|(name, params)| {
+                    let params = params.iter().map(intern_type).collect();
+
+                    (intern_str(&name), intern_slice(params))
+                })
+                .collect();
+            let properties = entity
+                .props
+                .into_iter()
+                .map(|(name, ty)| (intern_str(&name), intern_type(&ty)))
+                .collect();
+
+            let signatures = EntitySignatures {
+                methods:    intern_slice(methods),
+                properties: intern_slice(properties),
+            };
+
+            Some((entity_type, signatures))
+        })
+        .collect();
+
+    Ok(intern_slice(signatures))
+}
+
+/// [DefType] of `ty`. Aliases are resolved
+fn intern_type(ty: &WotType) -> DefType {
+    use OpaqueType::*;
+
+    match ty {
+        WotType::OpaqueType(ty) => match ty {
+            U8 => DefType::U8,
+            I8 => DefType::I8,
+            U16 => DefType::U16,
+            I16 => DefType::I16,
+            U32 => DefType::U32,
+            I32 => DefType::I32,
+            U64 => DefType::U64,
+            I64 => DefType::I64,
+            F32 => DefType::F32,
+            F64 => DefType::F64,
+            String => DefType::String,
+            Vector2 => DefType::Vector2,
+            Vector3 => DefType::Vector3,
+            Vector4 => DefType::Vector4,
+            Pickle => DefType::Pickle,
+            MailBox => DefType::MailBox,
+            UserType => DefType::UserType,
+            Alias(ty) => intern_type(ty),
+        },
+        WotType::Array(ty) => DefType::Array(&intern_slice(vec![intern_type(ty)])[0]),
+        WotType::FixedArray(ty, len) => DefType::FixedArray(&intern_slice(vec![intern_type(ty)])[0], *len),
+        WotType::FixedDict { is_nullable, dict } => {
+            let fields = dict
+                .iter()
+                .map(|(name, ty)| (intern_str(name), intern_type(ty)))
+                .collect();
+
+            DefType::FixedDict {
+                is_nullable: *is_nullable,
+                fields:      intern_slice(fields),
+            }
+        }
+    }
+}
+
+/// Intern `names` so that they can be used in a [VersionProfile]
+fn intern_names(names: Vec<String>) -> &'static [&'static str] {
+    intern_slice(names.iter().map(|name| intern_str(name)).collect())
+}
+
+/// Intern the method or property tables of the arena entities. Entities unknown to [EntityType] are left out
+fn intern_arena_tables(
+    entities: &[EntityTables], names: fn(&EntityTables) -> &Vec<String>,
+) -> &'static [(EntityType, &'static [&'static str])] {
+    let tables = entities
+        .iter()
+        .filter_map(|entity| {
+            let entity_type = EntityType::from_str(&entity.name).ok()?;
+
+            Some((entity_type, intern_names(names(entity).clone())))
+        })
+        .collect();
+
+    intern_slice(tables)
+}
+
+/// Values interned by [intern_str] and [intern_slice], one set per type
+static INTERNED: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>> = OnceLock::new();
+
+/// Set of the interned values of type `T`
+fn with_interned<T: ?Sized + Sync + 'static, R>(f: impl FnOnce(&mut HashSet<&'static T>) -> R) -> R {
+    let mut interned = INTERNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let set = interned
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(HashSet::<&'static T>::new()))
+        .downcast_mut()
+        .expect("interned values are keyed by their type");
+
+    f(set)
+}
+
+fn intern_str(value: &str) -> &'static str {
+    with_interned(|set: &mut HashSet<&'static str>| match set.get(value) {
+        Some(interned) => *interned,
+        None => {
+            let interned = &*Box::leak(value.into());
+            set.insert(interned);
+            interned
+        }
+    })
+}
+
+/// `values` as a slice that lives for the rest of the program. Equal slices are only allocated once
+pub(super) fn intern_slice<T: Eq + Hash + Send + Sync + 'static>(values: Vec<T>) -> &'static [T] {
+    with_interned(
+        |set: &mut HashSet<&'static [T]>| match set.get(values.as_slice()) {
+            Some(interned) => *interned,
+            None => {
+                let interned = &*Box::leak(values.into_boxed_slice());
+                set.insert(interned);
+                interned
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEF_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../definition_parser/definitions");
+
+    #[test]
+    fn loads_the_compiled_in_tables() {
+        let registry = DefinitionRegistry::load(DEF_DIR).unwrap();
+
+        for version in [[0, 9, 15, 0], [1, 11, 1, 0], [1, 16, 1, 0], [1, 19, 1, 0]] {
+            assert_eq!(
+                registry.profile(version),
+                Some(&VersionProfile::new(version)),
+                "{version:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn reuses_loaded_tables() {
+        let version = [1, 16, 1, 0];
+        let load = || {
+            let mut registry = DefinitionRegistry::default();
+            registry.load_version(DEF_DIR, version).unwrap();
+            registry.load_version(DEF_DIR, version).unwrap();
+
+            *registry.profile(version).unwrap()
+        };
+
+        let (first, second) = (load(), load());
+        assert!(std::ptr::eq(
+            first.signatures.unwrap(),
+            second.signatures.unwrap()
+        ));
+        assert!(std::ptr::eq(
+            first.avatar_methods().unwrap(),
+            second.avatar_methods().unwrap()
+        ));
+    }
+}
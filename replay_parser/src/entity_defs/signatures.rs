@@ -0,0 +1,48 @@
+use super::EntityType;
+
+/// Type of a method argument or of a property, as found in the `.def` files with the aliases resolved. Unlike
+/// [WotType](wot_def_file_parser::WotType) it can be written in a `static`, which is how the signatures
+/// generated by `gen_entity_defs` are compiled into the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+    String,
+    Vector2,
+    Vector3,
+    Vector4,
+    Pickle,
+    MailBox,
+    UserType,
+    Array(&'static DefType),
+
+    /// `ARRAY` or `TUPLE` with a size. The number of elements is not sent over the network
+    FixedArray(&'static DefType, usize),
+
+    /// Fields are in the order they are sent over the network
+    FixedDict {
+        is_nullable: bool,
+        fields:      &'static [(&'static str, DefType)],
+    },
+}
+
+/// Types of the arguments of the methods and of the properties of an entity, in the order of their ids. Used
+/// to decode methods and properties we do not have a hand-written parser for into
+/// [WotValue](crate::wot_types::WotValue)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EntitySignatures {
+    pub methods:    &'static [(&'static str, &'static [DefType])],
+    pub properties: &'static [(&'static str, DefType)],
+}
+
+/// Signatures of every entity of a version. Entities whose type is [EntityType::Unknown] are not included
+/// because we cannot tell them apart
+pub type Signatures = [(EntityType, EntitySignatures)];
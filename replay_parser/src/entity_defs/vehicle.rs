@@ -1,3 +1,5 @@
+// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
 use phf::phf_map;
 
 pub static VEHICLE_METHODS: phf::Map<&str, &[&str]> = phf_map! {
@@ -29,6 +31,7 @@ pub static VEHICLE_METHODS: phf::Map<&str, &[&str]> = phf_map! {
     "1_7_1_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
     "1_8_0_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
     "1_9_0_0" => &["ReceiveHorn", "ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
+    "1_9_1_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
     "1_10_0_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
     "1_10_1_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
     "1_11_0_0" => &["ShowShooting", "OnHealthChanged", "OnPushed", "ShowAmmoBayEffect", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "ShowDamageFromShot"],
@@ -50,8 +53,7 @@ pub static VEHICLE_METHODS: phf::Map<&str, &[&str]> = phf_map! {
     "1_20_0_0" => &["OnVehiclePickup", "ShowShooting", "OnPushed", "OnHealthChanged", "ShowAmmoBayEffect", "OnExtraHitted", "ShowRammingEffect", "ShowDamageFromExplosion", "OnStaticCollision", "UpdateLaserSight", "ShowDamageFromShot"],
 };
 
-
-pub static VEHICLE_PROPS: phf::Map<&str, &'static [&'static str]> = phf_map! {
+pub static VEHICLE_PROPS: phf::Map<&str, &[&str]> = phf_map! {
     "0_9_12_0" => &["IsStrafing", "PhysicsMode", "Gear", "IsCrewActive", "TrackScrolling", "GunAnglesPacked", "Health", "EngineMode", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_13_0" => &["IsStrafing", "PhysicsMode", "Gear", "IsCrewActive", "TrackScrolling", "GunAnglesPacked", "Health", "EngineMode", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_14_0" => &["IsStrafing", "PhysicsMode", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
@@ -60,29 +62,28 @@ pub static VEHICLE_PROPS: phf::Map<&str, &'static [&'static str]> = phf_map! {
     "0_9_17_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_18_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_19_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
-
     // Manual adjustment here: We switched places of `GunAnglesPacked` and `IsDisappeared`, Then in original position of GunAnglesPacked we use GunAnglesPackedArr. It's a special case for this version
     "0_9_20_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "GunAnglesPackedArr", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
-
     "0_9_21_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_22_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
     "0_9_23_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
-    "1_0_0_0" =>  &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
-    "1_0_1_0" =>  &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_0_2_0" =>  &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_1_0_0" =>  &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_2_0_0" =>  &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "BotKind", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_3_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_4_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_4_1_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_5_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_5_1_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "LastStandEnabled", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_6_0_0" =>  &["IsCapturingTeamBase", "BurnoutLevel", "IsStrafing", "IsSurfaceContact", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "TeamBasePoints", "GunAnglesPacked", "Health", "EngineMode", "RacePosition", "RaceFinishTime", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "LastCheckpointID", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_6_1_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_7_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_7_1_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_8_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
-    "1_9_0_0" =>  &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired", "Buffs"],
+    "1_0_0_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers"],
+    "1_0_1_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_0_2_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_1_0_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_2_0_0" => &["IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "BotKind", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "SteeringAngle", "StunInfo", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_3_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_4_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_4_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_5_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_5_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "LastStandEnabled", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_6_0_0" => &["IsCapturingTeamBase", "BurnoutLevel", "IsStrafing", "IsSurfaceContact", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "TeamBasePoints", "GunAnglesPacked", "Health", "EngineMode", "RacePosition", "RaceFinishTime", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "LastCheckpointID", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_6_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_7_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_7_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_8_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
+    "1_9_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired", "Buffs"],
+    "1_9_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "Inspired"],
     "1_10_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "Debuff", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "MasterVehID", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "HealingEffect", "DotEffect", "Inspired", "Healing", "HealOverTime"],
     "1_10_1_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "Debuff", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "MasterVehID", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "HealingEffect", "DotEffect", "Inspired", "Healing", "HealOverTime", "Buffs"],
     "1_11_0_0" => &["BurnoutLevel", "IsStrafing", "PhysicsMode", "SiegeState", "IsCrewActive", "Debuff", "IsSpeedCapturing", "IsBlockingCapture", "GunAnglesPacked", "Health", "EngineMode", "MasterVehID", "WheelsState", "StunInfo", "SteeringAngles", "WheelsScroll", "PublicInfo", "DamageStickers", "PublicStateModifiers", "InspiringEffect", "HealingEffect", "DotEffect", "Inspired", "Healing", "HealOverTime"],
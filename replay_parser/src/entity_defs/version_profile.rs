@@ -0,0 +1,201 @@
+use strum::VariantNames;
+
+use super::{
+    EntitySignatures, EntityType, Signatures, ARENA_ENTITY_METHODS, ARENA_ENTITY_PROPS, AVATAR_METHODS,
+    AVATAR_PROPS, ENTITY_SIGNATURES, ENTITY_TYPE_MAP, VEHICLE_METHODS, VEHICLE_PROPS,
+};
+use crate::events::AvatarMethods;
+use crate::utils::version_as_string;
+
+/// Everything the parser needs to know about a version of the game. It is resolved once per replay (see
+/// [Context](crate::Context)) so that parsing a packet does not have to look anything up by version.
+///
+/// A table is `None` if we do not have definition files for it in that version. The tables are either the
+/// ones compiled into the crate or the ones loaded by [DefinitionRegistry](crate::DefinitionRegistry)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VersionProfile {
+    version: [u16; 4],
+
+    pub(super) avatar_methods:  Option<&'static [&'static str]>,
+    pub(super) avatar_props:    Option<&'static [&'static str]>,
+    pub(super) vehicle_methods: Option<&'static [&'static str]>,
+    pub(super) vehicle_props:   Option<&'static [&'static str]>,
+    pub(super) arena_methods:   Option<&'static [(EntityType, &'static [&'static str])]>,
+    pub(super) arena_props:     Option<&'static [(EntityType, &'static [&'static str])]>,
+    pub(super) entity_types:    Option<&'static [EntityType]>,
+    pub(super) signatures:      Option<&'static Signatures>,
+
+    /// Variant of [AvatarMethods] of each method of `avatar_methods`, so that parsing a method does not
+    /// compare names. See [VersionProfile::set_avatar_methods]
+    avatar_method_variants: Option<&'static [Option<usize>]>,
+}
+
+impl VersionProfile {
+    /// Profile with the tables compiled into the crate. `version` should be one of
+    /// [VERSIONS](crate::VERSIONS)
+    pub fn new(version: [u16; 4]) -> Self {
+        let version_str = version_as_string(version);
+        let avatar_methods = AVATAR_METHODS.get(&version_str).copied();
+
+        VersionProfile {
+            version,
+            avatar_methods,
+            avatar_method_variants: avatar_methods.map(resolve_variants::<AvatarMethods>),
+            avatar_props: AVATAR_PROPS.get(&version_str).copied(),
+            vehicle_methods: VEHICLE_METHODS.get(&version_str).copied(),
+            vehicle_props: VEHICLE_PROPS.get(&version_str).copied(),
+            arena_methods: ARENA_ENTITY_METHODS.get(&version_str).copied(),
+            arena_props: ARENA_ENTITY_PROPS.get(&version_str).copied(),
+            entity_types: ENTITY_TYPE_MAP.get(&version_str).copied(),
+            signatures: ENTITY_SIGNATURES.get(&version_str).copied(),
+        }
+    }
+
+    pub fn version(&self) -> [u16; 4] {
+        self.version
+    }
+
+    pub fn avatar_methods(&self) -> Option<&'static [&'static str]> {
+        self.avatar_methods
+    }
+
+    /// Set the avatar methods and resolve their variants
+    pub(super) fn set_avatar_methods(&mut self, avatar_methods: &'static [&'static str]) {
+        self.avatar_methods = Some(avatar_methods);
+        self.avatar_method_variants = Some(resolve_variants::<AvatarMethods>(avatar_methods));
+    }
+
+    /// Index in [AvatarMethods] of the variant of the method `method_id` (see [VariantNames::VARIANTS]).
+    /// `None` if the method is not in the tables or has no variant
+    pub(crate) fn avatar_method_variant(&self, method_id: usize) -> Option<usize> {
+        *self.avatar_method_variants?.get(method_id)?
+    }
+
+    pub fn avatar_props(&self) -> Option<&'static [&'static str]> {
+        self.avatar_props
+    }
+
+    pub fn vehicle_methods(&self) -> Option<&'static [&'static str]> {
+        self.vehicle_methods
+    }
+
+    pub fn vehicle_props(&self) -> Option<&'static [&'static str]> {
+        self.vehicle_props
+    }
+
+    /// Methods of an arena entity (see `ARENA_ENTITIES` of the definition parser for the list of entities)
+    pub fn arena_entity_methods(&self, entity_type: EntityType) -> Option<&'static [&'static str]> {
+        find_table(self.arena_methods?, entity_type)
+    }
+
+    /// Properties of an arena entity (see `ARENA_ENTITIES` of the definition parser for the list of entities)
+    pub fn arena_entity_props(&self, entity_type: EntityType) -> Option<&'static [&'static str]> {
+        find_table(self.arena_props?, entity_type)
+    }
+
+    /// Signatures of the methods and properties of an entity type. Not available for the versions we do not
+    /// have the definition files of
+    pub fn signatures(&self, entity_type: EntityType) -> Option<&'static EntitySignatures> {
+        self.signatures?
+            .iter()
+            .find(|(signatures_entity, _)| *signatures_entity == entity_type)
+            .map(|(_, signatures)| signatures)
+    }
+
+    /// Find the type of an entity from the `entity_type_id` found in `AvatarCreate` and `EntityCreate`
+    pub fn find_entity_type(&self, entity_type_id: usize) -> Option<EntityType> {
+        // Bigword uses non-zero index here, that is why we subtract 1
+        self.entity_types?.get(entity_type_id.checked_sub(1)?).copied()
+    }
+}
+
+/// Index in `T::VARIANTS` of each name of `names`
+fn resolve_variants<T: VariantNames>(names: &'static [&'static str]) -> &'static [Option<usize>] {
+    let variants = names
+        .iter()
+        .map(|name| T::VARIANTS.iter().position(|variant| variant == name))
+        .collect();
+
+    super::registry::intern_slice(variants)
+}
+
+fn find_table(
+    tables: &'static [(EntityType, &'static [&'static str])], entity_type: EntityType,
+) -> Option<&'static [&'static str]> {
+    tables
+        .iter()
+        .find(|(table_entity, _)| *table_entity == entity_type)
+        .map(|(_, names)| *names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VERSIONS;
+
+    #[test]
+    fn every_version_has_tables() {
+        for &version in VERSIONS {
+            let profile = VersionProfile::new(version);
+
+            assert!(profile.avatar_methods().is_some(), "{version:?}");
+            assert!(profile.vehicle_props().is_some(), "{version:?}");
+            assert!(profile.find_entity_type(1).is_some(), "{version:?}");
+        }
+    }
+
+    #[test]
+    fn resolves_1_11_1_to_its_own_tables() {
+        // `onHealthChanged` got its `oldHealth` argument in 1.11.1, which moved it after `onPushed`
+        let method_id = |version, name| {
+            let profile = VersionProfile::new(crate::utils::validate_version(version));
+
+            profile
+                .vehicle_methods()
+                .unwrap()
+                .iter()
+                .position(|method| *method == name)
+        };
+
+        assert_eq!(method_id([1, 11, 0, 0], "OnHealthChanged"), Some(1));
+        assert_eq!(method_id([1, 11, 0, 0], "OnPushed"), Some(2));
+        assert_eq!(method_id([1, 11, 1, 0], "OnHealthChanged"), Some(2));
+        assert_eq!(method_id([1, 11, 1, 0], "OnPushed"), Some(1));
+        assert_eq!(
+            VersionProfile::new([1, 11, 1, 0]).find_entity_type(6),
+            Some(EntityType::Vehicle)
+        );
+    }
+
+    #[test]
+    fn resolves_avatar_method_variants() {
+        for &version in VERSIONS {
+            let profile = VersionProfile::new(version);
+
+            for (method_id, name) in profile.avatar_methods().unwrap().iter().enumerate() {
+                let variant = profile.avatar_method_variant(method_id).unwrap();
+                assert_eq!(AvatarMethods::VARIANTS[variant], *name, "{version:?}");
+            }
+            assert_eq!(profile.avatar_method_variant(1000), None);
+        }
+    }
+
+    #[test]
+    fn compiled_in_signatures_match_tables() {
+        let profile = VersionProfile::new([1, 16, 1, 0]);
+        let signatures = profile.signatures(EntityType::Vehicle).unwrap();
+
+        let names = signatures
+            .methods
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, profile.vehicle_methods().unwrap());
+        assert!(profile.signatures(EntityType::Avatar).is_some());
+
+        // No definition files for this version
+        assert!(VersionProfile::new([1, 19, 1, 0])
+            .signatures(EntityType::Vehicle)
+            .is_none());
+    }
+}
@@ -0,0 +1,53 @@
+// This file is generated by `gen_entity_defs` from the definition files, do not edit it by hand.
+// Run `cargo run -p wot_def_file_parser --bin gen_entity_defs` to regenerate it
+
+/// Versions we have the tables of, i.e. the versions of the definitions whose entity types are known. A
+/// replay of another version is parsed with the tables of the closest one
+pub const VERSIONS: &[[u16; 4]] = &[
+    [0, 9, 12, 0],
+    [0, 9, 13, 0],
+    [0, 9, 14, 0],
+    [0, 9, 15, 0],
+    [0, 9, 16, 0],
+    [0, 9, 17, 0],
+    [0, 9, 18, 0],
+    [0, 9, 19, 0],
+    [0, 9, 20, 0],
+    [0, 9, 21, 0],
+    [0, 9, 22, 0],
+    [0, 9, 23, 0],
+    [1, 0, 0, 0],
+    [1, 0, 1, 0],
+    [1, 0, 2, 0],
+    [1, 1, 0, 0],
+    [1, 2, 0, 0],
+    [1, 3, 0, 0],
+    [1, 4, 0, 0],
+    [1, 4, 1, 0],
+    [1, 5, 0, 0],
+    [1, 5, 1, 0],
+    [1, 6, 0, 0],
+    [1, 6, 1, 0],
+    [1, 7, 0, 0],
+    [1, 7, 1, 0],
+    [1, 8, 0, 0],
+    [1, 9, 0, 0],
+    [1, 10, 0, 0],
+    [1, 10, 1, 0],
+    [1, 11, 0, 0],
+    [1, 11, 1, 0],
+    [1, 12, 0, 0],
+    [1, 12, 1, 0],
+    [1, 13, 0, 0],
+    [1, 14, 0, 0],
+    [1, 14, 1, 0],
+    [1, 15, 0, 0],
+    [1, 16, 0, 0],
+    [1, 16, 1, 0],
+    [1, 17, 0, 0],
+    [1, 17, 1, 0],
+    [1, 18, 0, 0],
+    [1, 18, 1, 0],
+    [1, 19, 0, 0],
+    [1, 19, 1, 0],
+];
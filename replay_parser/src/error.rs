@@ -38,6 +38,9 @@ pub enum ReplayError {
     #[error("Unable to find the arena unique id")]
     MissingArenaUniqueId,
 
+    #[error("Unable to find the start of the battle")]
+    MissingBattleStart,
+
     #[error("Packet stream is corrupted")]
     PacketStreamError,
 
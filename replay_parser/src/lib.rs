@@ -1,25 +1,48 @@
 #![doc(html_root_url = "https://docs.rs/wot_replay_parser/0.2.2")]
 //! A parser for `.wotreplay` files generated by the game World of Tanks
 
+mod crypto;
 mod error;
 mod utils;
 
 mod replay_errors;
 mod replay_parser;
+mod recovery;
+pub use recovery::{RecoveryReason, RecoveryReport};
+mod replay_reader;
+pub use replay_reader::{PacketReader, ReplayReader};
+mod replay_writer;
+pub use replay_writer::ReplayWriter;
+mod anonymizer;
+pub use anonymizer::ReplayAnonymizer;
 
 mod packet_parser;
 pub use packet_parser::events;
 pub use packet_parser::BattleEvent;
 pub use packet_parser::Context;
+pub use packet_parser::LiveEntity;
 pub use packet_parser::EventPrinter;
 pub use packet_parser::EventStream;
+pub use packet_parser::OwnedPacket;
+pub use packet_parser::IndexEntry;
 pub use packet_parser::Packet;
+pub use packet_parser::PacketIndex;
 pub use packet_parser::PacketError;
 pub use packet_parser::PacketParser;
 pub use packet_parser::PacketStream;
+pub use packet_parser::PacketType;
+pub use packet_parser::TimedEventStream;
 
 mod battle_context;
-pub use battle_context::BattleContext;
+pub use battle_context::{ArenaPeriodSpan, BattleContext};
+mod synced_arena_data;
+pub use synced_arena_data::{SyncedArenaData, SyncedDataChange};
+mod arena_state;
+pub use arena_state::{ArenaState, BaseState, RosterEntry, TeamState, VehicleState};
+mod damage_ledger;
+pub use damage_ledger::{DamageInstance, DamageLedger, DamageTotals};
+mod shot_log;
+pub use shot_log::{PlayerShot, ShotHit, ShotLog};
 pub use error::ReplayError;
 // TODO: Remove this * import
 pub use replay_parser::*;
@@ -28,8 +51,11 @@ pub use crate::utils::get_replay_time;
 
 pub mod wot_types {
     pub use wot_types::ArenaBonusType;
+    pub use wot_types::AttackReason;
+    pub use wot_types::ArenaPeriod;
+    pub use wot_types::BattleEventType;
     pub use wot_types::WotValue;
 }
 
 mod entity_defs;
-pub use entity_defs::VERSIONS;
+pub use entity_defs::{DefinitionRegistry, EntitySignatures, VersionProfile, VERSIONS};
@@ -1,13 +1,41 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::{entity_defs::EntityType, utils::validate_version, PacketError};
+use serde::Serialize;
+
+use crate::{
+    entity_defs::{DefinitionRegistry, EntityType, VersionProfile},
+    utils::validate_version,
+    PacketError,
+};
 
 
 #[derive(Default, Debug)]
 pub struct Context {
+    /// Type of every entity seen so far. Entities are not removed from here when they leave so that late
+    /// packets about them can still be parsed
     entities: HashMap<i32, EntityType>,
-    players:  HashMap<i32, String>,
-    version:  [u16; 4],
+
+    /// Entities that the client currently knows about (i.e. that are in its area of interest)
+    live_entities: HashMap<i32, LiveEntity>,
+
+    players: HashMap<i32, String>,
+
+    /// Vehicles of the players that are not in the team of the recording player
+    enemies: HashSet<i32>,
+    profile: VersionProfile,
+}
+
+/// An entity that is in the area of interest of the client
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LiveEntity {
+    pub entity_type: EntityType,
+
+    /// Time of the packet that created the entity or made it enter the area of interest (same as
+    /// [Packet::time](crate::Packet::time))
+    pub entered_at: f32,
+
+    /// Whether the client controls the entity (see [EntityControl](crate::events::EntityControl))
+    pub controlled: bool,
 }
 
 impl Context {
@@ -15,15 +43,39 @@ impl Context {
         let validated_version = validate_version(version);
         Context {
             entities: HashMap::new(),
+            live_entities: HashMap::new(),
             players,
-            version: validated_version,
+            enemies: HashSet::new(),
+            profile: VersionProfile::new(validated_version),
+        }
+    }
+
+    /// Same as [Context::new] but the definitions are taken from `registry` if it has them for `version`.
+    /// Otherwise the definitions compiled into the crate are used
+    pub fn with_registry(
+        version: [u16; 4], players: HashMap<i32, String>, registry: &DefinitionRegistry,
+    ) -> Self {
+        match registry.profile(version) {
+            Some(profile) => Context {
+                entities: HashMap::new(),
+                live_entities: HashMap::new(),
+                players,
+                enemies: HashSet::new(),
+                profile: *profile,
+            },
+            None => Context::new(version, players),
         }
     }
 
     /// This may not be same as the replay version. This version returns a version that is closest to
     /// the actual replay version that we have .def files for
     pub fn get_version(&self) -> [u16; 4] {
-        self.version
+        self.profile.version()
+    }
+
+    /// Definitions for the version returned by [get_version](Context::get_version)
+    pub fn profile(&self) -> &VersionProfile {
+        &self.profile
     }
 
     pub fn find_entity_type(&self, entity_id: i32) -> Result<EntityType, PacketError> {
@@ -39,7 +91,100 @@ impl Context {
         self.entities.insert(entity_id, entity_type);
     }
 
+    /// Mark a known entity as being in the area of interest since `time`. Returns `false` if the type of
+    /// the entity is not known
+    pub fn enter_entity(&mut self, entity_id: i32, time: f32) -> bool {
+        let Some(&entity_type) = self.entities.get(&entity_id) else {
+            return false;
+        };
+
+        self.live_entities.insert(
+            entity_id,
+            LiveEntity {
+                entity_type,
+                entered_at: time,
+                controlled: false,
+            },
+        );
+
+        true
+    }
+
+    /// Remove an entity from the area of interest. Returns the entity if it was live
+    pub fn leave_entity(&mut self, entity_id: i32) -> Option<LiveEntity> {
+        self.live_entities.remove(&entity_id)
+    }
+
+    /// Set whether the client controls a live entity
+    pub fn control_entity(&mut self, entity_id: i32, controlled: bool) {
+        if let Some(entity) = self.live_entities.get_mut(&entity_id) {
+            entity.controlled = controlled;
+        }
+    }
+
+    /// Entities that are currently in the area of interest of the client
+    pub fn live_entities(&self) -> &HashMap<i32, LiveEntity> {
+        &self.live_entities
+    }
+
+    pub fn live_entity(&self, entity_id: i32) -> Option<&LiveEntity> {
+        self.live_entities.get(&entity_id)
+    }
+
     pub fn find_player(&self, id: i32) -> Option<String> {
         self.players.get(&id).map(Into::into)
     }
+
+    /// ID of the vehicle of the player named `name`
+    pub fn find_player_id(&self, name: &str) -> Option<i32> {
+        self.players
+            .iter()
+            .find(|(_, player)| player.split(", ").next() == Some(name))
+            .map(|(&id, _)| id)
+    }
+
+    /// Set the vehicles that are not in the team of the recording player
+    pub fn set_enemies(&mut self, enemies: HashSet<i32>) {
+        self.enemies = enemies;
+    }
+
+    /// Whether the vehicle `id` is not in the team of the recording player. `false` if we do not know
+    pub fn is_enemy(&self, id: i32) -> bool {
+        self.enemies.contains(&id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BattleEvent, ReplayParser};
+
+    #[test]
+    fn tracks_live_entities() {
+        let parser = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+        let mut context = parser.context().unwrap();
+
+        let mut leave_count = 0;
+        for packet in parser.packet_stream() {
+            let packet = packet.unwrap();
+
+            match BattleEvent::parse(&packet, &mut context) {
+                Ok(BattleEvent::EntityCreate(create)) => {
+                    let entity = context.live_entity(create.entity_id).unwrap();
+                    assert_eq!(entity.entered_at, packet.time());
+                }
+                Ok(BattleEvent::EntityLeave(leave)) => {
+                    assert!(context.live_entity(leave.entity_id).is_none());
+                    leave_count += 1;
+                }
+                Ok(BattleEvent::EntityControl(control)) => {
+                    let entity = context.live_entity(control.entity_id).unwrap();
+                    assert_eq!(entity.controlled, control.is_controlled);
+                }
+                _ => {}
+            }
+        }
+
+        assert!(leave_count > 0);
+        assert!(!context.live_entities().is_empty());
+    }
 }
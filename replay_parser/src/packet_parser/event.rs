@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 
-use crate::packet_parser::{Context, Packet, PacketError};
+use crate::packet_parser::{Context, Packet, PacketError, PacketType};
 use crate::{events::*, ReplayError};
 
 /// This enum aims to represent all possible events that can occur in a battle. Variants of this enum
@@ -12,21 +12,32 @@ use crate::{events::*, ReplayError};
 /// - `0x14` (if the replay is from, or before patch 0.9.13)
 /// - `0x18` (if the replay is from or later patch 0.9.14)
 ///
+/// [PacketType] takes care of these differences. Packets that don't have an event yet are
+/// `BattleEvent::Unimplemented` and carry their `PacketType`.
+///
 /// Some of these events have sub-events. For example, `BattleEvent::EntityMethod` has many sub events
-/// (represented as enum variants) such as `Avatar`, `Vehicle` etc. Furthermore, these sub-events also have their
-/// own sub-events. See their documentation for details
+/// (represented as enum variants) such as `Avatar`, `Vehicle` etc. Furthermore, these sub-events also have
+/// their own sub-events. See their documentation for details
 #[derive(Debug, Clone, Serialize)]
 #[non_exhaustive]
 // TODO: Box Large structure
 pub enum BattleEvent {
-    Unimplemented { packet_type: u32, size: usize },
+    Unimplemented {
+        packet_type: PacketType,
+        size:        usize,
+    },
     GameVersion(GameVersion),
     AvatarCreate(AvatarCreate),
     EntityMethod(EntityMethodEvent),
     Position(Position),
-    Chat(Chat),
+    Chat(ChatMessage),
     EntityCreate(EntityCreate),
+    EntityEnter(EntityEnter),
+    EntityLeave(EntityLeave),
+    EntityControl(EntityControl),
     EntityProperty(EntityPropertyEvent),
+    ArenaPeriod(ArenaPeriodEvent),
+    ArenaPeriodLength(ArenaPeriodLength),
     CryptoKey(CryptoKey),
 }
 
@@ -34,19 +45,21 @@ impl BattleEvent {
     /// Parse packet to a Battle event. Optional context is provided to aid in parsing some particular
     /// packets.
     pub fn parse(packet: &Packet, context: &mut Context) -> Result<BattleEvent, ReplayError> {
-        let v = context.get_version();
-
-        let event_result = match packet.packet_type() {
-            0x00 => AvatarCreate::parse_mut(packet, context),
-            0x05 => EntityCreate::parse_mut(packet, context),
-            0x07 => EntityPropertyEvent::parse(packet, context),
-            0x08 => EntityMethodEvent::parse(packet, context),
-            0x0A => Position::parse(packet, context),
-            0x14 if v <= [0, 9, 13, 0] => GameVersion::parse(packet, context),
-            0x18 if v > [0, 9, 13, 0] => GameVersion::parse(packet, context),
-            0x23 => Chat::parse(packet, context),
-            0x3D => CryptoKey::parse(packet, context),
-            packet_type @ _ => Ok(BattleEvent::Unimplemented {
+        let event_result = match packet.kind(context) {
+            PacketType::AvatarCreate => AvatarCreate::parse_mut(packet, context),
+            PacketType::EntityCreate => EntityCreate::parse_mut(packet, context),
+            PacketType::EntityEnter => EntityEnter::parse_mut(packet, context),
+            PacketType::EntityLeave => EntityLeave::parse_mut(packet, context),
+            PacketType::EntityControl => EntityControl::parse_mut(packet, context),
+            PacketType::EntityProperty => EntityPropertyEvent::parse(packet, context),
+            PacketType::EntityMethod => EntityMethodEvent::parse(packet, context),
+            PacketType::Position => Position::parse(packet, context),
+            PacketType::GameVersion => GameVersion::parse(packet, context),
+            PacketType::Chat => ChatMessage::parse(packet, context),
+            PacketType::ArenaPeriod => ArenaPeriodEvent::parse(packet, context),
+            PacketType::ArenaPeriodLength => ArenaPeriodLength::parse(packet, context),
+            PacketType::CryptoKey => CryptoKey::parse(packet, context),
+            packet_type => Ok(BattleEvent::Unimplemented {
                 packet_type,
                 size: packet.payload().len(),
             }),
@@ -120,6 +133,11 @@ impl EventPrinter for BattleEvent {
             Position(x) => x.to_debug_string(context),
             Chat(x) => x.to_debug_string(context),
             EntityCreate(x) => x.to_debug_string(context),
+            EntityEnter(x) => x.to_debug_string(context),
+            EntityLeave(x) => x.to_debug_string(context),
+            EntityControl(x) => x.to_debug_string(context),
+            ArenaPeriod(x) => x.to_debug_string(context),
+            ArenaPeriodLength(x) => x.to_debug_string(context),
             CryptoKey(x) => x.to_debug_string(context),
         }
     }
@@ -170,12 +188,24 @@ impl<'pkt> EventStream<'pkt> {
             context,
         }
     }
+
+    /// Event stream that parses the packets with an existing `context`, for ex. one created with
+    /// [Context::with_registry]
+    pub fn with_context(packet_stream: PacketStream<'pkt>, context: Context) -> Self {
+        EventStream {
+            packet_stream,
+            context,
+        }
+    }
 }
 
-impl<'pkt> Iterator for EventStream<'pkt> {
-    type Item = Result<BattleEvent, ReplayError>;
+impl<'pkt> EventStream<'pkt> {
+    /// Turn this stream into one where every event comes with the time of its packet
+    pub fn timed(self) -> TimedEventStream<'pkt> {
+        TimedEventStream { event_stream: self }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_timed(&mut self) -> Option<Result<(f32, BattleEvent), ReplayError>> {
         let packet = self.packet_stream.next()?;
         match packet {
             Ok(packet) => {
@@ -183,13 +213,36 @@ impl<'pkt> Iterator for EventStream<'pkt> {
                 let event = BattleEvent::parse(&packet, &mut self.context);
 
                 log_if_error(packet_id, &event);
-                Some(event)
+                Some(event.map(|event| (packet.time(), event)))
             }
             Err(err) => Some(Err(err)),
         }
     }
 }
 
+impl<'pkt> Iterator for EventStream<'pkt> {
+    type Item = Result<BattleEvent, ReplayError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_timed()
+            .map(|timed_event| timed_event.map(|(_, event)| event))
+    }
+}
+
+/// An [EventStream] that yields the time of the packet (in seconds since the start of the recording)
+/// along with each event. See [EventStream::timed]
+pub struct TimedEventStream<'pkt> {
+    event_stream: EventStream<'pkt>,
+}
+
+impl<'pkt> Iterator for TimedEventStream<'pkt> {
+    type Item = Result<(f32, BattleEvent), ReplayError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.event_stream.next_timed()
+    }
+}
+
 fn log_if_error(packet_id: i32, event: &Result<BattleEvent, ReplayError>) {
     match event.as_ref() {
         Ok(_) => {}
@@ -0,0 +1,50 @@
+use wot_types::ArenaPeriod;
+
+use crate::packet_parser::prelude::*;
+
+/// `0x16` The arena entered a new period. Unlike the `Period` arena update (see
+/// [UpdateArena](super::UpdateArena)), it is sent in every replay, including the ones that are recorded
+/// from the start of the loading screen
+#[derive(Debug, Clone, EventPrinter, Version, Serialize)]
+pub struct ArenaPeriodEvent {
+    pub period: ArenaPeriod,
+}
+
+impl PacketParser for ArenaPeriodEvent {
+    fn parse(packet: &Packet, context: &Context) -> Result<BattleEvent, PacketError> {
+        let period = parse_arena_period(packet.payload(), context.get_version())?;
+
+        Ok(BattleEvent::ArenaPeriod(ArenaPeriodEvent { period }))
+    }
+}
+
+/// `0x17` Sent right after [ArenaPeriodEvent] with the length of the new period
+#[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
+pub struct ArenaPeriodLength {
+    /// In seconds. Not meaningful for `ArenaPeriod::Waiting` (it is often a large negative number)
+    pub length: f32,
+}
+
+impl PacketParser for ArenaPeriodLength {
+    fn parse(packet: &Packet, context: &Context) -> Result<BattleEvent, PacketError> {
+        let period_length = from_slice(packet.payload(), context.get_version())?;
+
+        Ok(BattleEvent::ArenaPeriodLength(period_length))
+    }
+}
+
+/// Payload of `0x16`, before the id is converted to an [ArenaPeriod]
+#[derive(Debug, Version, Deserialize)]
+struct RawArenaPeriod {
+    period: u32,
+}
+
+/// The payload of `0x16` is the id of the period as a `u32`. Like the other packets, it is read with the
+/// layout of `version`: a field added to the packet goes in [RawArenaPeriod] with a `#[version]` attribute
+pub(crate) fn parse_arena_period(payload: &[u8], version: [u16; 4]) -> Result<ArenaPeriod, PacketError> {
+    let RawArenaPeriod { period } = from_slice(payload, version)?;
+
+    ArenaPeriod::try_from(period as i32).map_err(|_| PacketError::WrongEnumVariant {
+        err: format!("arena period of {period} is invalid"),
+    })
+}
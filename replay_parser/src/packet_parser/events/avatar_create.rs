@@ -1,6 +1,3 @@
-use serde::de;
-
-use crate::entity_defs::find_entity_type;
 use crate::packet_parser::prelude::*;
 use crate::utils;
 use crate::wot_types::WotValue;
@@ -39,30 +36,21 @@ impl PacketParser for AvatarCreate {
 
         let (_, avatar_create) = from_slice_unchecked::<AvatarCreate>(data, version)?;
 
-        let key = utils::version_as_string(version);
         let entity_type_id = avatar_create.entity_type_id;
 
-        let entity_type =
-            find_entity_type(&key, entity_type_id as usize).ok_or_else(|| PacketError::NotFoundError {
-                err: format!("entity type with id: {entity_type_id} not found for version: {key}"),
+        let entity_type = context
+            .profile()
+            .find_entity_type(entity_type_id as usize)
+            .ok_or_else(|| PacketError::NotFoundError {
+                err: format!(
+                    "entity type with id: {entity_type_id} not found for version: {}",
+                    utils::version_as_string(context.get_version())
+                ),
             })?;
 
         context.add_entity(avatar_create.entity_id, entity_type);
+        context.enter_entity(avatar_create.entity_id, packet.time());
 
         Ok(BattleEvent::AvatarCreate(avatar_create))
     }
 }
-
-
-fn deserialize_pickle<'de, D>(deserializer: D) -> core::result::Result<WotValue, D::Error>
-where
-    D: de::Deserializer<'de>,
-{
-    let vec: &[u8] = de::Deserialize::deserialize(deserializer)?;
-    let pickle =
-        serde_pickle::value_from_slice(vec, serde_pickle::DeOptions::new().replace_unresolved_globals())
-            .unwrap();
-
-    let wot_value = serde_pickle::from_value(pickle).map_err(|e| serde::de::Error::custom(e.to_string()))?;
-    Ok(wot_value)
-}
@@ -1,20 +1,220 @@
-use nom::number::complete::le_u32;
+use nom::{bytes::complete::take, number::complete::le_u32};
 
 use crate::packet_parser::prelude::*;
 
-#[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
-pub struct Chat {
-    pub msg: String,
+/// Text color of the messages sent to the team chat
+const TEAM_COLOR: &str = "#80D63A";
+
+/// Text color of the messages sent to the chat of both teams
+const ALL_COLOR: &str = "#FFFFFF";
+
+/// Text color of the messages sent to the chat of the squad
+const SQUAD_COLOR: &str = "#FFC697";
+
+/// `0x23` A message of the battle chat, as it is displayed by the client. The client formats messages as
+/// `<font color='#80D63A'>name[CLAN] (Tank)&nbsp;: </font><font color='#80D63A'>text</font>`
+#[derive(Debug, Clone, EventPrinter, Serialize)]
+pub struct ChatMessage {
+    /// Same as [Packet::time](crate::Packet::time)
+    pub time: f32,
+
+    /// Name of the player who sent the message, without the clan and the vehicle. `None` for system
+    /// messages
+    pub sender_name: Option<String>,
+
+    /// ID of the vehicle of the sender, found by name in the player list of the replay
+    #[event_debug(ignore)]
+    pub sender_id: Option<i32>,
+
+    pub channel: ChatChannel,
+
+    /// The text of the message, without markup
+    pub text: String,
+
+    /// The message as it was sent, with markup
+    #[event_debug(ignore)]
+    pub raw: String,
+}
+
+/// The channel of a message is not sent. A message from an enemy can only be on the chat of both teams,
+/// the channel of the other messages is guessed from the color of the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ChatChannel {
+    Team,
+    All,
+    Squad,
+
+    /// A message from the game (i.e without a sender)
+    System,
+
+    /// A message from a player with a text color we do not know (ex: a colorblind palette)
+    Unknown,
 }
 
-impl PacketParser for Chat {
-    fn parse(packet: &Packet, _: &Context) -> Result<BattleEvent, PacketError> {
+impl PacketParser for ChatMessage {
+    fn parse(packet: &Packet, context: &Context) -> Result<BattleEvent, PacketError> {
         let data = packet.payload();
 
         let (remaining, msg_length) = le_u32(data)?;
-        let msg_buffer = &remaining[..msg_length as usize];
-        let msg = String::from_utf8_lossy(msg_buffer);
+        let (_, msg_buffer) = take(msg_length)(remaining)?;
+        let raw = String::from_utf8_lossy(msg_buffer).into_owned();
+
+        let segments = font_segments(&raw);
+        let message = match segments.as_slice() {
+            [(_, sender), (color, text)] if sender.ends_with("&nbsp;: ") => {
+                let sender = strip_markup(sender.trim_end_matches("&nbsp;: "));
+                let sender_name = player_name(&sender).to_string();
+                let sender_id = context.find_player_id(&sender_name);
+
+                ChatMessage {
+                    time: packet.time(),
+                    channel: match sender_id {
+                        Some(id) if context.is_enemy(id) => ChatChannel::All,
+                        _ => channel_from_color(color),
+                    },
+                    sender_id,
+                    sender_name: Some(sender_name),
+                    text: strip_markup(text),
+                    raw,
+                }
+            }
+            _ => ChatMessage {
+                time: packet.time(),
+                sender_name: None,
+                sender_id: None,
+                channel: ChatChannel::System,
+                text: strip_markup(&raw),
+                raw,
+            },
+        };
+
+        Ok(BattleEvent::Chat(message))
+    }
+}
+
+/// Color and content of each `<font color='...'>...</font>` of the message
+fn font_segments(msg: &str) -> Vec<(&str, &str)> {
+    let mut segments = Vec::new();
+    let mut remaining = msg;
+
+    while let Some(start) = remaining.find("<font color='") {
+        let after_color = &remaining[(start + "<font color='".len())..];
+        let Some(color_end) = after_color.find('\'') else {
+            break;
+        };
+        let Some(content_start) = after_color.find('>') else {
+            break;
+        };
+        let content = &after_color[(content_start + 1)..];
+        let Some(content_end) = content.find("</font>") else {
+            break;
+        };
+
+        segments.push((&after_color[..color_end], &content[..content_end]));
+        remaining = &content[(content_end + "</font>".len())..];
+    }
+
+    segments
+}
+
+/// Remove the tags and decode the entities that the client adds to the text
+fn strip_markup(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => output.push(c),
+            _ => {}
+        }
+    }
+
+    output
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The sender is displayed as `name[CLAN] (Tank)`, the clan and the vehicle being optional
+fn player_name(sender: &str) -> &str {
+    let end = sender.find(['[', ' ']).unwrap_or(sender.len());
+
+    &sender[..end]
+}
+
+fn channel_from_color(color: &str) -> ChatChannel {
+    if color.eq_ignore_ascii_case(TEAM_COLOR) {
+        ChatChannel::Team
+    } else if color.eq_ignore_ascii_case(ALL_COLOR) {
+        ChatChannel::All
+    } else if color.eq_ignore_ascii_case(SQUAD_COLOR) {
+        ChatChannel::Squad
+    } else {
+        ChatChannel::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+
+    fn parse_message(raw: &str, context: &Context) -> ChatMessage {
+        let mut data = Vec::new();
+        data.extend((raw.len() as u32 + 4).to_le_bytes());
+        data.extend(0x23u32.to_le_bytes());
+        data.extend(10.0f32.to_le_bytes());
+        data.extend((raw.len() as u32).to_le_bytes());
+        data.extend(raw.as_bytes());
+
+        let BattleEvent::Chat(message) = ChatMessage::parse(&Packet::new(0, &data), context).unwrap() else {
+            panic!("not a chat message");
+        };
+
+        message
+    }
+
+    #[test]
+    fn parses_player_message() {
+        let raw = "<font color='#80D63A'>Dracola69_[ABC] (TVP T 50/51)&nbsp;: </font><font \
+                   color='#80D63A'>gg &amp; wp</font>";
+        let segments = font_segments(raw);
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(player_name(&strip_markup(segments[0].1)), "Dracola69_");
+        assert_eq!(channel_from_color(segments[1].0), ChatChannel::Team);
+        assert_eq!(strip_markup(segments[1].1), "gg & wp");
+    }
+
+    #[test]
+    fn decodes_channel() {
+        let players = HashMap::from([(1, "ally, T-34".to_string()), (2, "enemy, T-34".to_string())]);
+        let mut context = Context::new([1, 16, 1, 0], players);
+        context.set_enemies(HashSet::from([2]));
+
+        // An enemy can only write on the chat of both teams, whatever the color
+        let raw = "<font color='#80D63A'>enemy (T-34)&nbsp;: </font><font color='#80D63A'>hi</font>";
+        let message = parse_message(raw, &context);
+        assert_eq!((message.sender_id, message.channel), (Some(2), ChatChannel::All));
+
+        let raw = "<font color='#FFC697'>ally (T-34)&nbsp;: </font><font color='#FFC697'>hi</font>";
+        let message = parse_message(raw, &context);
+        assert_eq!(
+            (message.sender_id, message.channel),
+            (Some(1), ChatChannel::Squad)
+        );
 
-        Ok(BattleEvent::Chat(Chat { msg: msg.into() }))
+        let message = parse_message("Base captured", &context);
+        assert_eq!(
+            (message.sender_name, message.channel),
+            (None, ChatChannel::System)
+        );
     }
 }
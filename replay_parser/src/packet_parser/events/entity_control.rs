@@ -0,0 +1,58 @@
+use crate::packet_parser::prelude::*;
+
+/// `0x02` The client gains or loses control of an entity (i.e. it is the one that moves it)
+#[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
+pub struct EntityControl {
+    #[event_debug(as_player)]
+    pub entity_id:     i32,
+    pub is_controlled: bool,
+}
+
+impl PacketParser for EntityControl {
+    fn parse_mut(packet: &Packet, context: &mut Context) -> Result<BattleEvent, PacketError> {
+        let entity_control = from_slice::<EntityControl>(packet.payload(), context.get_version())?;
+
+        context.control_entity(entity_control.entity_id, entity_control.is_controlled);
+
+        Ok(BattleEvent::EntityControl(entity_control))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::entity_defs::EntityType;
+
+    fn packet(entity_id: i32, is_controlled: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend(5u32.to_le_bytes());
+        data.extend(0x02u32.to_le_bytes());
+        data.extend(5.0f32.to_le_bytes());
+        data.extend(entity_id.to_le_bytes());
+        data.push(is_controlled as u8);
+
+        data
+    }
+
+    #[test]
+    fn parses_entity_control() {
+        let mut context = Context::new([1, 16, 1, 0], HashMap::new());
+        context.add_entity(42, EntityType::Vehicle);
+        context.enter_entity(42, 1.0);
+
+        let data = packet(42, true);
+        let BattleEvent::EntityControl(control) =
+            EntityControl::parse_mut(&Packet::new(0, &data), &mut context).unwrap()
+        else {
+            panic!("not an entity control");
+        };
+        assert_eq!((control.entity_id, control.is_controlled), (42, true));
+        assert!(context.live_entity(42).unwrap().controlled);
+
+        let data = packet(42, false);
+        EntityControl::parse_mut(&Packet::new(0, &data), &mut context).unwrap();
+        assert!(!context.live_entity(42).unwrap().controlled);
+    }
+}
@@ -1,6 +1,8 @@
 use nom::{bytes::complete::take, number::complete::le_u32};
 
-use crate::{entity_defs::find_entity_type, packet_parser::prelude::*};
+use super::entity_property::parse_property_list;
+use super::EntityProperty;
+use crate::packet_parser::prelude::*;
 
 #[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
 pub struct EntityCreate {
@@ -14,25 +16,42 @@ pub struct EntityCreate {
     pub unknown:   i32,
     pub position:  Vector3,
     pub direction: Vector3,
+
+    /// Values of the properties when the entity was created, in the order they were sent. If a property
+    /// cannot be decoded, it and the ones after it are missing
+    #[serde(skip_deserializing)]
+    pub properties: Vec<EntityProperty>,
 }
 
 impl PacketParser for EntityCreate {
     fn parse_mut(packet: &Packet, context: &mut Context) -> Result<BattleEvent, PacketError> {
         let data = packet.payload();
 
-        let (remaining, entity_create) = from_slice_unchecked::<EntityCreate>(data, context.get_version())?;
+        let (remaining, mut entity_create) =
+            from_slice_unchecked::<EntityCreate>(data, context.get_version())?;
         let (remaining, size) = le_u32(remaining)?;
-        let (remaining, _data) = take(size)(remaining)?; // TODO: We will ignore the data for now...
+        let (remaining, property_data) = take(size)(remaining)?;
 
-        let key = utils::version_as_string(context.get_version());
         let entity_type_id = entity_create.entity_type_id;
 
-        let entity_type =
-            find_entity_type(&key, entity_type_id as usize).ok_or_else(|| PacketError::NotFoundError {
-                err: format!("entity type with id: {entity_type_id} not found for version: {key}"),
+        let entity_type = context
+            .profile()
+            .find_entity_type(entity_type_id as usize)
+            .ok_or_else(|| PacketError::NotFoundError {
+                err: format!(
+                    "entity type with id: {entity_type_id} not found for version: {}",
+                    utils::version_as_string(context.get_version())
+                ),
             })?;
 
+        if let Err(err) =
+            parse_property_list(entity_type, property_data, context, &mut entity_create.properties)
+        {
+            tracing::warn!(entity_id = entity_create.entity_id, %entity_type, error = ?err, "incomplete properties");
+        }
+
         context.add_entity(entity_create.entity_id, entity_type);
+        context.enter_entity(entity_create.entity_id, packet.time());
 
         if !remaining.is_empty() {
             Err(PacketError::UnconsumedInput)
@@ -0,0 +1,86 @@
+use crate::packet_parser::prelude::*;
+
+/// `0x03` An entity that the client already knows about enters its area of interest. Entities seen for the
+/// first time are sent with [EntityCreate](super::EntityCreate) instead.
+///
+/// This packet does not appear in the replays we have, so the layout (including the version of `vehicle_id`)
+/// is taken from `EntityCreate`
+#[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
+pub struct EntityEnter {
+    #[event_debug(as_player)]
+    pub entity_id: i32,
+
+    #[version([0, 9, 14, 0])]
+    pub vehicle_id: Option<i32>,
+
+    pub space_id: i32,
+}
+
+impl PacketParser for EntityEnter {
+    fn parse_mut(packet: &Packet, context: &mut Context) -> Result<BattleEvent, PacketError> {
+        let entity_enter = from_slice::<EntityEnter>(packet.payload(), context.get_version())?;
+
+        if !context.enter_entity(entity_enter.entity_id, packet.time()) {
+            return Err(PacketError::NotFoundError {
+                err: format!(
+                    "entity with id: {} entered before it was created",
+                    entity_enter.entity_id
+                ),
+            });
+        }
+
+        Ok(BattleEvent::EntityEnter(entity_enter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::entity_defs::EntityType;
+
+    fn packet(payload: &[i32]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend((payload.len() as u32 * 4).to_le_bytes());
+        data.extend(0x03u32.to_le_bytes());
+        data.extend(5.0f32.to_le_bytes());
+        payload.iter().for_each(|value| data.extend(value.to_le_bytes()));
+
+        data
+    }
+
+    #[test]
+    fn parses_entity_enter() {
+        let mut context = Context::new([1, 16, 1, 0], HashMap::new());
+        context.add_entity(42, EntityType::Vehicle);
+
+        let data = packet(&[42, 7, 3]);
+        let BattleEvent::EntityEnter(enter) =
+            EntityEnter::parse_mut(&Packet::new(0, &data), &mut context).unwrap()
+        else {
+            panic!("not an entity enter");
+        };
+        assert_eq!(
+            (enter.entity_id, enter.vehicle_id, enter.space_id),
+            (42, Some(7), 3)
+        );
+        assert_eq!(context.live_entity(42).unwrap().entered_at, 5.0);
+
+        // Before 0.9.14 there is no vehicle id
+        let mut context = Context::new([0, 9, 13, 0], HashMap::new());
+        context.add_entity(42, EntityType::Vehicle);
+
+        let data = packet(&[42, 3]);
+        let BattleEvent::EntityEnter(enter) =
+            EntityEnter::parse_mut(&Packet::new(0, &data), &mut context).unwrap()
+        else {
+            panic!("not an entity enter");
+        };
+        assert_eq!((enter.entity_id, enter.vehicle_id, enter.space_id), (42, None, 3));
+
+        // Entities must be created before they enter
+        let data = packet(&[43, 3]);
+        assert!(EntityEnter::parse_mut(&Packet::new(0, &data), &mut context).is_err());
+    }
+}
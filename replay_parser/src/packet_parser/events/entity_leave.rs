@@ -0,0 +1,19 @@
+use crate::packet_parser::prelude::*;
+
+/// `0x04` An entity leaves the area of interest of the client, for ex. an enemy vehicle that is no longer
+/// spotted. The client destroys the entity: it comes back with [EntityCreate](super::EntityCreate)
+#[derive(Debug, Clone, EventPrinter, Version, Deserialize, Serialize)]
+pub struct EntityLeave {
+    #[event_debug(as_player)]
+    pub entity_id: i32,
+}
+
+impl PacketParser for EntityLeave {
+    fn parse_mut(packet: &Packet, context: &mut Context) -> Result<BattleEvent, PacketError> {
+        let entity_leave = from_slice::<EntityLeave>(packet.payload(), context.get_version())?;
+
+        context.leave_entity(entity_leave.entity_id);
+
+        Ok(BattleEvent::EntityLeave(entity_leave))
+    }
+}
@@ -0,0 +1,140 @@
+use strum::VariantNames;
+
+use super::{EntityMethod, ShowDamageFromExplosion};
+use crate::{entity_defs::EntityType, packet_parser::prelude::*};
+
+/// Parse a method of an arena entity with its typed enum. `None` if we do not have the method tables of the
+/// entity or an enum for the entity. Like the other entities, a method we do not know the format of is
+/// decoded with its signature if it is available
+pub(super) fn parse_arena_method(
+    entity_type: EntityType, input: &[u8], method_id: usize, context: &Context,
+) -> Option<Result<EntityMethod, PacketError>> {
+    let name = *context
+        .profile()
+        .arena_entity_methods(entity_type)?
+        .get(method_id)?;
+
+    let method = match entity_type {
+        EntityType::DestructibleEntity => parse_variant::<DestructibleEntityMethods>(name, input, context)?
+            .map(EntityMethod::DestructibleEntity),
+        EntityType::Sector => parse_variant::<SectorMethods>(name, input, context)?.map(EntityMethod::Sector),
+        _ => return None,
+    };
+
+    Some(method.map_err(|err| PacketError::EntityMethodError {
+        entity_type,
+        method: name,
+        root_cause: err.to_string(),
+    }))
+}
+
+fn parse_variant<T: VariantDeserializer + VariantNames>(
+    name: &'static str, input: &[u8], context: &Context,
+) -> Option<Result<T, PacketError>> {
+    if !T::VARIANTS.contains(&name) {
+        return None;
+    }
+
+    Some(T::deserialize_variant(name, input, context))
+}
+
+/// Methods of an object that can be shot at and destroyed, like the bunkers of Frontline
+#[derive(Debug, Clone, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize)]
+pub enum DestructibleEntityMethods {
+    #[variant_de(delegate)]
+    OnHealthChanged(DestructibleHealthChanged),
+
+    #[variant_de(delegate)]
+    ShowDamageFromExplosion(ShowDamageFromExplosion),
+
+    #[variant_de(delegate)]
+    ShowDamageFromShot(DestructibleDamageFromShot),
+}
+
+/// Methods of a sector of the Frontline map
+#[derive(Debug, Clone, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize)]
+pub enum SectorMethods {
+    /// A bomber strike on the sector, at this position
+    ShowBomb(Vector3),
+}
+
+/// Ex: A Frontline bunker takes a shot and loses hp
+#[derive(Serialize, Deserialize, Debug, Clone, EventPrinter, Version)]
+pub struct DestructibleHealthChanged {
+    pub new_health: i16,
+
+    #[event_debug(as_player)]
+    pub attacker_id: i32,
+
+    /// Index of the reason in `ATTACK_REASONS`
+    pub attack_reason: u8,
+    pub hit_flags:     i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Version)]
+pub struct DestructibleDamageFromShot {
+    /// Packed segments of the hits, in the format of the
+    /// [DamageStickers](crate::events::DestructibleEntityProperties::DamageStickers) property
+    pub points:        Vec<u64>,
+    pub effects_index: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn parse(entity_type: EntityType, method_id: usize, input: &[u8]) -> EntityMethod {
+        let context = Context::new([1, 16, 1, 0], HashMap::new());
+
+        EntityMethod::new(entity_type, input, &context, method_id).unwrap()
+    }
+
+    #[test]
+    fn decodes_destructible_methods() {
+        let mut input = Vec::new();
+        input.extend(250i16.to_le_bytes());
+        input.extend(1234i32.to_le_bytes());
+        input.push(0);
+        input.extend(0i32.to_le_bytes());
+
+        let method = parse(EntityType::DestructibleEntity, 0, &input);
+        let EntityMethod::DestructibleEntity(DestructibleEntityMethods::OnHealthChanged(health)) = method
+        else {
+            panic!("unexpected method: {method:?}");
+        };
+        assert_eq!(health.new_health, 250);
+        assert_eq!(health.attacker_id, 1234);
+
+        let method = parse(EntityType::DestructibleEntity, 2, &[1, 9, 0, 0, 0, 0, 0, 0, 0, 3]);
+        let EntityMethod::DestructibleEntity(DestructibleEntityMethods::ShowDamageFromShot(shot)) = method
+        else {
+            panic!("unexpected method: {method:?}");
+        };
+        assert_eq!(shot.points, [9]);
+        assert_eq!(shot.effects_index, 3);
+
+        // Not enough bytes for the arguments
+        let context = Context::new([1, 16, 1, 0], HashMap::new());
+        let result = EntityMethod::new(EntityType::DestructibleEntity, &input[..4], &context, 0);
+        assert!(matches!(result, Err(PacketError::EntityMethodError { .. })));
+    }
+
+    #[test]
+    fn decodes_sector_methods() {
+        let input: Vec<u8> = [1.0f32, 2.0, 3.0].iter().flat_map(|f| f.to_le_bytes()).collect();
+
+        let method = parse(EntityType::Sector, 0, &input);
+        let EntityMethod::Sector(SectorMethods::ShowBomb(position)) = method else {
+            panic!("unexpected method: {method:?}");
+        };
+        assert_eq!(position.x, 1.0);
+
+        // Sector has a single method
+        assert!(matches!(
+            parse(EntityType::Sector, 1, &input),
+            EntityMethod::NotImplemented { .. }
+        ));
+    }
+}
@@ -0,0 +1,363 @@
+use nom::number::complete::{le_i32, le_u8};
+use wot_types::BattleEventType;
+
+use crate::packet_parser::generic::parse_packed_len;
+use crate::packet_parser::prelude::*;
+
+/// An entry of the player's battle event feed (the ribbons): damage dealt, spotting, assists, blocked
+/// damage, crits, kills, base capture and defence, etc. Only sent to the player who recorded the replay
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerBattleEvent {
+    /// `None` if the event type is not known to this crate or for the version of the replay. See
+    /// `event_type_id` in that case
+    pub event_type:    Option<BattleEventType>,
+    pub event_type_id: u8,
+
+    /// Vehicle (or other entity) the event is about. Ex: the vehicle that was damaged
+    pub target_id: i32,
+
+    /// Number of times the event happened. Ex: number of hits blocked for `Tanking`
+    pub count: u16,
+
+    /// `raw_details` decoded with the format of the event type
+    pub details: BattleEventDetails,
+
+    /// Packed data that depends on the event type. Ex: for `Damage`, the damage dealt and the attack reason
+    pub raw_details: u64,
+}
+
+/// Details of a [PlayerBattleEvent], as unpacked by the client for each event type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BattleEventDetails {
+    /// Damage dealt, received, blocked or assisted with. The higher bits (shell type, etc.) are left out
+    Damage {
+        damage:        u16,
+        /// Index of the reason in `ATTACK_REASONS`
+        attack_reason: u8,
+    },
+
+    /// Capture points added or dropped
+    CapturePoints(u64),
+
+    /// Bit mask of the modules and crew members that were damaged or destroyed
+    Crits(u64),
+
+    Visibility {
+        is_visible: bool,
+
+        /// `false` if the vehicle was spotted by an ally
+        is_direct: bool,
+    },
+
+    /// Details of an event type that does not have any or whose format we do not know
+    Other(u64),
+}
+
+impl BattleEventDetails {
+    pub fn new(event_type: Option<BattleEventType>, details: u64) -> Self {
+        use BattleEventType::*;
+
+        match event_type {
+            Some(
+                Damage | ReceivedDamage | Tanking | RadioAssist | TrackAssist | StunAssist | SmokeAssist
+                | InspireAssist,
+            ) => BattleEventDetails::Damage {
+                damage:        details as u16,
+                attack_reason: (details >> 16) as u8,
+            },
+            Some(BaseCapturePoints | BaseCaptureDropped) => BattleEventDetails::CapturePoints(details),
+            Some(Crit | ReceivedCrit) => BattleEventDetails::Crits(details),
+            Some(Spotted | TargetVisibility | Detected) => BattleEventDetails::Visibility {
+                is_visible: details & 0b01 != 0,
+                is_direct:  details & 0b10 != 0,
+            },
+            _ => BattleEventDetails::Other(details),
+        }
+    }
+}
+
+/// `(first version, event type)`, indexed by the id of the event type. Event types were added with the
+/// mechanics they describe (stun, Frontline, etc.), so an id is only known from the version it was introduced
+/// in
+const BATTLE_EVENT_TYPES: &[([u16; 4], BattleEventType)] = {
+    use BattleEventType::*;
+
+    &[
+        ([0, 0, 0, 0], Spotted),
+        ([0, 0, 0, 0], RadioAssist),
+        ([0, 0, 0, 0], TrackAssist),
+        ([0, 0, 0, 0], BaseCapturePoints),
+        ([0, 0, 0, 0], BaseCaptureDropped),
+        ([0, 0, 0, 0], Tanking),
+        ([0, 0, 0, 0], Crit),
+        ([0, 0, 0, 0], Damage),
+        ([0, 0, 0, 0], Kill),
+        ([0, 0, 0, 0], ReceivedCrit),
+        ([0, 0, 0, 0], ReceivedDamage),
+        ([0, 9, 20, 0], StunAssist),
+        ([1, 0, 0, 0], TargetVisibility),
+        ([1, 0, 0, 0], Detected),
+        ([1, 2, 0, 0], DestructibleDamaged),
+        ([1, 2, 0, 0], DestructibleDestroyed),
+        ([1, 2, 0, 0], DestructiblesDefended),
+        ([1, 2, 0, 0], DefenderBonus),
+        ([1, 4, 0, 0], SmokeAssist),
+        ([1, 4, 0, 0], InspireAssist),
+        ([1, 10, 0, 0], MultiStun),
+        ([1, 10, 0, 0], EquipmentTimerExpired),
+    ]
+};
+
+/// Type of the event with the id `id` in `version`. `None` if the id is not known for that version
+fn battle_event_type(id: u8, version: [u16; 4]) -> Option<BattleEventType> {
+    BATTLE_EVENT_TYPES
+        .get(id as usize)
+        .filter(|(since, _)| version >= *since)
+        .map(|(_, event_type)| *event_type)
+}
+
+/// `BATTLE_EVENT` in `alias.xml`, with the fields in the order they are sent. `details` is a `UINT32`
+/// before 1.0.1 and a `UINT64` since
+#[derive(Debug, Deserialize, Version)]
+struct RawBattleEvent {
+    event_type: u8,
+    target_id:  i32,
+
+    #[version(range([0, 0, 0, 0], [1, 0, 1, 0]))]
+    details_u32: Option<u32>,
+
+    #[version([1, 0, 1, 0])]
+    details_u64: Option<u64>,
+
+    count: u16,
+}
+
+impl RawBattleEvent {
+    fn into_event(self, version: [u16; 4]) -> PlayerBattleEvent {
+        let event_type = battle_event_type(self.event_type, version);
+        let details = self
+            .details_u64
+            .or(self.details_u32.map(u64::from))
+            .unwrap_or_default();
+
+        PlayerBattleEvent {
+            event_type,
+            event_type_id: self.event_type,
+            target_id: self.target_id,
+            count: self.count,
+            details: BattleEventDetails::new(event_type, details),
+            raw_details: details,
+        }
+    }
+}
+
+/// `BATTLE_EVENT` has `AllowNone` since 1.14: each element of the array starts with a byte telling whether
+/// it is present
+const NULLABLE_EVENT_SINCE: [u16; 4] = [1, 14, 0, 0];
+
+/// Only 1.16.1 sends the id of a vehicle before the events
+const VEHICLE_ID_VERSIONS: ([u16; 4], [u16; 4]) = ([1, 16, 1, 0], [1, 17, 0, 0]);
+
+/// Arguments of `onBattleEvents`
+#[derive(Debug, Clone, Serialize)]
+pub struct BattleEvents {
+    /// Vehicle whose feed the events are for (`OBJECT_ID` argument). Only sent in 1.16.1
+    pub vehicle_id: Option<i32>,
+
+    /// Elements that are `None` are left out
+    pub events: Vec<PlayerBattleEvent>,
+}
+
+/// Parse `onBattleEvents`: `ARRAY<BATTLE_EVENT>`, preceded by an `OBJECT_ID` in 1.16.1
+pub fn parse_battle_events(input: &[u8], version: [u16; 4]) -> Result<BattleEvents, PacketError> {
+    let (since, until) = VEHICLE_ID_VERSIONS;
+    let (mut input, vehicle_id) = if version >= since && version < until {
+        let (remaining, vehicle_id) = le_i32(input)?;
+        (remaining, Some(vehicle_id))
+    } else {
+        (input, None)
+    };
+
+    let (remaining, len) = parse_packed_len(input)?;
+    input = remaining;
+
+    let mut events = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        if version >= NULLABLE_EVENT_SINCE {
+            let (remaining, is_present) = le_u8(input)?;
+            input = remaining;
+
+            if is_present == 0 {
+                continue;
+            }
+        }
+
+        let (remaining, event) = from_slice_unchecked::<RawBattleEvent>(input, version)?;
+        events.push(event.into_event(version));
+        input = remaining;
+    }
+
+    if !input.is_empty() {
+        return Err(PacketError::UnconsumedInput);
+    }
+
+    Ok(BattleEvents { vehicle_id, events })
+}
+
+/// `onBattleEvent` (until 0.9.15), the method that was replaced by `onBattleEvents`. Its arguments are the
+/// type of the event and a list of details. We do not know if the types have the same ids as
+/// [BattleEventType] so they are kept as is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyBattleEvent {
+    pub event_type_id: u8,
+    pub details:       Vec<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `BATTLE_EVENT`: `eventType UINT8`, `targetID OBJECT_ID`, `details UINT32` (`UINT64` since 1.0.1) and
+    /// `count UINT16`
+    fn battle_event(event_type: u8, target_id: i32, details: u64, count: u16, version: [u16; 4]) -> Vec<u8> {
+        let mut input = vec![event_type];
+        input.extend(target_id.to_le_bytes());
+        if version >= [1, 0, 1, 0] {
+            input.extend(details.to_le_bytes());
+        } else {
+            input.extend((details as u32).to_le_bytes());
+        }
+        input.extend(count.to_le_bytes());
+
+        input
+    }
+
+    #[test]
+    fn parses_events_with_the_layout_of_each_version() {
+        // 0.9.16 to 1.0.0: `details` is a `UINT32`
+        let version = [0, 9, 20, 0];
+        let input = [
+            &[2][..],
+            &battle_event(7, 5, 300, 1, version),
+            &battle_event(200, 9, 0, 3, version),
+        ]
+        .concat();
+
+        let BattleEvents { vehicle_id, events } = parse_battle_events(&input, version).unwrap();
+        assert_eq!(vehicle_id, None);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, Some(BattleEventType::Damage));
+        assert_eq!(
+            (events[0].target_id, events[0].count, events[0].raw_details),
+            (5, 1, 300)
+        );
+        assert_eq!(
+            events[0].details,
+            BattleEventDetails::Damage {
+                damage:        300,
+                attack_reason: 0,
+            }
+        );
+        assert_eq!((events[1].event_type, events[1].event_type_id), (None, 200));
+        assert_eq!((events[1].target_id, events[1].count), (9, 3));
+
+        // 1.0.1 to 1.13: `details` is a `UINT64`
+        let version = [1, 10, 0, 0];
+        let input = [&[1][..], &battle_event(3, 5, 1 << 40, 2, version)].concat();
+        let events = parse_battle_events(&input, version).unwrap().events;
+        assert_eq!(events[0].event_type, Some(BattleEventType::BaseCapturePoints));
+        assert_eq!((events[0].raw_details, events[0].count), (1 << 40, 2));
+
+        // Since 1.14: each element starts with whether it is present
+        let version = [1, 18, 0, 0];
+        let input = [
+            &[3, 1][..],
+            &battle_event(8, 5, 0, 1, version),
+            &[0, 1],
+            &battle_event(0, 6, 0b11, 1, version),
+        ]
+        .concat();
+        let events = parse_battle_events(&input, version).unwrap().events;
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            (events[0].event_type, events[0].target_id),
+            (Some(BattleEventType::Kill), 5)
+        );
+        assert_eq!(
+            (events[1].event_type, events[1].target_id),
+            (Some(BattleEventType::Spotted), 6)
+        );
+
+        // 1.16.1 sends a vehicle id first
+        let version = [1, 16, 1, 0];
+        let input = [
+            &1234i32.to_le_bytes()[..],
+            &[1, 1],
+            &battle_event(8, 5, 0, 1, version),
+        ]
+        .concat();
+        let battle_events = parse_battle_events(&input, version).unwrap();
+        assert_eq!(battle_events.vehicle_id, Some(1234));
+        assert_eq!(battle_events.events[0].target_id, 5);
+
+        assert!(matches!(
+            parse_battle_events(&[input.as_slice(), &[0]].concat(), version),
+            Err(PacketError::UnconsumedInput)
+        ));
+    }
+
+    #[test]
+    fn parses_legacy_event() {
+        use std::collections::HashMap;
+
+        use crate::entity_defs::EntityType;
+        use crate::events::{AvatarMethods, EntityMethod};
+
+        // `onBattleEvent` of 0.9.15: `UINT8` and `ARRAY<UINT64>`
+        let context = Context::new([0, 9, 15, 0], HashMap::new());
+        let method_id = context
+            .profile()
+            .avatar_methods()
+            .unwrap()
+            .iter()
+            .position(|name| *name == "OnBattleEvent")
+            .unwrap();
+        let input = [&[7, 2][..], &300u64.to_le_bytes(), &5u64.to_le_bytes()].concat();
+
+        let method = EntityMethod::new(EntityType::Avatar, &input, &context, method_id).unwrap();
+        let EntityMethod::Avatar(AvatarMethods::OnBattleEvent(event)) = method else {
+            panic!("unexpected method: {method:?}");
+        };
+        assert_eq!((event.event_type_id, event.details), (7, vec![300, 5]));
+    }
+
+    #[test]
+    fn gates_event_types_by_version() {
+        assert_eq!(
+            battle_event_type(11, [1, 16, 1, 0]),
+            Some(BattleEventType::StunAssist)
+        );
+        assert_eq!(battle_event_type(11, [0, 9, 15, 0]), None);
+        assert_eq!(
+            battle_event_type(19, [1, 16, 1, 0]),
+            Some(BattleEventType::InspireAssist)
+        );
+        assert_eq!(battle_event_type(22, [1, 16, 1, 0]), None);
+
+        // Spotted directly, and the bits above the damage of a damage event
+        assert_eq!(
+            BattleEventDetails::new(Some(BattleEventType::Spotted), 0b11),
+            BattleEventDetails::Visibility {
+                is_visible: true,
+                is_direct:  true,
+            }
+        );
+        assert_eq!(
+            BattleEventDetails::new(Some(BattleEventType::ReceivedDamage), 0x0201_0096),
+            BattleEventDetails::Damage {
+                damage:        150,
+                attack_reason: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use wot_types::WotValue;
+
+use crate::packet_parser::prelude::*;
+
+/// `onChatAction`: an action of the chat server (`CHAT_ACTION_DATA` in `alias.xml`). In recent versions,
+/// these are mostly notifications for the player (ex: rewards at the end of the battle) whose content is
+/// in `data`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatAction {
+    pub request_id:      i64,
+    pub action:          u8,
+    pub action_response: u8,
+
+    /// Unix timestamp at which the server handled the action
+    pub time:      f64,
+    pub sent_time: f64,
+
+    pub channel:         i32,
+    pub originator:      i64,
+    pub originator_nick: String,
+    pub group:           u8,
+
+    /// Pickled content of the action. Kept as is because some of these pickles share objects, which
+    /// `serde_pickle` cannot read. See [ChatAction::unpickle_data]
+    pub data:  Vec<u8>,
+    pub flags: u8,
+}
+
+impl ChatAction {
+    /// `None` if the pickle cannot be read
+    pub fn unpickle_data(&self) -> Option<WotValue> {
+        let pickle = crate::utils::unpickle(&self.data).ok()?;
+
+        serde_pickle::from_value(pickle).ok()
+    }
+}
+
+/// `messenger_onActionByServerChat2`: a quick command (ex: "attacking", "help", "defend base") or a ping on
+/// the minimap, sent by a player of the battle. The arguments are the ones of `playermessenger_chat2.def`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatCommand {
+    /// Id of the command. Ids are assigned by the client in the order it registers the commands, which is
+    /// not in the definition files, so they are not mapped to a name. The same command may have another
+    /// id in another version of the game
+    pub action_id:  i16,
+    pub request_id: u16,
+    pub args:       ChatCommandArgs,
+}
+
+/// `GENERIC_MESSENGER_ARGS_chat2` in `alias.xml`. The meaning of each argument depends on the command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatCommandArgs {
+    /// Target of the command when it has one, for ex. the id of a vehicle or the index of a cell of the
+    /// minimap
+    pub int32_arg1: i32,
+    pub int64_arg1: i64,
+    pub float_arg1: f64,
+    pub str_arg1:   String,
+    pub str_arg2:   String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_chat_command() {
+        // `INT16` action id, `UINT16` request id and `GENERIC_MESSENGER_ARGS_chat2`
+        let input = [
+            &300i16.to_le_bytes()[..],
+            &7u16.to_le_bytes(),
+            &9383752i32.to_le_bytes(),
+            &1035966068i64.to_le_bytes(),
+            &1.5f64.to_le_bytes(),
+            &[3, b'a', b'b', b'c', 0],
+        ]
+        .concat();
+
+        let command: ChatCommand = from_slice_prim(&input, [1, 16, 1, 0]).unwrap();
+        assert_eq!((command.action_id, command.request_id), (300, 7));
+        assert_eq!(command.args.int32_arg1, 9383752);
+        assert_eq!(command.args.int64_arg1, 1035966068);
+        assert_eq!(command.args.float_arg1, 1.5);
+        assert_eq!(
+            (command.args.str_arg1.as_str(), command.args.str_arg2.as_str()),
+            ("abc", "")
+        );
+
+        assert!(from_slice_prim::<ChatCommand>(&input[1..], [1, 16, 1, 0]).is_err());
+    }
+
+    #[test]
+    fn parses_chat_action() {
+        // `(None, 1)` pickled with protocol 2
+        let data = [0x80, 0x02, b'N', b'K', 0x01, 0x86, b'.'];
+
+        let mut input = Vec::new();
+        input.extend(3i64.to_le_bytes());
+        input.extend([22, 0]);
+        input.extend(1.5f64.to_le_bytes());
+        input.extend(1.0f64.to_le_bytes());
+        input.extend(0i32.to_le_bytes());
+        input.extend(1035966068i64.to_le_bytes());
+        input.extend([6, b'_', b'D', b'a', b'c', b'i', b't']);
+        input.push(0);
+        input.push(data.len() as u8);
+        input.extend(data);
+        input.push(0);
+
+        let action: ChatAction = from_slice_prim(&input, [1, 16, 1, 0]).unwrap();
+        assert_eq!((action.request_id, action.action), (3, 22));
+        assert_eq!(action.originator, 1035966068);
+        assert_eq!(action.originator_nick, "_Dacit");
+        assert_eq!(
+            action.unpickle_data(),
+            Some(WotValue::Collection(vec![WotValue::None, WotValue::Int(1)]))
+        );
+    }
+}
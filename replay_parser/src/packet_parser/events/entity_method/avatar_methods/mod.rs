@@ -1,11 +1,25 @@
+mod battle_events;
+mod chat_actions;
+mod shot_results;
 pub mod update_arena;
 
+pub use battle_events::{BattleEventDetails, BattleEvents, LegacyBattleEvent, PlayerBattleEvent};
+pub use chat_actions::{ChatAction, ChatCommand, ChatCommandArgs};
+pub use shot_results::{ShotOutcome, ShotResult};
+use battle_events::parse_battle_events;
+use strum::VariantNames;
+
+use super::vehicle_misc_status::VehicleMiscStatus;
 use super::{MethodParser, UpdateArena};
 use crate::{
-    entity_defs::{EntityType, AVATAR_METHODS},
+    entity_defs::EntityType,
     packet_parser::prelude::*,
 };
 
+/// Variants that are not parsed by [VariantDeserializer]
+const UPDATE_ARENA: usize = variant_index(AvatarMethods::VARIANTS, "UpdateArena");
+const ON_BATTLE_EVENTS: usize = variant_index(AvatarMethods::VARIANTS, "OnBattleEvents");
+const UPDATE_VEHICLE_MISC_STATUS: usize = variant_index(AvatarMethods::VARIANTS, "UpdateVehicleMiscStatus");
 
 impl MethodParser for AvatarMethods {
     fn parse(input: &[u8], method_id: usize, context: &Context) -> Result<super::EntityMethod, PacketError>
@@ -13,23 +27,31 @@ impl MethodParser for AvatarMethods {
         Self: Sized,
     {
         let version = context.get_version();
-        let version_str = crate::utils::version_as_string(version);
 
         let not_found_err = |err_msg| PacketError::NotFoundError {
-            err: format!("{err_msg} version={version_str} method_id={method_id}"),
+            err: format!(
+                "{err_msg} version={} method_id={method_id}",
+                crate::utils::version_as_string(version)
+            ),
         };
 
-        let methods = AVATAR_METHODS
-            .get(&version_str)
+        let profile = context.profile();
+        profile
+            .avatar_methods()
             .ok_or_else(|| not_found_err("version not found"))?;
 
-        let discrim = methods
-            .get(method_id)
+        let variant = profile
+            .avatar_method_variant(method_id)
             .ok_or_else(|| not_found_err("method not found"))?;
+        let discrim = AvatarMethods::VARIANTS[variant];
 
-        let method = match *discrim {
-            "UpdateArena" => Ok(AvatarMethods::UpdateArena(UpdateArena::from(input, version)?)),
-            _ => VariantDeserializer::deserialize_variant(discrim, input, &context),
+        let method = match variant {
+            UPDATE_ARENA => Ok(AvatarMethods::UpdateArena(UpdateArena::from(input, version)?)),
+            ON_BATTLE_EVENTS => parse_battle_events(input, version).map(AvatarMethods::OnBattleEvents),
+            UPDATE_VEHICLE_MISC_STATUS => {
+                VehicleMiscStatus::from(input, version).map(AvatarMethods::UpdateVehicleMiscStatus)
+            }
+            _ => AvatarMethods::deserialize_variant_at(variant, input, context),
         }
         .map_err(|err| PacketError::EntityMethodError {
             entity_type: AvatarMethods::entity_type(),
@@ -37,11 +59,20 @@ impl MethodParser for AvatarMethods {
             root_cause:  err.to_string(),
         })?;
 
+        if !method.has_payload() {
+            let entity_type = AvatarMethods::entity_type();
+            if let Some(method) = super::EntityMethod::decode_generic(entity_type, input, context, method_id) {
+                return method;
+            }
+        }
+
         Ok(super::EntityMethod::Avatar(method))
     }
 }
 
-#[derive(Clone, Debug, strum::IntoStaticStr, Serialize, macros::EnumVariantDeserialize)]
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
 /// Different methods that can be called on the Avatar entity. If the enum is a unit variant, it means
 /// that we don't yet support parsing the data of the packet
 pub enum AvatarMethods {
@@ -64,15 +95,19 @@ pub enum AvatarMethods {
     HideAuraWarningHint,
     HideHint,
     LeavingProtectionZone,
-    MessengerOnActionByServerChat2,
+    MessengerOnActionByServerChat2(ChatCommand),
     NotifyCancelled,
     NotifyCannotStartRecovering,
     NotifyClients,
     OnAutoAimVehicleLost,
-    OnBattleEvent,
-    OnBattleEvents,
+
+    OnBattleEvent(LegacyBattleEvent),
+
+    #[variant_de(manual)]
+    OnBattleEvents(BattleEvents),
+
     OnBootcampEvent,
-    OnChatAction,
+    OnChatAction(ChatAction),
     OnCmdResponse,
     OnCmdResponseExt,
     OnCollisionWithVehicle,
@@ -129,7 +164,7 @@ pub enum AvatarMethods {
     ShowHittingArea,
     ShowOtherVehicleDamagedDevices,
     ShowOwnVehicleHitDirection,
-    ShowShotResults,
+    ShowShotResults(Vec<ShotResult>),
     ShowTracer,
     ShowVehicleDamageInfo,
     StopTracer,
@@ -169,7 +204,10 @@ pub enum AvatarMethods {
     UpdateVehicleGunReloadTime,
     UpdateVehicleHealth,
     UpdateVehicleLimits,
-    UpdateVehicleMiscStatus,
+
+    #[variant_de(manual)]
+    UpdateVehicleMiscStatus(VehicleMiscStatus),
+
     UpdateVehicleOptionalDeviceStatus,
     UpdateVehicleQuickShellChanger,
     UpdateVehicleSetting,
@@ -0,0 +1,115 @@
+use wot_types::VehicleHitFlags;
+
+use crate::packet_parser::prelude::*;
+
+/// Result of a shot of the player who recorded the replay on one vehicle. A shot (or the explosion of an HE
+/// shell) can have a result for multiple vehicles
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "u64")]
+pub struct ShotResult {
+    pub vehicle_id: i32,
+    pub flags:      VehicleHitFlags,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ShotOutcome {
+    /// At least one armor plate or module was penetrated
+    Penetrated,
+    Ricochet,
+
+    /// The shell (or its explosion) did not penetrate
+    Blocked,
+
+    /// None of the above. Ex: the vehicle was already dead
+    Other,
+}
+
+impl From<u64> for ShotResult {
+    fn from(packed: u64) -> Self {
+        ShotResult {
+            vehicle_id: (packed & 0xFFFF_FFFF) as i32,
+            flags:      VehicleHitFlags((packed >> 32) as u32),
+        }
+    }
+}
+
+impl ShotResult {
+    pub fn outcome(&self) -> ShotOutcome {
+        if self.flags.intersects(VehicleHitFlags::IS_ANY_PIERCING_MASK) {
+            ShotOutcome::Penetrated
+        } else if self.flags.contains(VehicleHitFlags::RICOCHET) {
+            ShotOutcome::Ricochet
+        } else if self.flags.intersects(VehicleHitFlags::IS_ANY_NOT_PIERCING_MASK) {
+            ShotOutcome::Blocked
+        } else {
+            ShotOutcome::Other
+        }
+    }
+
+    /// The vehicle was destroyed by this shot
+    pub fn is_kill(&self) -> bool {
+        self.flags.contains(VehicleHitFlags::VEHICLE_KILLED)
+    }
+
+    pub fn started_fire(&self) -> bool {
+        self.flags.contains(VehicleHitFlags::FIRE_STARTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_shot_results() {
+        // Results of shots of the example replay: the vehicle ID in the low half and the flags in the high
+        // half
+        let penetration = ShotResult::from(0x0010_0D10_008F_2F47);
+        assert_eq!(penetration.vehicle_id, 9383751);
+        assert_eq!(penetration.flags, VehicleHitFlags(0x0010_0D10));
+        assert_eq!(penetration.outcome(), ShotOutcome::Penetrated);
+        assert!(!penetration.is_kill());
+
+        // An HE shell that did not penetrate, but killed the vehicle with its explosion
+        let kill = ShotResult::from(0x0010_0511_008F_2F45);
+        assert_eq!(kill.vehicle_id, 9383749);
+        assert_eq!(kill.outcome(), ShotOutcome::Blocked);
+        assert!(kill.is_kill());
+        assert!(!kill.started_fire());
+
+        let ricochet =
+            ShotResult::from(((VehicleHitFlags::RICOCHET | VehicleHitFlags::FIRE_STARTED) as u64) << 32 | 1);
+        assert_eq!(ricochet.vehicle_id, 1);
+        assert_eq!(ricochet.outcome(), ShotOutcome::Ricochet);
+        assert!(ricochet.started_fire());
+
+        assert_eq!(
+            ShotResult::from(0x0000_0002_0000_0001).outcome(),
+            ShotOutcome::Other
+        );
+    }
+
+    #[test]
+    fn parses_shot_results() {
+        // `ARRAY<UINT64>`
+        let input = [
+            &[2][..],
+            &0x0010_0D10_008F_2F47u64.to_le_bytes(),
+            &0x0010_0110_008F_2F49u64.to_le_bytes(),
+        ]
+        .concat();
+
+        let results: Vec<ShotResult> = from_slice_prim(&input, [1, 16, 1, 0]).unwrap();
+        let outcomes = results
+            .iter()
+            .map(|result| (result.vehicle_id, result.outcome()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [
+                (9383751, ShotOutcome::Penetrated),
+                (9383753, ShotOutcome::Blocked)
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use serde_pickle::Value as PickleVal;
+
+use super::{parse_value, ArenaUpdateData};
+use crate::packet_parser::prelude::*;
+
+/// A vehicle used a combat equipment. Ex: an airstrike or an artillery barrage in Frontline and Steel Hunter.
+/// Sent as `(shooter_id, equipment_id)`, the layout has not changed between the versions we support
+#[derive(Debug, Clone, Serialize, Version)]
+pub struct CombatEquipmentUsed {
+    pub shooter_id:   i32,
+    pub equipment_id: i32,
+}
+
+pub fn parse_combat_equipment_used(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    let pickle_value = serde_pickle::value_from_slice(
+        arena_data,
+        serde_pickle::DeOptions::new().replace_unresolved_globals(),
+    )?;
+
+    let PickleVal::Tuple(thing) = pickle_value else {
+        return Err(PacketError::PickleError {
+            err: "Expected tuple for combat equipment used".into(),
+        });
+    };
+
+    let combat_equipment_used = CombatEquipmentUsed {
+        shooter_id:   parse_value(0, &thing)?,
+        equipment_id: parse_value(1, &thing)?,
+    };
+
+    Ok(ArenaUpdateData::CombatEquipmentUsed(combat_equipment_used))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_combat_equipment_used() {
+        let value = PickleVal::Tuple(vec![PickleVal::I64(9383752), PickleVal::I64(42)]);
+        let pickle = serde_pickle::value_to_vec(&value, serde_pickle::SerOptions::new()).unwrap();
+
+        let ArenaUpdateData::CombatEquipmentUsed(used) = parse_combat_equipment_used(&pickle).unwrap() else {
+            panic!("not a combat equipment");
+        };
+        assert_eq!((used.shooter_id, used.equipment_id), (9383752, 42));
+
+        let pickle = serde_pickle::value_to_vec(&PickleVal::I64(1), serde_pickle::SerOptions::new()).unwrap();
+        assert!(parse_combat_equipment_used(&pickle).is_err());
+    }
+}
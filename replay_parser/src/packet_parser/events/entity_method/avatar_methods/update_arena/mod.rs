@@ -1,24 +1,40 @@
 mod avatar_ready;
 mod base;
+mod combat_equipment_used;
 mod fog_of_war;
 mod period;
+mod radar_info;
+mod sync_objects;
+mod team_killer;
 mod vehicle_added;
 mod vehicle_descr;
 mod vehicle_killed;
 mod vehicle_list;
+mod vehicle_recovered;
 mod vehicle_statistics;
 mod vehicle_updated;
 
 use avatar_ready::{parse_avatar_ready, AvatarReady};
 use base::{parse_base_captured, parse_base_points, BaseCaptured, BasePoints};
+pub use combat_equipment_used::CombatEquipmentUsed;
+use combat_equipment_used::parse_combat_equipment_used;
 use fog_of_war::{parse_fog_of_war, FogOfWar};
 use nom::number::complete::le_u8;
 use period::{parse_period, Period};
+use radar_info::parse_radar_info;
+pub use radar_info::{RadarInfo, RadarLoot, RadarVehicle};
 use serde_pickle::Value as PickleVal;
+use sync_objects::{parse_sync_objects, parse_sync_objects_diff};
+pub use sync_objects::SyncObjects;
+use team_killer::parse_team_killer;
+pub use team_killer::TeamKiller;
 use vehicle_added::parse_vehicle_added;
 use vehicle_descr::{parse_vehicle_descr, VehicleDescr};
 use vehicle_killed::{parse_vehicle_killed, VehicleKilled};
-use vehicle_list::{parse_vehicle_list, VehicleData};
+use vehicle_list::parse_vehicle_list;
+pub use vehicle_list::VehicleData;
+use vehicle_recovered::parse_vehicle_recovered;
+pub use vehicle_recovered::VehicleRecovered;
 use vehicle_statistics::{parse_statistics, parse_vehicle_statistics, VehicleStatistics};
 use vehicle_updated::parse_vehicle_updated;
 use wot_types::ArenaUpdate;
@@ -46,6 +62,12 @@ pub enum ArenaUpdateData {
     FogOfWar(FogOfWar),
     VehicleAdded(VehicleData),
     VehicleUpdated(VehicleData),
+    TeamKiller(TeamKiller),
+    VehicleRecovered(VehicleRecovered),
+    CombatEquipmentUsed(CombatEquipmentUsed),
+    RadarInfoReceived(RadarInfo),
+    SyncObjects(SyncObjects),
+    SyncObjectsDiff(SyncObjects),
     Unimplemented,
 }
 
@@ -73,6 +95,12 @@ impl UpdateArena {
             FogOfWar => parse_fog_of_war(arena_data)?,
             VehicleAdded => parse_vehicle_added(arena_data)?,
             VehicleUpdated => parse_vehicle_updated(arena_data)?,
+            TeamKiller => parse_team_killer(arena_data)?,
+            VehicleRecovered => parse_vehicle_recovered(arena_data)?,
+            CombatEquipmentUsed => parse_combat_equipment_used(arena_data)?,
+            RadarInfoReceived => parse_radar_info(arena_data)?,
+            SyncObjects => parse_sync_objects(arena_data)?,
+            SyncObjectsDiff => parse_sync_objects_diff(arena_data)?,
             _ => ArenaUpdateData::Unimplemented,
         };
 
@@ -94,6 +122,22 @@ fn parse_value<'de, T: Deserialize<'de>>(index: usize, pickle_val: &[PickleVal])
     serde_pickle::from_value(pickle_val).map_err(|err| PacketError::PickleError { err: err.to_string() })
 }
 
+/// Some updates only send the id of a vehicle. It is either the pickle itself or the first element of a tuple
+fn parse_vehicle_id(arena_data: &[u8]) -> Result<i32, PacketError> {
+    let pickle_value = serde_pickle::value_from_slice(
+        arena_data,
+        serde_pickle::DeOptions::new().replace_unresolved_globals(),
+    )?;
+
+    match pickle_value {
+        PickleVal::I64(vehicle_id) => Ok(vehicle_id as i32),
+        PickleVal::Tuple(thing) => parse_value(0, &thing),
+        _ => Err(PacketError::PickleError {
+            err: "Expected I64 value for vehicle id".into(),
+        }),
+    }
+}
+
 fn parse_truthy_value(index: usize, pickle_val: &[PickleVal]) -> Result<i64, PacketError> {
     let pickle_val = pickle_val.get(index).ok_or_else(|| PacketError::PickleError {
         err: format!("Cannot get index: {index}"),
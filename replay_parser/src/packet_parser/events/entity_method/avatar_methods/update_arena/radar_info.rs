@@ -0,0 +1,159 @@
+use serde_pickle::Value as PickleVal;
+
+use super::{parse_value, ArenaUpdateData};
+use crate::packet_parser::prelude::*;
+
+/// Vehicles (and loot) revealed by a radar (Steel Hunter)
+#[derive(Debug, Clone, Serialize, Version)]
+pub struct RadarInfo {
+    /// Time the marks stay on the minimap
+    pub retention_time: Option<f32>,
+
+    pub vehicles: Vec<RadarVehicle>,
+
+    /// Loot boxes revealed by the radar. Sent as a second list when loot was added to the radar
+    #[version([1, 10, 0, 0])]
+    pub loots: Option<Vec<RadarLoot>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RadarVehicle {
+    pub vehicle_id: i32,
+
+    /// Position on the map (x, z)
+    pub position: (f32, f32),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RadarLoot {
+    pub loot_id: i32,
+
+    /// Position on the map (x, z)
+    pub position: (f32, f32),
+}
+
+/// The data is a compressed pickle: a tuple with the time the marks stay on the minimap, the list of vehicles
+/// `(vehicle_id, (x, z), ...)` and, in newer versions, the list of loot `(loot_id, (x, z), ...)`. Elements
+/// after the position of an entry are ignored
+pub fn parse_radar_info(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    let decompressed = utils::decompress_vec(arena_data, |err| PacketError::ConversionError {
+        err: err.to_string(),
+    })?;
+    let pickle_value = serde_pickle::value_from_slice(
+        &decompressed,
+        serde_pickle::DeOptions::new().replace_unresolved_globals(),
+    )?;
+
+    let PickleVal::Tuple(thing) = pickle_value else {
+        return Err(PacketError::PickleError {
+            err: "Expected tuple for radar info".into(),
+        });
+    };
+
+    let mut retention_time = None;
+    let mut lists = Vec::new();
+    for value in thing {
+        match value {
+            PickleVal::List(entries) => lists.push(entries),
+            PickleVal::F64(time) if retention_time.is_none() => retention_time = Some(time as f32),
+            other => {
+                return Err(PacketError::PickleError {
+                    err: format!("Unexpected element in radar info: {other:?}"),
+                })
+            }
+        }
+    }
+
+    let mut lists = lists.into_iter();
+    let vehicles = lists
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| {
+            let (vehicle_id, position) = parse_entry(entry)?;
+            Ok(RadarVehicle { vehicle_id, position })
+        })
+        .collect::<Result<_, PacketError>>()?;
+    let loots = lists
+        .next()
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|entry| {
+                    let (loot_id, position) = parse_entry(entry)?;
+                    Ok(RadarLoot { loot_id, position })
+                })
+                .collect::<Result<_, PacketError>>()
+        })
+        .transpose()?;
+
+    Ok(ArenaUpdateData::RadarInfoReceived(RadarInfo {
+        retention_time,
+        vehicles,
+        loots,
+    }))
+}
+
+/// `(id, (x, z), ...)`
+fn parse_entry(entry: &PickleVal) -> Result<(i32, (f32, f32)), PacketError> {
+    let (PickleVal::Tuple(entry) | PickleVal::List(entry)) = entry else {
+        return Err(PacketError::PickleError {
+            err: format!("Expected tuple for radar entry, got {entry:?}"),
+        });
+    };
+
+    Ok((parse_value(0, entry)?, parse_value(1, entry)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use miniz_oxide::deflate::compress_to_vec_zlib;
+
+    use super::*;
+
+    fn parse(value: PickleVal) -> Result<RadarInfo, PacketError> {
+        let pickle = serde_pickle::value_to_vec(&value, serde_pickle::SerOptions::new()).unwrap();
+
+        match parse_radar_info(&compress_to_vec_zlib(&pickle, 6))? {
+            ArenaUpdateData::RadarInfoReceived(radar_info) => Ok(radar_info),
+            other => panic!("unexpected update: {other:?}"),
+        }
+    }
+
+    fn entry(id: i64, x: f64, z: f64) -> PickleVal {
+        PickleVal::Tuple(vec![
+            PickleVal::I64(id),
+            PickleVal::Tuple(vec![PickleVal::F64(x), PickleVal::F64(z)]),
+        ])
+    }
+
+    #[test]
+    fn parses_radar_info() {
+        let radar_info = parse(PickleVal::Tuple(vec![
+            PickleVal::F64(5.0),
+            PickleVal::List(vec![entry(9383752, 10.0, -20.0)]),
+            PickleVal::List(vec![entry(3, 1.0, 2.0)]),
+        ]))
+        .unwrap();
+
+        assert_eq!(radar_info.retention_time, Some(5.0));
+        assert_eq!(radar_info.vehicles[0].vehicle_id, 9383752);
+        assert_eq!(radar_info.vehicles[0].position, (10.0, -20.0));
+        assert_eq!(radar_info.loots.unwrap()[0].loot_id, 3);
+
+        // Older versions do not send the loot
+        let radar_info = parse(PickleVal::Tuple(vec![
+            PickleVal::F64(5.0),
+            PickleVal::List(vec![entry(9383752, 10.0, -20.0)]),
+        ]))
+        .unwrap();
+        assert!(radar_info.loots.is_none());
+
+        // A malformed entry is an error instead of being skipped
+        let result = parse(PickleVal::Tuple(vec![
+            PickleVal::F64(5.0),
+            PickleVal::List(vec![PickleVal::I64(9383752)]),
+        ]));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use wot_types::WotValue;
+
+use super::ArenaUpdateData;
+use crate::packet_parser::prelude::*;
+
+/// Synced data containers of the arena, keyed by the id of the object that owns them. Game modes added in
+/// later patches (ex: Comp7) keep their state (roles, points, timers etc.) in these containers.
+///
+/// `SyncObjects` sends the complete data of the objects while `SyncObjectsDiff` only sends the values that
+/// changed. See [SyncedArenaData](crate::SyncedArenaData) to get the state of the containers over the course
+/// of the replay
+#[derive(Debug, Clone, Serialize, Version)]
+pub struct SyncObjects {
+    pub objects: HashMap<i64, WotValue>,
+}
+
+/// The data is a pickled dict of `object_id -> data`
+pub fn parse_sync_objects(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    Ok(ArenaUpdateData::SyncObjects(parse_objects(arena_data)?))
+}
+
+/// Same format as `SyncObjects`, but `data` only contains what changed
+pub fn parse_sync_objects_diff(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    Ok(ArenaUpdateData::SyncObjectsDiff(parse_objects(arena_data)?))
+}
+
+fn parse_objects(arena_data: &[u8]) -> Result<SyncObjects, PacketError> {
+    let pickle_value = serde_pickle::value_from_slice(
+        arena_data,
+        serde_pickle::DeOptions::new().replace_unresolved_globals(),
+    )?;
+    let objects = serde_pickle::from_value(pickle_value)
+        .map_err(|err| PacketError::PickleError { err: err.to_string() })?;
+
+    Ok(SyncObjects { objects })
+}
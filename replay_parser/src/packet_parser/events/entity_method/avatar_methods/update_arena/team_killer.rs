@@ -0,0 +1,35 @@
+use super::{parse_vehicle_id, ArenaUpdateData};
+use crate::packet_parser::prelude::*;
+
+/// A vehicle is marked as a team killer (its name turns blue). Sent as the pickled id of the vehicle, the
+/// layout has not changed between the versions we support
+#[derive(Debug, Clone, Serialize, Version)]
+pub struct TeamKiller {
+    pub vehicle_id: i32,
+}
+
+pub fn parse_team_killer(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    let team_killer = TeamKiller {
+        vehicle_id: parse_vehicle_id(arena_data)?,
+    };
+
+    Ok(ArenaUpdateData::TeamKiller(team_killer))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_pickle::Value as PickleVal;
+
+    use super::*;
+
+    #[test]
+    fn parses_team_killer() {
+        let pickle =
+            serde_pickle::value_to_vec(&PickleVal::I64(9383752), serde_pickle::SerOptions::new()).unwrap();
+
+        let ArenaUpdateData::TeamKiller(team_killer) = parse_team_killer(&pickle).unwrap() else {
+            panic!("not a team killer");
+        };
+        assert_eq!(team_killer.vehicle_id, 9383752);
+    }
+}
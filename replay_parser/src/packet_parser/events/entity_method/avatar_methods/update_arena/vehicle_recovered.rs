@@ -0,0 +1,40 @@
+use super::{parse_vehicle_id, ArenaUpdateData};
+use crate::packet_parser::prelude::*;
+
+/// A destroyed vehicle is back in the battle (ex: respawn in Frontline). The id of the vehicle is sent alone
+/// or as the first element of a tuple, the other elements are not decoded
+#[derive(Debug, Clone, Serialize, Version)]
+pub struct VehicleRecovered {
+    pub vehicle_id: i32,
+}
+
+pub fn parse_vehicle_recovered(arena_data: &[u8]) -> Result<ArenaUpdateData, PacketError> {
+    let vehicle_recovered = VehicleRecovered {
+        vehicle_id: parse_vehicle_id(arena_data)?,
+    };
+
+    Ok(ArenaUpdateData::VehicleRecovered(vehicle_recovered))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_pickle::Value as PickleVal;
+
+    use super::*;
+
+    #[test]
+    fn parses_vehicle_recovered() {
+        for value in [
+            PickleVal::I64(9383752),
+            PickleVal::Tuple(vec![PickleVal::I64(9383752), PickleVal::Bool(true)]),
+        ] {
+            let pickle = serde_pickle::value_to_vec(&value, serde_pickle::SerOptions::new()).unwrap();
+
+            let ArenaUpdateData::VehicleRecovered(recovered) = parse_vehicle_recovered(&pickle).unwrap()
+            else {
+                panic!("not a recovered vehicle");
+            };
+            assert_eq!(recovered.vehicle_id, 9383752);
+        }
+    }
+}
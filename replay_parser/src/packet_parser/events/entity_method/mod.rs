@@ -1,12 +1,16 @@
+pub(crate) mod arena_methods;
 pub(crate) mod avatar_methods;
 pub(crate) mod vehicle_methods;
-// mod vehicle_misc_status;
+pub(crate) mod vehicle_misc_status;
 
 pub use avatar_methods::update_arena::*;
 use nom::number::complete::le_i32;
 pub use vehicle_methods::*;
 
+use self::arena_methods::{DestructibleEntityMethods, SectorMethods};
 use self::avatar_methods::AvatarMethods;
+use crate::packet_parser::generic::decode_args;
+use crate::wot_types::WotValue;
 use crate::{entity_defs::EntityType, packet_parser::prelude::*};
 
 /// Represents all packets of type `0x08`. `0x08` packet seems to describe a method call on an entity.
@@ -29,17 +33,7 @@ impl PacketParser for EntityMethodEvent {
         let (method_data, _size) = le_i32(remaining)?;
 
         let entity_type = context.find_entity_type(entity_id)?;
-        let method_id = method_id as usize;
-
-        use EntityType::*;
-        let method = match entity_type {
-            Vehicle => VehicleMethods::parse(method_data, method_id, context),
-            Avatar => AvatarMethods::parse(method_data, method_id, context),
-            _ => Ok(EntityMethod::NotImplemented {
-                entity_type,
-                method_id,
-            }),
-        }?;
+        let method = EntityMethod::new(entity_type, method_data, context, method_id as usize)?;
 
         Ok(BattleEvent::EntityMethod(EntityMethodEvent { entity_id, method }))
     }
@@ -68,6 +62,16 @@ pub enum EntityMethod {
     },
     Vehicle(VehicleMethods),
     Avatar(AvatarMethods),
+    DestructibleEntity(DestructibleEntityMethods),
+    Sector(SectorMethods),
+
+    /// A method we do not have a parser for, decoded from its signature in the definition files. Not
+    /// produced for the versions we do not have the definition files of
+    Generic {
+        entity_type: EntityType,
+        name:        &'static str,
+        args:        Vec<WotValue>,
+    },
 }
 
 impl EntityMethod {
@@ -79,12 +83,36 @@ impl EntityMethod {
         match entity_type {
             Vehicle => VehicleMethods::parse(input, method_id, context),
             Avatar => AvatarMethods::parse(input, method_id, context),
-            _ => Ok(EntityMethod::NotImplemented {
-                entity_type,
-                method_id,
-            }),
+            _ => arena_methods::parse_arena_method(entity_type, input, method_id, context)
+                .or_else(|| Self::decode_generic(entity_type, input, context, method_id))
+                .unwrap_or(Ok(EntityMethod::NotImplemented {
+                    entity_type,
+                    method_id,
+                })),
         }
     }
+
+    /// Decode the method with the signatures of the version profile. `None` if the signatures of the
+    /// entity are not available
+    pub(crate) fn decode_generic(
+        entity_type: EntityType, input: &[u8], context: &Context, method_id: usize,
+    ) -> Option<Result<Self, PacketError>> {
+        let (name, params) = context.profile().signatures(entity_type)?.methods.get(method_id)?;
+
+        let method = decode_args(params, input)
+            .map(|args| EntityMethod::Generic {
+                entity_type,
+                name,
+                args,
+            })
+            .map_err(|err| PacketError::EntityMethodError {
+                entity_type,
+                method:      name,
+                root_cause:  err.to_string(),
+            });
+
+        Some(method)
+    }
 }
 
 
@@ -1,8 +1,7 @@
+use wot_types::{AttackReason, TankPart, VehicleHitEffect};
+
 use super::MethodParser;
-use crate::{
-    entity_defs::{EntityType, VEHICLE_METHODS},
-    packet_parser::prelude::*,
-};
+use crate::{entity_defs::EntityType, packet_parser::prelude::*};
 
 #[derive(Debug, Clone, Serialize, macros::EnumVariantDeserialize)]
 #[non_exhaustive]
@@ -46,14 +45,17 @@ impl MethodParser for VehicleMethods {
         Self: Sized,
     {
         let version = context.get_version();
-        let version_str = crate::utils::version_as_string(version);
 
         let not_found_err = |err_msg| PacketError::NotFoundError {
-            err: format!("{err_msg} version={version_str} method_id={method_id}"),
+            err: format!(
+                "{err_msg} version={} method_id={method_id}",
+                crate::utils::version_as_string(version)
+            ),
         };
 
-        let methods = VEHICLE_METHODS
-            .get(&version_str)
+        let methods = context
+            .profile()
+            .vehicle_methods()
             .ok_or_else(|| not_found_err("version not found"))?;
 
         let discrim = methods
@@ -61,13 +63,20 @@ impl MethodParser for VehicleMethods {
             .ok_or_else(|| not_found_err("method not found"))?;
 
 
-        let method = VariantDeserializer::deserialize_variant(discrim, input, &context).map_err(|err| {
-            PacketError::EntityMethodError {
+        let method: VehicleMethods = VariantDeserializer::deserialize_variant(discrim, input, &context)
+            .map_err(|err| PacketError::EntityMethodError {
                 entity_type: VehicleMethods::entity_type(),
                 method:      discrim,
                 root_cause:  err.to_string(),
+            })?;
+
+        if !method.has_payload() {
+            let entity_type = VehicleMethods::entity_type();
+            if let Some(method) = super::EntityMethod::decode_generic(entity_type, input, context, method_id)
+            {
+                return method;
             }
-        })?;
+        }
 
         Ok(super::EntityMethod::Vehicle(method))
     }
@@ -83,7 +92,7 @@ impl VehicleMethods {
 #[derive(Serialize, Deserialize, Debug, Clone, Version)]
 pub struct ShowDamageFromShot {
     pub entity_id:     u32,
-    pub points:        Vec<u64>,
+    pub points:        Vec<HitPoint>,
     pub effects_index: u8,
     pub damage_factor: u8,
 
@@ -91,6 +100,61 @@ pub struct ShowDamageFromShot {
     pub last_material_shield: Option<bool>,
 }
 
+/// Where a shell hit a vehicle, decoded from a packed `UINT64` of the `points` of `ShowDamageFromShot` (see
+/// `DamageFromShotDecoder` in the game's scripts). A shot has multiple hit points if the shell ricocheted or
+/// went through multiple plates.
+///
+/// From the lowest byte: the hit effect code, the index of the component, the `x`, `y` and `z` of the start
+/// of the hit segment, then the `x`, `y` and `z` of its end. The first two bytes were checked against the
+/// example replay: the first one goes up to `6` like the effect codes and the second one up to `3` like the
+/// parts of a tank, and penetrating shots of the recording player have a piercing effect. Each coordinate is
+/// quantized across the bounding box of the component, so the client needs the model of the vehicle to get
+/// the position of the hit
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "u64")]
+pub struct HitPoint {
+    /// `None` if the effect code is not known to this crate. See `effect_code` in that case
+    pub effect:      Option<VehicleHitEffect>,
+    pub effect_code: u8,
+
+    /// `None` for the parts of wheeled vehicles that are not in `TankPart`. See `component_index` in that
+    /// case
+    pub component:       Option<TankPart>,
+    pub component_index: u8,
+
+    /// Start and end of the hit segment, as `[x, y, z]`. Each coordinate goes from `0` to `255` across the
+    /// bounding box of the component
+    pub start: [u8; 3],
+    pub end:   [u8; 3],
+}
+
+impl From<u64> for HitPoint {
+    fn from(packed: u64) -> Self {
+        let [effect_code, component_index, start @ .., end_x, end_y, end_z] = packed.to_le_bytes();
+
+        HitPoint {
+            effect: VehicleHitEffect::try_from(effect_code as i32).ok(),
+            effect_code,
+            component: TankPart::try_from(component_index as i32).ok(),
+            component_index,
+            start,
+            end: [end_x, end_y, end_z],
+        }
+    }
+}
+
+impl HitPoint {
+    /// The shell went through the armor at this point
+    pub fn is_penetration(&self) -> bool {
+        use VehicleHitEffect::*;
+
+        matches!(
+            self.effect,
+            Some(ArmorPiercedNoDamage | ArmorPierced | CriticalHit | ArmorPiercedDeviceDamaged)
+        )
+    }
+}
+
 /// A vehicle fires a shot
 #[derive(Serialize, Deserialize, Debug, Clone, Version)]
 pub struct ShowShooting {
@@ -112,9 +176,17 @@ pub struct OnHealthChanged {
     #[event_debug(as_player)]
     pub attacker_id: i32,
 
+    /// Index of the reason in `ATTACK_REASONS`. See [OnHealthChanged::reason]
     pub attack_reason: u8,
 }
 
+impl OnHealthChanged {
+    /// `None` if the reason was added in a version we do not support yet
+    pub fn reason(&self) -> Option<AttackReason> {
+        AttackReason::try_from(self.attack_reason as i32).ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, EventPrinter, Version)]
 pub struct ShowDamageFromExplosion {
     #[event_debug(as_player)]
@@ -156,3 +228,28 @@ pub struct OnStaticCollision {
     #[version([0, 9, 23, 0])]
     pub destr_max_health: Option<u16>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_hit_point() {
+        // A penetration (effect 4) of the turret (component 2)
+        let point = HitPoint::from(0x0A14_1E28_3C50_0204);
+
+        assert_eq!(point.effect_code, 4);
+        assert_eq!(point.effect, Some(VehicleHitEffect::ArmorPierced));
+        assert!(point.is_penetration());
+        assert_eq!(point.component_index, 2);
+        assert_eq!(point.component, Some(TankPart::Turret));
+        assert_eq!(point.start, [0x50, 0x3C, 0x28]);
+        assert_eq!(point.end, [0x1E, 0x14, 0x0A]);
+
+        // Effect and component that this crate does not know
+        let point = HitPoint::from(0x0000_0000_0000_0907);
+        assert_eq!((point.effect, point.effect_code), (None, 7));
+        assert_eq!((point.component, point.component_index), (None, 9));
+        assert!(!point.is_penetration());
+    }
+}
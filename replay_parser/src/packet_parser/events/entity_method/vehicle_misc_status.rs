@@ -1,28 +1,120 @@
 use crate::packet_parser::prelude::*;
 
-#[derive(Debug, Clone)]
+/// Status of the player vehicle (or the observed vehicle). Ex: the sixth sense lit up, the vehicle is about
+/// to drown, the siege mode is being engaged, etc.
+#[derive(Debug, Clone, Serialize)]
 pub struct VehicleMiscStatus {
-    pub avatar_id:     i32,
+    pub vehicle_id:    i32,
     pub status_detail: VehicleMiscStatusDetail,
 }
-#[derive(Debug, Clone)]
+
+#[derive(Debug, Clone, Serialize)]
 pub enum VehicleMiscStatusDetail {
+    OtherVehicleDamagedDevicesVisible(bool),
+
+    /// Sixth sense. `true` when the vehicle is spotted
     IsObservedByEnemy(bool),
+
+    LoaderIntuitionWasUsed,
+    VehicleIsOverturned(DestroyTimer),
+    VehicleDrownWarning(DestroyTimer),
+    InDeathZone(DestroyTimer),
+
+    /// The horn of the vehicle was muted for spamming it. Horns were removed in 0.9.20
+    HornBanned(bool),
+
+    DestroyedDeviceIsRepairing {
+        device_index: u8,
+
+        /// Percentage of the repair that is done
+        progress:  u8,
+        time_left: Option<f32>,
+    },
+
+    SiegeModeStateChanged {
+        state:     i32,
+        time_left: Option<f32>,
+    },
+
+    BurnoutWarning(i32),
+    BurnoutUnavailableDueToBrokenEngine(bool),
+
+    DualgunChargerState {
+        state:     i32,
+        time_left: Option<f32>,
+    },
+
+    /// A code that is not known for the version of the replay. Codes 8, 12 and 13 are always unknown: we
+    /// have not found what they are used for
+    Unknown {
+        status_code: u8,
+        int_arg:     i32,
+        float_args:  Vec<f32>,
+    },
+}
+
+/// Timer until the vehicle is destroyed (overturned, drowning or in a death zone)
+#[derive(Debug, Clone, Serialize)]
+pub struct DestroyTimer {
+    /// `0` when the timer is stopped
+    pub level:     i32,
+    pub time_left: Option<f32>,
 }
+
 impl VehicleMiscStatus {
     pub fn from(data: &[u8], version: [u16; 4]) -> Result<Self, PacketError> {
         let misc_status: UpdateVehicleMiscStatus = from_slice(data, version)?;
 
+        let UpdateVehicleMiscStatus {
+            avatar_id,
+            status_code,
+            status_data,
+            other_args,
+        } = misc_status;
+        let time_left = other_args.first().copied();
+        let destroy_timer = || DestroyTimer {
+            level: status_data,
+            time_left,
+        };
+
         use VehicleMiscStatusCode as Code;
         use VehicleMiscStatusDetail::*;
-        let status_detail = {
-            match misc_status.status_code {
-                _ => IsObservedByEnemy(!(misc_status.status_data == 0)),
+        let status_detail = match VehicleMiscStatusCode::from_code(status_code, version) {
+            Some(Code::OtherVehicleDamagedDevicesVisible) => {
+                OtherVehicleDamagedDevicesVisible(status_data != 0)
             }
+            Some(Code::IsObservedByEnemy) => IsObservedByEnemy(status_data != 0),
+            Some(Code::LoaderIntuitionWasUsed) => LoaderIntuitionWasUsed,
+            Some(Code::VehicleIsOverturned) => VehicleIsOverturned(destroy_timer()),
+            Some(Code::VehicleDrownWarning) => VehicleDrownWarning(destroy_timer()),
+            Some(Code::InDeathZone) => InDeathZone(destroy_timer()),
+            Some(Code::HornBanned) => HornBanned(status_data != 0),
+            Some(Code::DestroyedDeviceIsRepairing) => DestroyedDeviceIsRepairing {
+                device_index: (status_data & 0xFF) as u8,
+                progress: ((status_data >> 8) & 0xFF) as u8,
+                time_left,
+            },
+            Some(Code::SiegeModeStateChanged) => SiegeModeStateChanged {
+                state: status_data,
+                time_left,
+            },
+            Some(Code::BurnoutWarning) => BurnoutWarning(status_data),
+            Some(Code::BurnoutUnavailableDueToBrokenEngine) => {
+                BurnoutUnavailableDueToBrokenEngine(status_data != 0)
+            }
+            Some(Code::DualgunChargerState) => DualgunChargerState {
+                state: status_data,
+                time_left,
+            },
+            None => Unknown {
+                status_code,
+                int_arg: status_data,
+                float_args: other_args,
+            },
         };
 
         Ok(VehicleMiscStatus {
-            avatar_id: misc_status.avatar_id,
+            vehicle_id: avatar_id,
             status_detail,
         })
     }
@@ -37,36 +129,135 @@ pub struct UpdateVehicleMiscStatus {
     pub other_args:  Vec<f32>,
 }
 
+// Took from https://github.com/IzeBerg/wot-src/blob/EU/sources/res/scripts/common/constants.py
+// class VEHICLE_MISC_STATUS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum VehicleMiscStatusCode {
+    OtherVehicleDamagedDevicesVisible,
+    IsObservedByEnemy,
+    LoaderIntuitionWasUsed,
+    VehicleIsOverturned,
+    VehicleDrownWarning,
+    InDeathZone,
+    HornBanned,
+    DestroyedDeviceIsRepairing,
+    SiegeModeStateChanged,
+    BurnoutWarning,
+    BurnoutUnavailableDueToBrokenEngine,
+    DualgunChargerState,
+}
 
-enum VehicleMiscStatusCode {
-    OtherVehicleDamagedDevicesVisible = 0,
-    IsObservedByEnemy          = 1,
-    LoaderIntuitionWasUsed     = 2,
-    VehicleIsOverturned        = 3,
-    VehicleDrownWarning        = 4,
-    InDeathZone                = 5,
-    DestroyedDeviceIsRepairing = 7,
-    SiegeModeStateChanged      = 9,
-    BurnoutWarning             = 10,
-    BurnoutUnavailableDueToBrokenEngine = 11,
-    DualgunChargerState        = 14,
+/// `(first version, first version without it, code, status)`. Codes were added with the mechanics they
+/// describe (siege mode, wheeled vehicles, dual-gun vehicles) and removed with them (horns, whose
+/// `receiveHorn` method is gone in 0.9.20), so a code is only known for the versions that have the mechanic
+const MISC_STATUS_CODES: &[([u16; 4], [u16; 4], u8, VehicleMiscStatusCode)] = {
+    use VehicleMiscStatusCode::*;
+
+    const FIRST: [u16; 4] = [0, 0, 0, 0];
+    const LATEST: [u16; 4] = [u16::MAX; 4];
+
+    &[
+        (FIRST, LATEST, 0, OtherVehicleDamagedDevicesVisible),
+        (FIRST, LATEST, 1, IsObservedByEnemy),
+        (FIRST, LATEST, 2, LoaderIntuitionWasUsed),
+        (FIRST, LATEST, 3, VehicleIsOverturned),
+        (FIRST, LATEST, 4, VehicleDrownWarning),
+        (FIRST, LATEST, 5, InDeathZone),
+        (FIRST, [0, 9, 20, 0], 6, HornBanned),
+        (FIRST, LATEST, 7, DestroyedDeviceIsRepairing),
+        ([0, 9, 17, 0], LATEST, 9, SiegeModeStateChanged),
+        ([1, 4, 0, 0], LATEST, 10, BurnoutWarning),
+        ([1, 4, 0, 0], LATEST, 11, BurnoutUnavailableDueToBrokenEngine),
+        ([1, 7, 1, 0], LATEST, 14, DualgunChargerState),
+    ]
+};
+
+impl VehicleMiscStatusCode {
+    /// Status of `code` in `version`. `None` if the code is not known for that version
+    pub fn from_code(code: u8, version: [u16; 4]) -> Option<Self> {
+        MISC_STATUS_CODES
+            .iter()
+            .find(|(since, until, status_code, _)| {
+                *status_code == code && version >= *since && version < *until
+            })
+            .map(|(_, _, _, status)| *status)
+    }
 }
 
-// trait DecodeVehicleMiscStatus {
-//     fn decode(misc_status: UpdateVehicleMiscStatus) -> Result<Self> where Self: std::marker::Sized;
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(status_code: u8, status_data: i32, version: [u16; 4]) -> VehicleMiscStatusDetail {
+        let mut input = Vec::new();
+        input.extend(1234i32.to_le_bytes());
+        input.push(status_code);
+        input.extend(status_data.to_le_bytes());
+        input.push(1);
+        input.extend(2.5f32.to_le_bytes());
+
+        let status = VehicleMiscStatus::from(&input, version).unwrap();
+        assert_eq!(status.vehicle_id, 1234);
+
+        status.status_detail
+    }
+
+    #[test]
+    fn decodes_codes_in_their_version_range() {
+        use VehicleMiscStatusDetail::*;
+
+        // Known in every version
+        let InDeathZone(timer) = decode(5, 1, [0, 9, 13, 0]) else {
+            panic!()
+        };
+        assert_eq!((timer.level, timer.time_left), (1, Some(2.5)));
+        assert!(matches!(decode(5, 1, [1, 16, 1, 0]), InDeathZone(_)));
+
+        // Horns were removed in 0.9.20
+        assert!(matches!(decode(6, 1, [0, 9, 19, 0]), HornBanned(true)));
+        assert!(matches!(
+            decode(6, 1, [0, 9, 20, 0]),
+            Unknown { status_code: 6, .. }
+        ));
 
-// struct ObservedByEnemy(bool);
+        // Siege mode since 0.9.17
+        assert!(matches!(
+            decode(9, 1, [0, 9, 16, 0]),
+            Unknown { status_code: 9, .. }
+        ));
+        assert!(matches!(
+            decode(9, 1, [0, 9, 17, 0]),
+            SiegeModeStateChanged { state: 1, .. }
+        ));
 
-// impl DecodeVehicleMiscStatus for ObservedByEnemy {
-//     fn decode(misc_status: UpdateVehicleMiscStatus) -> Result<Self> {
-//         assert!(misc_status.status_code == VehicleMiscStatusCode::IsObservedByEnemy as u8);
+        // Wheeled vehicles since 1.4
+        assert!(matches!(
+            decode(10, 3, [1, 3, 0, 0]),
+            Unknown { status_code: 10, .. }
+        ));
+        assert!(matches!(decode(10, 3, [1, 4, 0, 0]), BurnoutWarning(3)));
+        assert!(matches!(
+            decode(11, 1, [1, 4, 0, 0]),
+            BurnoutUnavailableDueToBrokenEngine(true)
+        ));
 
-//         if misc_status.status_data == 0 {
-//             Ok(ObservedByEnemy(true))
-//         } else {
-//             Err(crate::Error::Other("Unknown status data for observed by enemy".into()))
-//         }
+        // Dual-gun vehicles since 1.7.1
+        assert!(matches!(
+            decode(14, 2, [1, 7, 0, 0]),
+            Unknown { status_code: 14, .. }
+        ));
+        assert!(matches!(
+            decode(14, 2, [1, 7, 1, 0]),
+            DualgunChargerState { state: 2, .. }
+        ));
 
-//     }
-// }
+        // Never known
+        let Unknown {
+            int_arg, float_args, ..
+        } = decode(12, 7, [1, 16, 1, 0])
+        else {
+            panic!()
+        };
+        assert_eq!((int_arg, float_args), (7, vec![2.5]));
+    }
+}
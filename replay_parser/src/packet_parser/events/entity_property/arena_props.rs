@@ -0,0 +1,329 @@
+use strum::VariantNames;
+
+use super::EntityProperty;
+use crate::{entity_defs::EntityType, packet_parser::prelude::*};
+
+/// Parse a property of an arena entity with its typed enum. `None` if we do not have the property tables of
+/// the entity or an enum variant for the property. Like the other entities, a property we do not know the
+/// format of is decoded with its signature if it is available
+pub(super) fn parse_arena_property(
+    entity_type: EntityType, input: &[u8], property_id: usize, context: &Context,
+) -> Option<Result<EntityProperty, PacketError>> {
+    let name = *context
+        .profile()
+        .arena_entity_props(entity_type)?
+        .get(property_id)?;
+
+    let property = match entity_type {
+        EntityType::AreaDestructibles => parse_variant::<AreaDestructiblesProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::AreaDestructibles(property), has_payload)),
+        EntityType::DestructibleEntity => {
+            parse_variant::<DestructibleEntityProperties>(name, input, context)?
+                .map(|(property, has_payload)| (EntityProperty::DestructibleEntity(property), has_payload))
+        }
+        EntityType::SectorBase => parse_variant::<SectorBaseProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::SectorBase(property), has_payload)),
+        EntityType::Sector => parse_variant::<SectorProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::Sector(property), has_payload)),
+        EntityType::ProtectionZone => parse_variant::<ProtectionZoneProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::ProtectionZone(property), has_payload)),
+        EntityType::BasicMine => parse_variant::<BasicMineProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::BasicMine(property), has_payload)),
+        EntityType::StepRepairPoint => parse_variant::<StepRepairPointProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::StepRepairPoint(property), has_payload)),
+        EntityType::EventPointsPickup => parse_variant::<EventPointsPickupProperties>(name, input, context)?
+            .map(|(property, has_payload)| (EntityProperty::EventPointsPickup(property), has_payload)),
+        _ => return None,
+    };
+
+    let generic = || EntityProperty::decode_generic(entity_type, input, context, property_id);
+    match property {
+        Ok((property, true)) => Some(Ok(property)),
+        Ok((property, false)) => Some(generic().unwrap_or(Ok(property))),
+        Err(err) => Some(
+            generic()
+                .unwrap_or_else(|| Err(PacketError::entity_prop_err(entity_type, name, err.to_string()))),
+        ),
+    }
+}
+
+fn parse_variant<T: VariantDeserializer + VariantNames>(
+    name: &'static str, input: &[u8], context: &Context,
+) -> Option<Result<(T, bool), PacketError>> {
+    if !T::VARIANTS.contains(&name) {
+        return None;
+    }
+
+    let property = T::deserialize_variant(name, input, context).map(|property| {
+        let has_payload = property.has_payload();
+        (property, has_payload)
+    });
+
+    Some(property)
+}
+
+/// Properties of the entity that keeps the state of the destructible objects of the map (fences, houses,
+/// trees etc.). Every element starts with the chunk of the map the object is in and the index of the object
+/// in that chunk. The meaning of the other bytes comes from how they vary in replays and is not confirmed
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum AreaDestructiblesProperties {
+    DestroyedModules(Vec<DestroyedModule>),
+    DestroyedFragiles(Vec<DestroyedFragile>),
+    FallenColumns(Vec<FallenColumn>),
+    FallenTrees(Vec<FallenTree>),
+}
+
+/// A part of a bigger object (ex: a wall of a house) that was knocked down
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DestroyedModule {
+    pub chunk_id:           u8,
+    pub destructible_index: u8,
+
+    /// Which part of the object was destroyed. An object can have several destroyed modules with the same
+    /// `destructible_index`
+    pub module_index: u8,
+}
+
+/// A small object (ex: a fence or a lamp post) that was knocked down
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DestroyedFragile {
+    pub chunk_id:           u8,
+    pub destructible_index: u8,
+
+    /// `false` for nearly every fragile. Probably set when the fragile was destroyed by a shot instead of
+    /// a vehicle running into it
+    pub is_shot_damage: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FallenColumn {
+    pub chunk_id:           u8,
+    pub destructible_index: u8,
+
+    /// Direction of the fall, packed in a byte (`0` to `255` for a full turn)
+    pub fall_yaw: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FallenTree {
+    pub chunk_id:           u8,
+    pub destructible_index: u8,
+
+    /// How far the tree leans once fallen. Stays within a narrow range in replays
+    pub fall_pitch: u8,
+
+    /// Direction of the fall, packed in a byte (`0` to `255` for a full turn)
+    pub fall_yaw: u8,
+
+    pub fall_speed: u8,
+}
+
+/// Properties of an object that can be shot at and destroyed, like the bunkers of Frontline
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum DestructibleEntityProperties {
+    IsActive(bool),
+    Team(u8),
+    DestructibleEntityID(u8),
+    IsDestructibleDestroyed(bool),
+    TypeID(u8),
+    Health(f32),
+    MaxHealth(f32),
+    LinkedMapActivities(String),
+
+    /// Packed positions of the shells that hit the object
+    DamageStickers(Vec<u64>),
+}
+
+/// Properties of a capturable base of a Frontline sector
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum SectorBaseProperties {
+    IsActive(bool),
+    Team(u8),
+    BaseID(u8),
+    SectorID(u8),
+
+    /// Capture progress from `0` to `100`
+    PointsPercentage(u8),
+    CapturingStopped(bool),
+    IsCaptured(bool),
+    InvadersCount(u8),
+    MaxPoints(f32),
+    OnDamageCooldownTime(f32),
+    Radius(f32),
+    ExpectedCaptureTime(f32),
+}
+
+/// Properties of a sector of the Frontline map
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum SectorProperties {
+    GroupID(u8),
+    SectorID(u8),
+    PlayerGroup(u8),
+    IDInPlayerGroup(u8),
+    Team(u8),
+    State(u8),
+    LengthX(f32),
+    LengthZ(f32),
+    TransitionTime(f32),
+    EndOfTransitionPeriod(f32),
+}
+
+/// Properties of the zone of Frontline that is defended by turrets
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum ProtectionZoneProperties {
+    ZoneID(u8),
+    Team(u8),
+    IsActive(bool),
+    LengthX(f32),
+    LengthZ(f32),
+}
+
+/// Properties of a mine placed on the map (ex: by the minefield ability of Frontline)
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum BasicMineProperties {
+    EquipmentID(u32),
+    OwnerVehicleID(i32),
+    IsDetonated(bool),
+    IsMarkerEnabled(bool),
+}
+
+/// Properties of a repair point of Frontline
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum StepRepairPointProperties {
+    Team(u8),
+    Radius(f32),
+}
+
+/// Properties of the point pickups of the 1.2 event
+#[derive(
+    Clone, Debug, strum::IntoStaticStr, strum::EnumVariantNames, Serialize, macros::EnumVariantDeserialize,
+)]
+pub enum EventPointsPickupProperties {
+    IsRemoving(bool),
+    ModelType(u8),
+    EventPoints(u16),
+    VehicleID(i32),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn parse(version: [u16; 4], entity_type: EntityType, property_id: usize, input: &[u8]) -> EntityProperty {
+        let context = Context::new(version, HashMap::new());
+
+        parse_arena_property(entity_type, input, property_id, &context)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn decodes_destructibles() {
+        // Two modules of the same object, as found in a 1.16.1 replay
+        let property = parse(
+            [1, 16, 1, 0],
+            EntityType::AreaDestructibles,
+            0,
+            &[2, 0, 31, 152, 0, 31, 148],
+        );
+        let EntityProperty::AreaDestructibles(AreaDestructiblesProperties::DestroyedModules(modules)) =
+            property
+        else {
+            panic!("unexpected property: {property:?}");
+        };
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[1].destructible_index, 31);
+        assert_eq!(modules[1].module_index, 148);
+
+        let property = parse(
+            [1, 16, 1, 0],
+            EntityType::AreaDestructibles,
+            3,
+            &[1, 0, 139, 189, 112, 59],
+        );
+        let EntityProperty::AreaDestructibles(AreaDestructiblesProperties::FallenTrees(trees)) = property
+        else {
+            panic!("unexpected property: {property:?}");
+        };
+        assert_eq!(trees[0].destructible_index, 139);
+        assert_eq!(trees[0].fall_speed, 59);
+    }
+
+    #[test]
+    fn decodes_frontline_entities() {
+        let property = parse([1, 16, 1, 0], EntityType::SectorBase, 4, &[42]);
+        assert!(matches!(
+            property,
+            EntityProperty::SectorBase(SectorBaseProperties::PointsPercentage(42))
+        ));
+
+        let property = parse(
+            [1, 16, 1, 0],
+            EntityType::ProtectionZone,
+            3,
+            &300.0f32.to_le_bytes(),
+        );
+        assert!(matches!(
+            property,
+            EntityProperty::ProtectionZone(ProtectionZoneProperties::LengthX(length)) if length == 300.0
+        ));
+
+        let property = parse([1, 16, 1, 0], EntityType::BasicMine, 2, &[7, 0, 0, 0]);
+        assert!(matches!(
+            property,
+            EntityProperty::BasicMine(BasicMineProperties::EquipmentID(7))
+        ));
+
+        let property = parse(
+            [1, 16, 1, 0],
+            EntityType::DestructibleEntity,
+            8,
+            &[1, 5, 0, 0, 0, 0, 0, 0, 0],
+        );
+        assert!(matches!(
+            property,
+            EntityProperty::DestructibleEntity(DestructibleEntityProperties::DamageStickers(stickers)) if stickers == [5]
+        ));
+    }
+
+    #[test]
+    fn uses_the_tables_of_the_version() {
+        // EventPointsPickup only exists in 1.2.0
+        let property = parse([1, 2, 0, 0], EntityType::EventPointsPickup, 2, &[0x2C, 0x01]);
+        assert!(matches!(
+            property,
+            EntityProperty::EventPointsPickup(EventPointsPickupProperties::EventPoints(300))
+        ));
+
+        let context = Context::new([1, 16, 1, 0], HashMap::new());
+        assert!(parse_arena_property(EntityType::EventPointsPickup, &[0x2C, 0x01], 2, &context).is_none());
+
+        // MaxHealth was added in 1.3.0, before that LinkedMapActivities follows Health
+        let property = parse(
+            [1, 2, 0, 0],
+            EntityType::DestructibleEntity,
+            6,
+            &[3, b'a', b'b', b'c'],
+        );
+        assert!(matches!(
+            property,
+            EntityProperty::DestructibleEntity(DestructibleEntityProperties::LinkedMapActivities(activities)) if activities == "abc"
+        ));
+    }
+}
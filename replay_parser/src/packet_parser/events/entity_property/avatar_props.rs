@@ -1,8 +1,6 @@
 use super::{EntityProperty, PropertyParser};
-use crate::{
-    entity_defs::{EntityType, AVATAR_PROPS},
-    packet_parser::prelude::*,
-};
+use crate::wot_types::WotValue;
+use crate::{entity_defs::EntityType, packet_parser::prelude::*};
 
 impl PropertyParser for AvatarProperties {
     fn parse(
@@ -12,14 +10,17 @@ impl PropertyParser for AvatarProperties {
         Self: Sized,
     {
         let version = context.get_version();
-        let version_str = crate::utils::version_as_string(version);
 
         let not_found_err = |err_msg| PacketError::NotFoundError {
-            err: format!("{err_msg} version={version_str} property_id={property_id}"),
+            err: format!(
+                "{err_msg} version={} property_id={property_id}",
+                crate::utils::version_as_string(version)
+            ),
         };
 
-        let props = AVATAR_PROPS
-            .get(&version_str)
+        let props = context
+            .profile()
+            .avatar_props()
             .ok_or_else(|| not_found_err("version not found"))?;
 
         let discrim = props
@@ -27,7 +28,15 @@ impl PropertyParser for AvatarProperties {
             .ok_or_else(|| not_found_err("property not found"))?;
 
 
-        let property = VariantDeserializer::deserialize_variant(discrim, input, &context)?;
+        let property: AvatarProperties = VariantDeserializer::deserialize_variant(discrim, input, &context)?;
+
+        if !property.has_payload() {
+            if let Some(property) =
+                EntityProperty::decode_generic(EntityType::Avatar, input, context, property_id)
+            {
+                return property;
+            }
+        }
 
         Ok(EntityProperty::Avatar(property))
     }
@@ -40,12 +49,12 @@ pub enum AvatarProperties {
     AmmoViews(AmmoViews),
 
     ArenaBonusType(u8),
-    ArenaExtraData, //Python
+    ArenaExtraData(Pickled<WotValue>),
     ArenaGuiType(u8),
     ArenaTypeID(i32),
     ArenaUniqueID(u64),
     ClientCtx(String),
-    ClientData, //Python
+    ClientData(Pickled<WotValue>),
     CustomizationDisplayType(u8),
     DenunciationsLeft(i16),
 
@@ -67,7 +76,7 @@ pub enum AvatarProperties {
     OwnVehicleGear(u8),
     PlayLimits, // PlayLimit Values
     PlayerVehicleID(i32),
-    QuestProgressDescriptor, //Python
+    QuestProgressDescriptor(Pickled<WotValue>),
 
     #[variant_de(delegate)]
     RemoteCamera(RemoteCamera),
@@ -1,3 +1,4 @@
+mod arena_props;
 mod avatar_props;
 mod vehicle_props;
 
@@ -11,6 +12,46 @@ pub enum EntityProperty {
     },
     Vehicle(VehicleProperties),
     Avatar(AvatarProperties),
+    AreaDestructibles(AreaDestructiblesProperties),
+    DestructibleEntity(DestructibleEntityProperties),
+    SectorBase(SectorBaseProperties),
+    Sector(SectorProperties),
+    ProtectionZone(ProtectionZoneProperties),
+    BasicMine(BasicMineProperties),
+    StepRepairPoint(StepRepairPointProperties),
+    EventPointsPickup(EventPointsPickupProperties),
+
+    /// A property we do not have a parser for, decoded from its type in the definition files. Not produced
+    /// for the versions we do not have the definition files of
+    Generic {
+        entity_type: EntityType,
+        name:        &'static str,
+        value:       WotValue,
+    },
+}
+
+impl EntityProperty {
+    /// Decode the property with the signatures of the version profile. `None` if the signatures of the
+    /// entity are not available
+    pub(crate) fn decode_generic(
+        entity_type: EntityType, input: &[u8], context: &Context, property_id: usize,
+    ) -> Option<Result<Self, PacketError>> {
+        let (name, ty) = context
+            .profile()
+            .signatures(entity_type)?
+            .properties
+            .get(property_id)?;
+
+        let property = decode_property(ty, input)
+            .map(|value| EntityProperty::Generic {
+                entity_type,
+                name,
+                value,
+            })
+            .map_err(|err| PacketError::entity_prop_err(entity_type, name, err.to_string()));
+
+        Some(property)
+    }
 }
 
 
@@ -19,12 +60,23 @@ pub(crate) trait PropertyParser {
     where
         Self: Sized;
 }
-use nom::number::complete::{le_i32, le_u32};
+use std::ops::Range;
+
+use nom::number::complete::{le_i32, le_u32, le_u8};
 
-use self::avatar_props::AvatarProperties;
-use self::vehicle_props::VehicleProperties;
+pub use self::arena_props::{
+    AreaDestructiblesProperties, BasicMineProperties, DestroyedFragile, DestroyedModule,
+    DestructibleEntityProperties, EventPointsPickupProperties, FallenColumn, FallenTree, ProtectionZoneProperties,
+    SectorBaseProperties, SectorProperties, StepRepairPointProperties,
+};
+pub use self::avatar_props::AvatarProperties;
+pub use self::vehicle_props::{
+    CrewGroups, PublicInfoValues, ShellSetup, VehicleEnhancements, VehiclePerks, VehicleProperties, VehicleSetups,
+};
 use crate::entity_defs::EntityType;
+use crate::packet_parser::generic::{decode_property, decode_value};
 use crate::packet_parser::prelude::*;
+use crate::wot_types::WotValue;
 
 #[derive(Debug, Clone, EventPrinter, Version, Serialize)]
 pub struct EntityPropertyEvent {
@@ -47,15 +99,7 @@ impl PacketParser for EntityPropertyEvent {
         let entity_type = context.find_entity_type(entity_id)?;
         let property_id = property_id as usize;
 
-        use EntityType::*;
-        let property = match entity_type {
-            Vehicle => VehicleProperties::parse(remaining, property_id, context),
-            Avatar => AvatarProperties::parse(remaining, property_id, context),
-            _ => Ok(EntityProperty::NotImplemented {
-                entity_type,
-                property_id,
-            }),
-        }?;
+        let property = parse_property(entity_type, remaining, property_id, context)?;
 
         Ok(BattleEvent::EntityProperty(EntityPropertyEvent {
             entity_id,
@@ -63,3 +107,146 @@ impl PacketParser for EntityPropertyEvent {
         }))
     }
 }
+
+/// Parse the value of the property `property_id` of an entity. `input` must be fully consumed
+fn parse_property(
+    entity_type: EntityType, input: &[u8], property_id: usize, context: &Context,
+) -> Result<EntityProperty, PacketError> {
+    use EntityType::*;
+    match entity_type {
+        Vehicle => VehicleProperties::parse(input, property_id, context),
+        Avatar => AvatarProperties::parse(input, property_id, context),
+        _ => arena_props::parse_arena_property(entity_type, input, property_id, context)
+            .or_else(|| EntityProperty::decode_generic(entity_type, input, context, property_id))
+            .unwrap_or(Ok(EntityProperty::NotImplemented {
+                entity_type,
+                property_id,
+            })),
+    }
+}
+
+/// Parse a list of properties as sent with the creation of an entity: the number of properties (`u8`)
+/// followed by the id (`u8`) and the value of each property. Parsed properties are pushed to `properties`
+/// so that the ones before an error are kept
+pub(crate) fn parse_property_list(
+    entity_type: EntityType, input: &[u8], context: &Context, properties: &mut Vec<EntityProperty>,
+) -> Result<(), PacketError> {
+    let (mut input, count) = le_u8(input)?;
+
+    for _ in 0..count {
+        let (remaining, property_id) = le_u8(input)?;
+        let (remaining, property) =
+            parse_sized_property(entity_type, remaining, property_id as usize, context)?;
+
+        properties.push(property);
+        input = remaining;
+    }
+
+    if input.is_empty() {
+        Ok(())
+    } else {
+        Err(PacketError::UnconsumedInput)
+    }
+}
+
+/// Find the value of the property `name` in a list of properties as sent with the creation of an entity.
+/// Returns the position of the value in `input`, or `None` if the property is not in the list
+pub(crate) fn find_property_value(
+    entity_type: EntityType, input: &[u8], name: &str, context: &Context,
+) -> Result<Option<Range<usize>>, PacketError> {
+    let signatures = context
+        .profile()
+        .signatures(entity_type)
+        .ok_or_else(|| PacketError::NotFoundError {
+            err: format!("unable to find the properties of {entity_type}"),
+        })?;
+
+    let (mut remaining, count) = le_u8(input)?;
+    for _ in 0..count {
+        let (value, property_id) = le_u8(remaining)?;
+        let Some((property, ty)) = signatures.properties.get(property_id as usize) else {
+            return Err(PacketError::NotFoundError {
+                err: format!("unable to find the size of property {property_id} of {entity_type}"),
+            });
+        };
+        let (next, _) = decode_value(ty, value)?;
+
+        if *property == name {
+            let start = input.len() - value.len();
+            return Ok(Some(start..(input.len() - next.len())));
+        }
+        remaining = next;
+    }
+
+    Ok(None)
+}
+
+/// Parse a property that is followed by other data. Its size is the size of its type in the definition
+/// files. Returns the remaining input
+fn parse_sized_property<'a>(
+    entity_type: EntityType, input: &'a [u8], property_id: usize, context: &Context,
+) -> Result<(&'a [u8], EntityProperty), PacketError> {
+    let Some((_, ty)) = context
+        .profile()
+        .signatures(entity_type)
+        .and_then(|signatures| signatures.properties.get(property_id))
+    else {
+        return Err(PacketError::NotFoundError {
+            err: format!("unable to find the size of property {property_id} of {entity_type}"),
+        });
+    };
+
+    let (remaining, _) = decode_value(ty, input)?;
+    let value = &input[..input.len() - remaining.len()];
+
+    Ok((
+        remaining,
+        parse_property(entity_type, value, property_id, context)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn parse_list(input: &[u8]) -> (Result<(), PacketError>, Vec<EntityProperty>) {
+        let context = Context::new([1, 16, 1, 0], HashMap::new());
+        let mut properties = Vec::new();
+
+        let result = parse_property_list(EntityType::Vehicle, input, &context, &mut properties);
+
+        (result, properties)
+    }
+
+    #[test]
+    fn parses_property_list() {
+        // Health (INT16), EngineMode (TUPLE of 2 UINT8) and AvatarID (INT32) of a vehicle in 1.16.1
+        let input = [3, 12, 0xF4, 0x01, 13, 2, 1, 14, 77, 0, 0, 0];
+
+        let (result, properties) = parse_list(&input);
+        result.unwrap();
+        assert_eq!(properties.len(), 3);
+        assert!(matches!(
+            properties[0],
+            EntityProperty::Vehicle(VehicleProperties::Health(500))
+        ));
+    }
+
+    #[test]
+    fn keeps_properties_before_error() {
+        // The value of AvatarID is cut short
+        let (result, properties) = parse_list(&[2, 12, 0xF4, 0x01, 14, 77, 0]);
+        assert!(result.is_err());
+        assert_eq!(properties.len(), 1);
+
+        // There is no property 200
+        let (result, properties) = parse_list(&[2, 12, 0xF4, 0x01, 200, 1]);
+        assert!(matches!(result, Err(PacketError::NotFoundError { .. })));
+        assert_eq!(properties.len(), 1);
+
+        let (result, _) = parse_list(&[1, 12, 0xF4, 0x01, 0]);
+        assert!(matches!(result, Err(PacketError::UnconsumedInput)));
+    }
+}
@@ -1,8 +1,7 @@
+use std::collections::HashMap;
+
 use super::{EntityProperty, PropertyParser};
-use crate::{
-    entity_defs::{EntityType, VEHICLE_PROPS},
-    packet_parser::prelude::*,
-};
+use crate::{entity_defs::EntityType, packet_parser::prelude::*};
 
 impl PropertyParser for VehicleProperties {
     fn parse(
@@ -12,14 +11,17 @@ impl PropertyParser for VehicleProperties {
         Self: Sized,
     {
         let version = context.get_version();
-        let version_str = crate::utils::version_as_string(version);
 
         let not_found_err = |err_msg| PacketError::NotFoundError {
-            err: format!("{err_msg} version={version_str} property_id={property_id}"),
+            err: format!(
+                "{err_msg} version={} property_id={property_id}",
+                crate::utils::version_as_string(version)
+            ),
         };
 
-        let props = VEHICLE_PROPS
-            .get(&version_str)
+        let props = context
+            .profile()
+            .vehicle_props()
             .ok_or_else(|| not_found_err("version not found"))?;
 
         let discrim = props
@@ -27,12 +29,21 @@ impl PropertyParser for VehicleProperties {
             .ok_or_else(|| not_found_err("property not found"))?;
 
         let property = match *discrim {
-            "Debuff" => parse_debuff_values(input, context.get_version()),
-            "StunInfo" => parse_stun_info_values(input, context.get_version()),
+            "Debuff" => parse_debuff_values(input, version),
+            "StunInfo" => parse_stun_info_values(input, context),
+            "PublicInfo" => parse_public_info_values(input, version),
             _ => VariantDeserializer::deserialize_variant(discrim, input, &context),
         }
         .map_err(|err| PacketError::entity_prop_err(EntityType::Vehicle, discrim, err.to_string()))?;
 
+        if !property.has_payload() {
+            if let Some(property) =
+                EntityProperty::decode_generic(EntityType::Vehicle, input, context, property_id)
+            {
+                return property;
+            }
+        }
+
         Ok(EntityProperty::Vehicle(property))
     }
 }
@@ -57,7 +68,7 @@ pub enum VehicleProperties {
     DisabledSwitches(Vec<i32>),
     DotEffect(DotEffect),
     EngineMode((u8, u8)),
-    Enhancements, //<Python>
+    Enhancements(Pickled<VehicleEnhancements>),
     Gear(i8),
     GunAnglesPacked(u16),
     GunAnglesPackedArr(Vec<u16>), // This field is manually added by us to support weird behavior in 0.9.20
@@ -97,14 +108,17 @@ pub enum VehicleProperties {
     OwnVehiclePosition(OwnVehiclePosition),
 
     PhysicsMode(u8),
-    PublicInfo, //PublicInfoValues
+
+    #[variant_de(manual)]
+    PublicInfo(PublicInfoValues),
+
     PublicStateModifiers(Vec<u8>),
     QuickShellChangerFactor(f32),
     RaceFinishTime(f32),
     RacePosition(i32),
     SecondGunAnglesPacked(u16),
-    Setups,        // Python
-    SetupsIndexes, // Python
+    Setups(Pickled<VehicleSetups>),
+    SetupsIndexes(Pickled<HashMap<u8, u8>>), // Setup group -> index of the selected setup
     SiegeState(u8),
     SteeringAngle(f32),
     SteeringAngles(Vec<u8>),
@@ -114,7 +128,7 @@ pub enum VehicleProperties {
 
     TeamBasePoints(u16),
     TrackScrolling(u16),
-    VehPerks, // Python
+    VehPerks(Pickled<VehiclePerks>),
     VehPostProgression(Vec<i32>),
     WheelsScroll(Vec<u8>),
     WheelsState(u64),
@@ -126,6 +140,70 @@ impl VehicleProperties {
     }
 }
 
+/// Loadouts of the vehicle (`Setups` property). Each field has one entry per setup of the group: vehicles
+/// with the switchable setups feature have two, the others only one. Items are compact descriptors
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct VehicleSetups {
+    #[serde(
+        rename = "shellsSetups",
+        default,
+        deserialize_with = "deserialize_shells_setups"
+    )]
+    pub shells: Vec<Vec<ShellSetup>>,
+
+    #[serde(rename = "eqsSetups", default)]
+    pub equipment: Vec<Vec<i32>>,
+
+    #[serde(rename = "devicesSetups", default)]
+    pub devices: Vec<Vec<i32>>,
+
+    #[serde(rename = "boostersSetups", default)]
+    pub boosters: Vec<Vec<i32>>,
+}
+
+/// Perks of the crew that apply to the vehicle (`VehPerks` property): the level of each perk, keyed by the id
+/// of the perk. Only sent to the player of the vehicle, so the format has not been checked against a replay
+#[derive(Clone, Serialize, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct VehiclePerks {
+    pub levels: HashMap<u32, u32>,
+}
+
+/// Field modifications of the vehicle (`Enhancements` property): the level of each enhancement, keyed by the
+/// id of the enhancement. Like [VehiclePerks], only sent to the player of the vehicle
+#[derive(Clone, Serialize, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct VehicleEnhancements {
+    pub levels: HashMap<u32, u32>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct ShellSetup {
+    pub comp_descr: i32,
+    pub count:      u16,
+}
+
+/// Shells are sent as a flat list of `compDescr, count` pairs
+fn deserialize_shells_setups<'de, D>(deserializer: D) -> Result<Vec<Vec<ShellSetup>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let setups: Vec<Vec<i32>> = Deserialize::deserialize(deserializer)?;
+
+    Ok(setups
+        .into_iter()
+        .map(|setup| {
+            setup
+                .chunks_exact(2)
+                .map(|pair| ShellSetup {
+                    comp_descr: pair[0],
+                    count:      pair[1] as u16,
+                })
+                .collect()
+        })
+        .collect())
+}
+
 #[derive(Clone, Serialize, Debug, Deserialize, Version)]
 pub struct HealingEffect {
     radius:             f32,
@@ -209,8 +287,13 @@ pub struct StunInfo {
     stun_type:        u8,
 }
 
-pub fn parse_stun_info_values(input: &[u8], de_version: [u16; 4]) -> Result<VehicleProperties, PacketError> {
-    if de_version >= [1, 20, 0, 0] {
+/// `STUN_INFO` is a `FLOAT64` in the definitions we have and a struct from 1.20. Versions we do not have the
+/// definitions of are parsed with the closest version we have, so the size of the property is what tells
+/// them apart
+pub fn parse_stun_info_values(input: &[u8], context: &Context) -> Result<VehicleProperties, PacketError> {
+    let de_version = context.get_version();
+
+    if input.len() != std::mem::size_of::<f64>() {
         Ok(VehicleProperties::StunInfo(StunInfoValues::StunInfo(from_slice(
             input, de_version,
         )?)))
@@ -255,38 +338,228 @@ pub struct InspiredValues {
     equipment_id: Option<u16>,
 }
 
-// // #[version([0, 9, 12, 0])]
-// pub struct PublicInfoValues {
-//     name: String,
-//     compDescr: String,
+/// Info about a vehicle that is sent to every player
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct PublicInfoValues {
+    pub name:         String,
+    pub comp_descr:   Vec<u8>,
+    pub outfit:       Option<Vec<u8>>,
+    pub index:        Option<u8>,
+    pub team:         u8,
+    pub prebattle_id: i32,
+    pub marks_on_gun: u8,
+
+    pub crew_group:        Option<CrewGroups>,
+    pub commander_skin_id: Option<u16>,
+    pub max_health:        Option<u16>,
+}
+
+/// The type of `crew_group` changed over time, so it cannot be part of a struct deserialized with the
+/// `#[version]` attributes
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub enum CrewGroups {
+    /// From 1.4.1
+    U16(u16),
+
+    /// From 1.12.1
+    U16Array(Vec<u16>),
+
+    /// From 1.19.1
+    U32Array(Vec<u32>),
+}
+
+impl CrewGroups {
+    pub fn to_vec(&self) -> Vec<u32> {
+        match self {
+            CrewGroups::U16(group) => vec![*group as u32],
+            CrewGroups::U16Array(groups) => groups.iter().map(|group| *group as u32).collect(),
+            CrewGroups::U32Array(groups) => groups.clone(),
+        }
+    }
+}
 
-//     #[0, 9, 21, 0]
-//     outfit: String,
+/// Fields of `PublicInfo` that come before `crew_group`
+#[derive(Clone, Serialize, Debug, Deserialize, Version)]
+struct PublicInfoHead {
+    name:       String,
+    comp_descr: Vec<u8>,
 
-//     #[0, 9, 14, 0]
-//     index: u8,
+    #[version([0, 9, 21, 0])]
+    outfit: Option<Vec<u8>>,
 
-//     team: u8,
-//     prebattleID: i32,
-//     marksOnGun: u8
+    #[version([0, 9, 14, 0])]
+    index: Option<u8>,
 
-//     #[version([1, 4, 1, 0])]
-//     crewGroup: CrewGroups,
+    team:         u8,
+    prebattle_id: i32,
+    marks_on_gun: u8,
+}
 
-//     #[version([1, 4, 1, 0])]
-//     commanderSkinID: u16,
+/// Fields of `PublicInfo` that come after `crew_group`
+#[derive(Clone, Serialize, Debug, Deserialize, Version)]
+struct PublicInfoTail {
+    #[version([1, 4, 1, 0])]
+    commander_skin_id: Option<u16>,
 
-//     #[version[1, 10, 0, 0]]
-//     maxHealth: u16
+    #[version([1, 10, 0, 0])]
+    max_health: Option<u16>,
+}
 
+pub fn parse_public_info_values(
+    input: &[u8], de_version: [u16; 4],
+) -> Result<VehicleProperties, PacketError> {
+    let (input, head) = from_slice_unchecked::<PublicInfoHead>(input, de_version)?;
+
+    let (input, crew_group) = if de_version >= [1, 19, 1, 0] {
+        let (input, groups) = from_slice_prim_unchecked(input, de_version)?;
+        (input, Some(CrewGroups::U32Array(groups)))
+    } else if de_version >= [1, 12, 1, 0] {
+        let (input, groups) = from_slice_prim_unchecked(input, de_version)?;
+        (input, Some(CrewGroups::U16Array(groups)))
+    } else if de_version >= [1, 4, 1, 0] {
+        let (input, group) = from_slice_prim_unchecked(input, de_version)?;
+        (input, Some(CrewGroups::U16(group)))
+    } else {
+        (input, None)
+    };
+
+    let tail: PublicInfoTail = from_slice(input, de_version)?;
+
+    Ok(VehicleProperties::PublicInfo(PublicInfoValues {
+        name: head.name,
+        comp_descr: head.comp_descr,
+        outfit: head.outfit,
+        index: head.index,
+        team: head.team,
+        prebattle_id: head.prebattle_id,
+        marks_on_gun: head.marks_on_gun,
+        crew_group,
+        commander_skin_id: tail.commander_skin_id,
+        max_health: tail.max_health,
+    }))
+}
 
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_public_info() {
+        // Taken from the `EntityCreate` of a vehicle in `input_files/example.wotreplay` (1.16.1)
+        let input = [
+            &[12][..],
+            b"BipolarPixel",
+            &[
+                15, 0x51, 0x18, 0x36, 0x00, 0x50, 0x00, 0xcd, 0x00, 0x14, 0x00, 0x34, 0x00, 0x58, 0x00, 0x00,
+            ],
+            &[4, 0x04, 0x10, 0xf9, 0x03],
+            &[0, 2, 0, 0, 0, 0, 0],
+            &[4, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0x00, 0x00, 0xc4, 0x09],
+        ]
+        .concat();
+
+        let VehicleProperties::PublicInfo(public_info) =
+            parse_public_info_values(&input, [1, 16, 1, 0]).unwrap()
+        else {
+            panic!("expected PublicInfo");
+        };
+        assert_eq!(public_info.name, "BipolarPixel");
+        assert_eq!(public_info.comp_descr.len(), 15);
+        assert_eq!(public_info.outfit.as_deref(), Some(&[0x04, 0x10, 0xf9, 0x03][..]));
+        assert_eq!(public_info.team, 2);
+        assert_eq!(public_info.marks_on_gun, 0);
+        assert_eq!(public_info.crew_group.unwrap().to_vec(), vec![0; 4]);
+        assert_eq!(public_info.commander_skin_id, Some(0));
+        assert_eq!(public_info.max_health, Some(2500));
+
+        assert!(parse_public_info_values(&input, [1, 19, 1, 0]).is_err());
+    }
 
-// pub enum CrewGroups {
-//     #[version([1, 4, 1, 0])]
-//     U16(u16),
-//     #[version([1, 12, 1, 0])]
-//     U16Array(Vec<u16>),
-//     #[version([1, 19, 1, 0])]
-//     U32Array(Vec<u32>),
-// }
+    #[test]
+    fn parses_setups() {
+        use serde_pickle::{HashableValue, Value};
+
+        let setups = |setups: &[&[i64]]| {
+            Value::List(
+                setups
+                    .iter()
+                    .map(|setup| Value::List(setup.iter().map(|item| Value::I64(*item)).collect()))
+                    .collect(),
+            )
+        };
+        let pickle = Value::Dict(
+            [
+                ("shellsSetups", setups(&[&[26426, 31, 26170, 0], &[26170, 18]])),
+                ("eqsSetups", setups(&[&[1531, 1019, 4091]])),
+            ]
+            .into_iter()
+            .map(|(key, value)| (HashableValue::Bytes(key.as_bytes().to_vec()), value))
+            .collect(),
+        );
+        let pickle = serde_pickle::value_to_vec(&pickle, serde_pickle::SerOptions::new()).unwrap();
+        let input = [&[pickle.len() as u8][..], &pickle].concat();
+
+        let Pickled(setups) = from_slice_prim::<Pickled<VehicleSetups>>(&input, [1, 16, 1, 0]).unwrap();
+        assert_eq!(setups.shells.len(), 2);
+        assert_eq!(setups.shells[0][0].comp_descr, 26426);
+        assert_eq!(setups.shells[0][0].count, 31);
+        assert_eq!(setups.shells[1].len(), 1);
+        assert_eq!(setups.equipment, vec![vec![1531, 1019, 4091]]);
+        assert!(setups.boosters.is_empty());
+    }
+
+    #[test]
+    fn parses_perks_and_enhancements() {
+        use serde_pickle::{HashableValue, Value};
+
+        // Built with `serde_pickle`, these properties are not sent to the player who recorded our replays
+        let levels = |entries: &[(i64, i64)]| {
+            let dict = Value::Dict(
+                entries
+                    .iter()
+                    .map(|(id, level)| (HashableValue::I64(*id), Value::I64(*level)))
+                    .collect(),
+            );
+            let pickle = serde_pickle::value_to_vec(&dict, serde_pickle::SerOptions::new()).unwrap();
+
+            [&[pickle.len() as u8][..], &pickle].concat()
+        };
+
+        let input = levels(&[(1, 3), (14, 1)]);
+        let Pickled(perks) = from_slice_prim::<Pickled<VehiclePerks>>(&input, [1, 16, 1, 0]).unwrap();
+        assert_eq!(perks.levels, HashMap::from([(1, 3), (14, 1)]));
+
+        let input = levels(&[]);
+        let Pickled(enhancements) =
+            from_slice_prim::<Pickled<VehicleEnhancements>>(&input, [1, 16, 1, 0]).unwrap();
+        assert!(enhancements.levels.is_empty());
+
+        // A level that is not an integer is an error instead of a value we cannot use
+        let dict = Value::Dict([(HashableValue::I64(1), Value::String("a".to_string()))].into());
+        let pickle = serde_pickle::value_to_vec(&dict, serde_pickle::SerOptions::new()).unwrap();
+        let input = [&[pickle.len() as u8][..], &pickle].concat();
+        assert!(from_slice_prim::<Pickled<VehiclePerks>>(&input, [1, 16, 1, 0]).is_err());
+    }
+
+    #[test]
+    fn parses_stun_info_by_size() {
+        // 1.20 replays are parsed with the tables of the latest version we have
+        let context = Context::new([1, 20, 0, 0], HashMap::new());
+
+        let stun_time = 123.5f64.to_le_bytes();
+        assert!(matches!(
+            parse_stun_info_values(&stun_time, &context),
+            Ok(VehicleProperties::StunInfo(StunInfoValues::F64(time))) if time == 123.5
+        ));
+
+        let stun_info = [&stun_time[..], &[2]].concat();
+        assert!(matches!(
+            parse_stun_info_values(&stun_info, &context),
+            Ok(VehicleProperties::StunInfo(StunInfoValues::StunInfo(StunInfo {
+                stun_type: 2,
+                ..
+            })))
+        ));
+    }
+}
@@ -1,7 +1,20 @@
 /// `entity_method` describe multiple events because there can be many different types of method calls
 mod entity_method;
-pub use entity_method::avatar_methods::update_arena::ArenaUpdateData;
+pub use entity_method::arena_methods::{
+    DestructibleDamageFromShot, DestructibleEntityMethods, DestructibleHealthChanged, SectorMethods,
+};
+pub use entity_method::avatar_methods::update_arena::{
+    ArenaUpdateData, CombatEquipmentUsed, RadarInfo, RadarLoot, RadarVehicle, SyncObjects, TeamKiller, UpdateArena,
+    VehicleData, VehicleRecovered,
+};
+pub use entity_method::avatar_methods::{
+    AvatarMethods, BattleEventDetails, BattleEvents, ChatAction, ChatCommand, ChatCommandArgs, LegacyBattleEvent,
+    PlayerBattleEvent, ShotOutcome, ShotResult,
+};
 pub use entity_method::vehicle_methods::*;
+pub use entity_method::vehicle_misc_status::{
+    DestroyTimer, VehicleMiscStatus, VehicleMiscStatusCode, VehicleMiscStatusDetail,
+};
 pub use entity_method::EntityMethod;
 pub use entity_method::EntityMethodEvent;
 
@@ -15,14 +28,36 @@ mod position;
 pub use position::Position;
 
 mod chat;
-pub use chat::Chat;
+pub use chat::{ChatChannel, ChatMessage};
 
 mod entity_create;
 pub use entity_create::EntityCreate;
 
+mod entity_enter;
+pub use entity_enter::EntityEnter;
+
+mod entity_leave;
+pub use entity_leave::EntityLeave;
+
+mod entity_control;
+pub use entity_control::EntityControl;
+
+mod arena_period;
+pub(crate) use arena_period::parse_arena_period;
+pub use arena_period::{ArenaPeriodEvent, ArenaPeriodLength};
+
 mod crypto_key;
 pub use crypto_key::CryptoKey;
 
 mod entity_property;
 pub use entity_property::EntityProperty;
 pub use entity_property::EntityPropertyEvent;
+pub(crate) use entity_property::find_property_value;
+pub use entity_property::{
+    AreaDestructiblesProperties, AvatarProperties, BasicMineProperties, CrewGroups, DestroyedFragile,
+    DestroyedModule, DestructibleEntityProperties, EventPointsPickupProperties, FallenColumn, FallenTree,
+    ProtectionZoneProperties, PublicInfoValues, SectorBaseProperties, SectorProperties, ShellSetup,
+    StepRepairPointProperties, VehicleEnhancements, VehiclePerks, VehicleProperties, VehicleSetups,
+};
+
+pub use super::types::{Pickled, Vector3};
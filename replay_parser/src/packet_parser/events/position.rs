@@ -29,7 +29,7 @@ impl PacketParser for Position {
 mod tests {
     use super::*;
     use crate::utils::replay_iterator;
-    pub use crate::{Packet, ReplayParser};
+    pub use crate::{Packet, PacketType, ReplayParser};
 
     #[test]
     fn parses_position() {
@@ -43,9 +43,10 @@ mod tests {
                 return;
             }
 
-            let packet_stream = parser
-                .packet_stream()
-                .filter(|packet| packet.as_ref().unwrap().packet_type() == 0x0A);
+            let version = context.get_version();
+            let packet_stream = parser.packet_stream().filter(|packet| {
+                PacketType::from_raw(packet.as_ref().unwrap().packet_type(), version) == PacketType::Position
+            });
 
             for position_packet in packet_stream {
                 let position_packet = position_packet.unwrap();
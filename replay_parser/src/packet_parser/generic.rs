@@ -0,0 +1,197 @@
+//! Decoding of any method argument or property into a [WotValue] by following its type signature from the
+//! `.def` files. Used as a fallback for methods and properties we do not have a hand-written struct for.
+use std::collections::HashMap;
+
+use nom::bytes::complete::take;
+use nom::number::complete::{
+    le_f32, le_f64, le_i16, le_i32, le_i64, le_i8, le_u16, le_u24, le_u32, le_u64, le_u8,
+};
+
+use super::{parse_byte_array, PacketError};
+use crate::entity_defs::DefType;
+use crate::wot_types::WotValue;
+
+/// Decode the arguments of a method call. `input` must be fully consumed
+pub(crate) fn decode_args(params: &[DefType], mut input: &[u8]) -> Result<Vec<WotValue>, PacketError> {
+    let mut args = Vec::with_capacity(params.len());
+
+    for param in params {
+        let (remaining, arg) = decode_value(param, input)?;
+        args.push(arg);
+        input = remaining;
+    }
+
+    if input.is_empty() {
+        Ok(args)
+    } else {
+        Err(PacketError::UnconsumedInput)
+    }
+}
+
+/// Decode the value of a property. `input` must be fully consumed
+pub(crate) fn decode_property(ty: &DefType, input: &[u8]) -> Result<WotValue, PacketError> {
+    let (remaining, value) = decode_value(ty, input)?;
+
+    if remaining.is_empty() {
+        Ok(value)
+    } else {
+        Err(PacketError::UnconsumedInput)
+    }
+}
+
+/// Decode a single value of type `ty`. Returns the remaining input
+pub(crate) fn decode_value<'a>(ty: &DefType, input: &'a [u8]) -> Result<(&'a [u8], WotValue), PacketError> {
+    let int = |(remaining, value)| (remaining, WotValue::Int(value));
+    let uint = |(remaining, value)| (remaining, WotValue::Uint(value));
+    let float = |(remaining, value)| (remaining, WotValue::Float(value));
+
+    let decoded = match ty {
+        DefType::U8 => le_u8(input).map(|(rem, x)| uint((rem, x as u64)))?,
+        DefType::U16 => le_u16(input).map(|(rem, x)| uint((rem, x as u64)))?,
+        DefType::U32 => le_u32(input).map(|(rem, x)| uint((rem, x as u64)))?,
+        DefType::U64 => le_u64(input).map(uint)?,
+        DefType::I8 => le_i8(input).map(|(rem, x)| int((rem, x as i64)))?,
+        DefType::I16 => le_i16(input).map(|(rem, x)| int((rem, x as i64)))?,
+        DefType::I32 => le_i32(input).map(|(rem, x)| int((rem, x as i64)))?,
+        DefType::I64 => le_i64(input).map(int)?,
+        DefType::F32 => le_f32(input).map(|(rem, x)| float((rem, x as f64)))?,
+        DefType::F64 => le_f64(input).map(float)?,
+        DefType::Vector2 => decode_vector(input, 2)?,
+        DefType::Vector3 => decode_vector(input, 3)?,
+        DefType::Vector4 => decode_vector(input, 4)?,
+        DefType::String => {
+            let (remaining, bytes) = parse_byte_array(input)?;
+            let value = match std::str::from_utf8(bytes) {
+                Ok(text) => WotValue::Text(text.to_string()),
+                Err(_) => WotValue::Bytes(bytes.to_vec()),
+            };
+
+            (remaining, value)
+        }
+        DefType::Pickle => {
+            let (remaining, bytes) = parse_byte_array(input)?;
+
+            (remaining, unpickle(bytes))
+        }
+        // The format of user types is defined in Python code. The ones we have seen are sent as a blob
+        DefType::UserType => {
+            let (remaining, bytes) = parse_byte_array(input)?;
+
+            (remaining, WotValue::Bytes(bytes.to_vec()))
+        }
+        DefType::MailBox => {
+            let (remaining, bytes) = take(12usize)(input)?;
+
+            (remaining, WotValue::Bytes(bytes.to_vec()))
+        }
+        DefType::Array(ty) => {
+            let (input, len) = parse_packed_len(input)?;
+
+            decode_collection(ty, len as usize, input)?
+        }
+        DefType::FixedArray(ty, len) => decode_collection(ty, *len, input)?,
+        DefType::FixedDict { is_nullable, fields } => {
+            let mut input = input;
+
+            if *is_nullable {
+                let (remaining, is_present) = le_u8(input)?;
+                if is_present == 0 {
+                    return Ok((remaining, WotValue::None));
+                }
+                input = remaining;
+            }
+
+            let mut values = HashMap::new();
+            for (name, ty) in fields.iter() {
+                let (remaining, value) = decode_value(ty, input)?;
+                values.insert(name.to_string(), value);
+                input = remaining;
+            }
+
+            (input, WotValue::NamedCollection(values))
+        }
+    };
+
+    Ok(decoded)
+}
+
+/// `len` values of type `ty`
+fn decode_collection<'a>(
+    ty: &DefType, len: usize, mut input: &'a [u8],
+) -> Result<(&'a [u8], WotValue), PacketError> {
+    let mut values = Vec::new();
+    for _ in 0..len {
+        let (remaining, value) = decode_value(ty, input)?;
+        values.push(value);
+        input = remaining;
+    }
+
+    Ok((input, WotValue::Collection(values)))
+}
+
+fn decode_vector(mut input: &[u8], len: usize) -> Result<(&[u8], WotValue), PacketError> {
+    let mut values = Vec::with_capacity(len);
+
+    for _ in 0..len {
+        let (remaining, value) = le_f32(input)?;
+        values.push(WotValue::Float(value as f64));
+        input = remaining;
+    }
+
+    Ok((input, WotValue::Collection(values)))
+}
+
+/// Pickles that cannot be read are kept as bytes
+fn unpickle(bytes: &[u8]) -> WotValue {
+    crate::utils::unpickle(bytes)
+        .ok()
+        .and_then(|pickle| serde_pickle::from_value(pickle).ok())
+        .unwrap_or_else(|| WotValue::Bytes(bytes.to_vec()))
+}
+
+/// Length of arrays. Same format as the length of byte arrays (see [parse_byte_array])
+pub(crate) fn parse_packed_len(input: &[u8]) -> Result<(&[u8], u32), PacketError> {
+    let (remaining, len) = le_u8(input)?;
+
+    if len == u8::MAX {
+        Ok(le_u24(remaining)?)
+    } else {
+        Ok((remaining, len as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_nested_types() {
+        // FIXED_DICT (AllowNone) { kills: ARRAY<UINT16>, name: STRING }
+        let ty = DefType::FixedDict {
+            is_nullable: true,
+            fields:      &[
+                ("kills", DefType::Array(&DefType::U16)),
+                ("name", DefType::String),
+            ],
+        };
+        // TUPLE<UINT8> with a size of 2
+        let pair = DefType::FixedArray(&DefType::U8, 2);
+        let params = [ty, ty, DefType::I8, pair];
+        let input = [1, 2, 7, 0, 9, 0, 3, b'f', b'o', b'o', 0, 0xFF, 4, 5];
+
+        let args = decode_args(&params, &input).unwrap();
+        let WotValue::NamedCollection(dict) = &args[0] else {
+            panic!("expected a dict, got {:?}", args[0]);
+        };
+        assert!(matches!(&dict["kills"], WotValue::Collection(kills) if kills.len() == 2));
+        assert!(matches!(&dict["name"], WotValue::Text(name) if name == "foo"));
+        assert!(matches!(args[1], WotValue::None));
+        assert!(matches!(args[2], WotValue::Int(-1)));
+        assert!(matches!(&args[3], WotValue::Collection(pair) if pair.len() == 2));
+
+        assert!(matches!(
+            decode_args(&params, &[input.as_slice(), &[0]].concat()),
+            Err(PacketError::UnconsumedInput)
+        ));
+    }
+}
@@ -41,6 +41,10 @@
 mod serde_packet;
 pub use serde_packet::from_slice;
 pub use serde_packet::from_slice_unchecked;
+pub(crate) use serde_packet::{parse_byte_array, write_byte_array};
+
+/// Decodes methods and properties into `WotValue` using the signatures from the definition files
+mod generic;
 
 /// Contains code for all the different types of events. For each packet, we have an event. An event can be
 /// considered the human readable abstraction over a packet.
@@ -49,20 +53,29 @@ pub mod events;
 /// Contains `Packet`, and `PacketStream`. A light zero-copy wrapper for the binary data from
 /// `.wotreplay`. These are then used by the `events` module to parse into events that we can understand.
 mod packet;
+pub use packet::OwnedPacket;
 pub use packet::Packet;
 pub use packet::PacketStream;
 
+mod packet_type;
+pub use packet_type::PacketType;
+
+mod packet_index;
+pub use packet_index::{IndexEntry, PacketIndex};
+pub(crate) use packet::METADATA_SIZE;
+
 mod error;
 pub use error::PacketError;
 
 mod context;
-pub use context::Context;
+pub use context::{Context, LiveEntity};
 
 mod event;
 pub use event::BattleEvent;
 pub use event::EventPrinter;
 pub use event::EventStream;
 pub use event::PacketParser;
+pub use event::TimedEventStream;
 
 pub mod types;
 
@@ -79,14 +92,51 @@ pub mod types;
 ///   `serde_packet::from_slice_prim`
 /// - no attribute and unit like enum variant. This will just return that unit variant whenever it is
 ///
+/// `has_payload` is `false` for unit variants, i.e. the ones whose data we do not parse yet. When the
+/// signatures of the entity are known (see `VersionProfile::signatures`), those are decoded generically
+/// instead.
+///
 /// The way we know which variant to target is using the `discrim` argument provided to
-/// `VariantDeserializer::deserialize_variant`. See how it is used in `avatar_methods.rs` for example usage.
+/// `VariantDeserializer::deserialize_variant`, or the index of the variant provided to
+/// `VariantDeserializer::deserialize_variant_at`. See how it is used in `avatar_methods.rs` for example usage.
 pub trait VariantDeserializer {
     fn deserialize_variant(
         discrim: &'static str, input: &[u8], context: &Context,
     ) -> Result<Self, PacketError>
     where
         Self: Sized;
+
+    /// Same as `deserialize_variant` with the index of the variant, in the order they are declared (same as
+    /// `strum::VariantNames::VARIANTS`), instead of its name
+    fn deserialize_variant_at(variant: usize, input: &[u8], context: &Context) -> Result<Self, PacketError>
+    where
+        Self: Sized;
+
+    fn has_payload(&self) -> bool;
+}
+
+/// Index of the variant `name` in `variants` (see `strum::VariantNames::VARIANTS`). Evaluated at compile time
+/// so that a variant that does not exist is a compile error
+pub(crate) const fn variant_index(variants: &[&str], name: &str) -> usize {
+    let mut index = 0;
+
+    while index < variants.len() {
+        let variant = variants[index].as_bytes();
+        let name = name.as_bytes();
+
+        if variant.len() == name.len() {
+            let mut i = 0;
+            while i < name.len() && variant[i] == name[i] {
+                i += 1;
+            }
+            if i == name.len() {
+                return index;
+            }
+        }
+        index += 1;
+    }
+
+    panic!("variant not found")
 }
 
 pub(crate) mod prelude {
@@ -94,9 +144,11 @@ pub(crate) mod prelude {
     pub(crate) use serde::{Deserialize, Serialize};
 
     pub(crate) use super::event::{BattleEvent, EventPrinter, PacketParser, TrackVersion, VersionInfo};
-    pub(crate) use super::serde_packet::{from_slice, from_slice_prim, from_slice_unchecked};
-    pub(crate) use super::types::Vector3;
+    pub(crate) use super::serde_packet::{
+        from_slice, from_slice_prim, from_slice_prim_unchecked, from_slice_unchecked,
+    };
+    pub(crate) use super::types::{deserialize_pickle, Pickled, Vector3};
     pub(crate) use super::Context;
-    pub(crate) use super::VariantDeserializer;
+    pub(crate) use super::{variant_index, VariantDeserializer};
     pub(crate) use super::{Packet, PacketError};
 }
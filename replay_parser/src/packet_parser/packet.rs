@@ -1,5 +1,6 @@
 use byteorder::{ReadBytesExt, LE};
 
+use crate::packet_parser::{Context, PacketType};
 use crate::ReplayError;
 
 pub const METADATA_SIZE: usize = 12;
@@ -26,6 +27,11 @@ impl<'pkt> Packet<'pkt> {
         chunk.read_u32::<LE>().unwrap()
     }
 
+    /// Type of this packet, resolved for the version of the replay
+    pub fn kind(&self, context: &Context) -> PacketType {
+        PacketType::from_raw(self.packet_type(), context.get_version())
+    }
+
     pub fn time(&'pkt self) -> f32 {
         let mut chunk = &self.inner()[8..];
         chunk.read_f32::<LE>().unwrap()
@@ -47,6 +53,34 @@ impl<'pkt> Packet<'pkt> {
     }
 }
 
+/// Owned version of `Packet`. Used when the packets are not held in a single buffer, for ex. when they are
+/// read incrementally by [PacketReader](crate::PacketReader)
+#[derive(Clone)]
+pub struct OwnedPacket {
+    inner: Vec<u8>,
+    id:    i32,
+}
+
+impl OwnedPacket {
+    pub fn new(id: i32, data: Vec<u8>) -> Self {
+        Self { id, inner: data }
+    }
+
+    pub fn as_packet(&self) -> Packet<'_> {
+        Packet::new(self.id, &self.inner)
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.inner
+    }
+}
+
+impl std::fmt::Debug for OwnedPacket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_packet().fmt(f)
+    }
+}
+
 /// A `PacketStream` is a wrapper around the binary data produced after decompressing the `.wotreplay` file.
 /// It implements the `Iterator` trait which allows to retrieve each `Packet` as we iterate through the binary
 /// stream.
@@ -66,8 +100,19 @@ impl<'a> PacketStream<'a> {
         }
     }
 
+    /// Stream that starts at `position` (byte offset) of `inner`. `count` is the id of the packet at that
+    /// position
+    pub(crate) fn starting_at(inner: &'a [u8], position: usize, count: i32) -> Self {
+        Self {
+            inner,
+            position,
+            count,
+        }
+    }
+
     pub fn reset(&mut self) {
         self.position = 0;
+        self.count = 0;
     }
 }
 
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use crate::packet_parser::{Packet, PacketStream, PacketType};
+use crate::ReplayError;
+
+/// Location and metadata of a packet inside the packet buffer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IndexEntry {
+    /// Offset of the packet from the start of the packet buffer
+    pub offset: usize,
+
+    /// Size of the entire packet, metadata included
+    pub len: usize,
+
+    pub packet_type: PacketType,
+    pub time:        f32,
+
+    /// Entity the packet refers to. `None` for packet types that are not about an entity
+    pub entity_id: Option<i32>,
+}
+
+/// An index over the packet buffer that is built in a single pass. Unlike [PacketStream] which can only go
+/// forward, it allows random access to the packets by time, type and entity.
+/// ## Example
+/// ```
+/// # use wot_replay_parser::*;
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+/// let index = replay.packet_index().unwrap();
+///
+/// // Jump to the 100 second mark
+/// let packet = index.seek_time(100.0).next().unwrap().unwrap();
+/// assert!(packet.time() >= 100.0);
+///
+/// for packet in index.packets_of_type(PacketType::Chat) {
+///     assert_eq!(packet.packet_type(), 0x23);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PacketIndex<'a> {
+    inner:   &'a [u8],
+    entries: Vec<IndexEntry>,
+
+    /// `max_times[i]` is the largest timestamp of packets `0..=i`. Timestamps are not always in order so
+    /// this is what we binary search on
+    max_times: Vec<f32>,
+
+    by_type:   HashMap<PacketType, Vec<usize>>,
+    by_entity: HashMap<i32, Vec<usize>>,
+}
+
+impl<'a> PacketIndex<'a> {
+    /// Index the packets of `packets_buffer`. `version` is the version of the replay and is needed to resolve
+    /// the packet types
+    pub fn new(packets_buffer: &'a [u8], version: [u16; 4]) -> Result<Self, ReplayError> {
+        let mut index = Self {
+            inner:     packets_buffer,
+            entries:   Vec::new(),
+            max_times: Vec::new(),
+            by_type:   HashMap::new(),
+            by_entity: HashMap::new(),
+        };
+
+        let mut offset = 0;
+        for packet in PacketStream::new(packets_buffer) {
+            let packet = packet?;
+            let packet_type = PacketType::from_raw(packet.packet_type(), version);
+            let entity_id = entity_id(&packet, packet_type);
+            let time = packet.time();
+            let position = index.entries.len();
+
+            let max_time = index.max_times.last().map_or(time, |max_time| max_time.max(time));
+            index.max_times.push(max_time);
+
+            index.by_type.entry(packet_type).or_default().push(position);
+            if let Some(entity_id) = entity_id {
+                index.by_entity.entry(entity_id).or_default().push(position);
+            }
+
+            index.entries.push(IndexEntry {
+                offset,
+                len: packet.inner().len(),
+                packet_type,
+                time,
+                entity_id,
+            });
+            offset += packet.inner().len();
+        }
+
+        Ok(index)
+    }
+
+    /// Number of packets in the index
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Packet with the given id, i.e. the `id`th packet of the stream. Same as [Packet::id]
+    pub fn get(&self, id: usize) -> Option<Packet<'a>> {
+        self.entries.get(id).map(|entry| self.packet(id, entry))
+    }
+
+    /// Id of the first packet from the point the battle reaches `time`. Returns [len](PacketIndex::len) if
+    /// no packet is that late
+    pub fn position_of_time(&self, time: f32) -> usize {
+        self.max_times.partition_point(|max_time| *max_time < time)
+    }
+
+    /// A [PacketStream] that starts at the first packet from the point the battle reaches `time`
+    pub fn seek_time(&self, time: f32) -> PacketStream<'a> {
+        let position = self.position_of_time(time);
+        let offset = self
+            .entries
+            .get(position)
+            .map_or(self.inner.len(), |entry| entry.offset);
+
+        PacketStream::starting_at(self.inner, offset, position as i32)
+    }
+
+    /// All the packets of type `packet_type`, in stream order
+    pub fn packets_of_type(&self, packet_type: PacketType) -> impl Iterator<Item = Packet<'a>> + '_ {
+        self.packets_at(self.by_type.get(&packet_type))
+    }
+
+    /// All the packets that refer to the entity `entity_id`, in stream order
+    pub fn packets_for_entity(&self, entity_id: i32) -> impl Iterator<Item = Packet<'a>> + '_ {
+        self.packets_at(self.by_entity.get(&entity_id))
+    }
+
+    fn packets_at<'s>(&'s self, ids: Option<&'s Vec<usize>>) -> impl Iterator<Item = Packet<'a>> + 's {
+        ids.into_iter()
+            .flatten()
+            .map(|id| self.packet(*id, &self.entries[*id]))
+    }
+
+    fn packet(&self, id: usize, entry: &IndexEntry) -> Packet<'a> {
+        Packet::new(id as i32, &self.inner[entry.offset..(entry.offset + entry.len)])
+    }
+}
+
+/// The payload of these packets starts with the id of the entity they refer to
+fn entity_id(packet: &Packet, packet_type: PacketType) -> Option<i32> {
+    use PacketType::*;
+
+    match packet_type {
+        AvatarCreate | CellPlayerCreate | EntityControl | EntityEnter | EntityLeave | EntityCreate
+        | EntityProperty | EntityMethod | Position => {
+            let entity_id = packet.payload().get(..4)?;
+            Some(i32::from_le_bytes(entity_id.try_into().unwrap()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet_parser::METADATA_SIZE;
+
+    const VERSION: [u16; 4] = [1, 16, 1, 0];
+
+    fn packet(packet_type: u32, time: f32, entity_id: i32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend(4u32.to_le_bytes());
+        data.extend(packet_type.to_le_bytes());
+        data.extend(time.to_le_bytes());
+        data.extend(entity_id.to_le_bytes());
+
+        data
+    }
+
+    fn ids<'a>(packets: impl Iterator<Item = Packet<'a>>) -> Vec<i32> {
+        packets.map(|packet| packet.id()).collect()
+    }
+
+    #[test]
+    fn seeks_by_time_and_entity() {
+        // Timestamps are not in order and the end of stream packet has a timestamp of 0
+        let buffer = [
+            packet(0x08, 1.0, 7),
+            packet(0x0A, 3.0, 8),
+            packet(0x08, 2.0, 8),
+            packet(0x23, 5.0, 7),
+            packet(0x07, 5.0, 7),
+            packet(0xFFFFFFFF, 0.0, 0),
+        ]
+        .concat();
+        let index = PacketIndex::new(&buffer, VERSION).unwrap();
+        assert_eq!(index.len(), 6);
+
+        // The battle reaches 2.5 at the second packet even though the third one is earlier
+        assert_eq!(index.position_of_time(0.0), 0);
+        assert_eq!(index.position_of_time(2.5), 1);
+        assert_eq!(index.position_of_time(3.0), 1);
+        assert_eq!(index.position_of_time(4.0), 3);
+        let stream = index.seek_time(2.5).map(|packet| packet.unwrap().id());
+        assert_eq!(stream.collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+        // Chat packets are not about an entity
+        assert_eq!(ids(index.packets_for_entity(7)), [0, 4]);
+        assert_eq!(ids(index.packets_for_entity(8)), [1, 2]);
+        assert_eq!(ids(index.packets_of_type(PacketType::EntityMethod)), [0, 2]);
+        assert_eq!(index.get(3).unwrap().inner(), packet(0x23, 5.0, 7));
+        assert_eq!(index.entries()[3].entity_id, None);
+        assert_eq!(index.entries()[4].offset, 4 * (METADATA_SIZE + 4));
+    }
+
+    #[test]
+    fn lookups_past_the_end() {
+        let buffer = [packet(0x08, 1.0, 7), packet(0x08, 2.0, 7)].concat();
+        let index = PacketIndex::new(&buffer, VERSION).unwrap();
+
+        assert_eq!(index.position_of_time(2.5), index.len());
+        assert!(index.seek_time(2.5).next().is_none());
+        assert!(index.get(2).is_none());
+        assert!(ids(index.packets_for_entity(8)).is_empty());
+        assert!(ids(index.packets_of_type(PacketType::Chat)).is_empty());
+
+        let index = PacketIndex::new(&[], VERSION).unwrap();
+        assert!(index.is_empty());
+        assert_eq!(index.position_of_time(0.0), 0);
+        assert!(index.seek_time(0.0).next().is_none());
+        assert!(index.get(0).is_none());
+        assert!(ids(index.packets_for_entity(7)).is_empty());
+        assert!(ids(index.packets_of_type(PacketType::EntityMethod)).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use serde::Serialize;
+
+/// Last version where the game version packet is `0x14` instead of `0x18`
+const LEGACY_VERSION: [u16; 4] = [0, 9, 13, 0];
+
+/// The type of a packet. The number that identifies a type may change between game versions so use
+/// [Packet::kind](crate::Packet::kind) or [PacketType::from_raw] instead of comparing
+/// [Packet::packet_type](crate::Packet::packet_type) directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[non_exhaustive]
+pub enum PacketType {
+    /// `0x00` Creation of the player's `Avatar` entity
+    AvatarCreate,
+
+    /// `0x01` Cell part of the player's `Avatar` entity
+    CellPlayerCreate,
+
+    /// `0x02` Player gains or loses control of an entity
+    EntityControl,
+
+    /// `0x03` Entity enters the area of interest
+    EntityEnter,
+
+    /// `0x04` Entity leaves the area of interest
+    EntityLeave,
+
+    /// `0x05`
+    EntityCreate,
+
+    /// `0x07`
+    EntityProperty,
+
+    /// `0x08`
+    EntityMethod,
+
+    /// `0x0A`
+    Position,
+
+    /// `0x0D` Key-value data attached to the space (i.e the map)
+    SpaceData,
+
+    /// `0x0F` Path of the geometry of the map that is loaded, for ex. `spaces/45_north_america`
+    SpaceGeometry,
+
+    /// `0x11` Pickled battle results sent at the end of the battle
+    BattleResults,
+
+    /// `0x16` The arena entered a new period (see `wot_types::ArenaPeriod`)
+    ArenaPeriod,
+
+    /// `0x17` Length of the current arena period in seconds
+    ArenaPeriodLength,
+
+    /// `0x14` (if the replay is from, or before patch 0.9.13), `0x18` otherwise
+    GameVersion,
+
+    /// `0x1B` Camera mode of the player, for ex. `arcade` or `sniper`
+    CameraMode,
+
+    /// `0x23`
+    Chat,
+
+    /// `0x3D`
+    CryptoKey,
+
+    /// `0xFFFFFFFF` Last packet of the replay
+    EndOfStream,
+
+    /// Packet type that we do not know about yet
+    Unknown(u32),
+}
+
+impl PacketType {
+    /// Resolve the packet type number found in a replay of `version`
+    pub fn from_raw(raw: u32, version: [u16; 4]) -> Self {
+        use PacketType::*;
+
+        match raw {
+            0x00 => AvatarCreate,
+            0x01 => CellPlayerCreate,
+            0x02 => EntityControl,
+            0x03 => EntityEnter,
+            0x04 => EntityLeave,
+            0x05 => EntityCreate,
+            0x07 => EntityProperty,
+            0x08 => EntityMethod,
+            0x0A => Position,
+            0x0D => SpaceData,
+            0x0F => SpaceGeometry,
+            0x11 => BattleResults,
+            0x14 if version <= LEGACY_VERSION => GameVersion,
+            0x16 => ArenaPeriod,
+            0x17 => ArenaPeriodLength,
+            0x18 if version > LEGACY_VERSION => GameVersion,
+            0x1B => CameraMode,
+            0x23 => Chat,
+            0x3D => CryptoKey,
+            0xFFFFFFFF => EndOfStream,
+            raw => Unknown(raw),
+        }
+    }
+
+    /// Inverse of [from_raw](PacketType::from_raw)
+    pub fn to_raw(self, version: [u16; 4]) -> u32 {
+        use PacketType::*;
+
+        match self {
+            AvatarCreate => 0x00,
+            CellPlayerCreate => 0x01,
+            EntityControl => 0x02,
+            EntityEnter => 0x03,
+            EntityLeave => 0x04,
+            EntityCreate => 0x05,
+            EntityProperty => 0x07,
+            EntityMethod => 0x08,
+            Position => 0x0A,
+            SpaceData => 0x0D,
+            SpaceGeometry => 0x0F,
+            BattleResults => 0x11,
+            GameVersion if version <= LEGACY_VERSION => 0x14,
+            GameVersion => 0x18,
+            ArenaPeriod => 0x16,
+            ArenaPeriodLength => 0x17,
+            CameraMode => 0x1B,
+            Chat => 0x23,
+            CryptoKey => 0x3D,
+            EndOfStream => 0xFFFFFFFF,
+            Unknown(raw) => raw,
+        }
+    }
+}
+
+impl std::fmt::Display for PacketType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketType::Unknown(raw) => write!(f, "Unknown(0x{raw:02X})"),
+            packet_type => write!(f, "{packet_type:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Last version with the legacy numbers and the first one after it
+    const LEGACY: [u16; 4] = LEGACY_VERSION;
+    const CURRENT: [u16; 4] = [0, 9, 14, 0];
+
+    #[test]
+    fn remaps_game_version_at_version_switch() {
+        assert_eq!(PacketType::from_raw(0x14, LEGACY), PacketType::GameVersion);
+        assert_eq!(PacketType::from_raw(0x18, LEGACY), PacketType::Unknown(0x18));
+        assert_eq!(PacketType::GameVersion.to_raw(LEGACY), 0x14);
+
+        assert_eq!(PacketType::from_raw(0x18, CURRENT), PacketType::GameVersion);
+        assert_eq!(PacketType::from_raw(0x14, CURRENT), PacketType::Unknown(0x14));
+        assert_eq!(PacketType::GameVersion.to_raw(CURRENT), 0x18);
+    }
+
+    #[test]
+    fn round_trips_raw_types() {
+        for version in [[0, 9, 12, 0], LEGACY, CURRENT, [1, 16, 1, 0]] {
+            for raw in (0x00..=0x40).chain([0xFFFFFFFF]) {
+                let packet_type = PacketType::from_raw(raw, version);
+
+                assert_eq!(packet_type.to_raw(version), raw, "{packet_type} in {version:?}");
+            }
+        }
+    }
+}
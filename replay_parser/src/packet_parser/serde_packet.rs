@@ -71,7 +71,7 @@ where
     Ok(t)
 }
 
-pub fn _from_slice_prim_unchecked<'a, T>(
+pub fn from_slice_prim_unchecked<'a, T>(
     input: &'a [u8], de_version: [u16; 4],
 ) -> Result<(&'a [u8], T), PacketError>
 where
@@ -593,3 +593,17 @@ pub fn parse_byte_array(input: &[u8]) -> Result<(&[u8], &[u8]), PacketError> {
         Ok((remaining, bytes_array))
     }
 }
+
+/// Inverse of [parse_byte_array]. Append the length prefix followed by `bytes_array` to `output`
+pub fn write_byte_array(bytes_array: &[u8], output: &mut Vec<u8>) {
+    let len = bytes_array.len();
+
+    if len < u8::MAX as usize {
+        output.push(len as u8);
+    } else {
+        output.push(u8::MAX);
+        output.extend_from_slice(&(len as u32).to_le_bytes()[..3]);
+    }
+
+    output.extend_from_slice(bytes_array);
+}
@@ -6,3 +6,32 @@ pub struct Vector3 {
     pub z: f32,
     pub y: f32,
 }
+
+/// Value of the `PYTHON` type in the `.def` files. It is sent as a pickle inside a byte array. `T` is
+/// deserialized from the unpickled value, use [WotValue](crate::wot_types::WotValue) to keep it as is
+#[derive(Serialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct Pickled<T>(pub T);
+
+impl<'de, T: serde::de::DeserializeOwned> Deserialize<'de> for Pickled<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_pickle(deserializer).map(Pickled)
+    }
+}
+
+/// Use with `#[serde(deserialize_with = "deserialize_pickle")]` on fields that are pickles
+pub(crate) fn deserialize_pickle<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    use serde::de::Error;
+
+    let bytes: &[u8] = Deserialize::deserialize(deserializer)?;
+    let pickle = crate::utils::unpickle(bytes).map_err(D::Error::custom)?;
+
+    serde_pickle::from_value(pickle).map_err(|err| D::Error::custom(err.to_string()))
+}
@@ -0,0 +1,187 @@
+//! Salvage the packets of replays whose binary portion is truncated or corrupted, for ex. when the game
+//! crashed in the middle of the battle.
+
+use std::io::{ErrorKind, Read};
+
+use crate::replay_reader::BinaryReader;
+use crate::PacketStream;
+
+/// Outcome of parsing a replay with [parse_recover](crate::ReplayParser::parse_recover)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryReport {
+    /// Size of the recovered packet stream. Only complete packets are counted
+    pub bytes_recovered: usize,
+
+    /// Number of complete packets recovered
+    pub packets_recovered: usize,
+
+    /// Largest timestamp of the recovered packets, i.e. how far into the battle the replay goes. `None` if
+    /// no packet was recovered
+    pub last_packet_time: Option<f32>,
+
+    pub reason: RecoveryReason,
+}
+
+impl RecoveryReport {
+    /// Whether the whole packet stream was recovered
+    pub fn is_complete(&self) -> bool {
+        self.reason == RecoveryReason::Complete
+    }
+}
+
+/// Why the packet stream stops where it does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryReason {
+    /// Nothing is missing
+    Complete,
+
+    /// The compressed data ends before the end of the zlib stream. This is what the replays of crashed
+    /// clients look like
+    TruncatedStream,
+
+    /// The compressed data is invalid from this point onwards. Corruption is not always detected right away
+    /// so the last packets may contain garbage
+    CorruptedStream(String),
+
+    /// The zlib stream is intact but the last packet is cut off
+    IncompletePacket,
+}
+
+/// Decrypt and inflate as much of the binary portion as possible. The returned buffer is truncated to the
+/// end of the last complete packet
+pub(crate) fn recover_packets(binary: &[u8]) -> (Vec<u8>, RecoveryReport) {
+    let mut packets_buffer = Vec::new();
+
+    // Bytes inflated before an error are still appended to the buffer
+    let mut reason = match BinaryReader::new(binary).read_to_end(&mut packets_buffer) {
+        Ok(_) => RecoveryReason::Complete,
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => RecoveryReason::TruncatedStream,
+        Err(err) => RecoveryReason::CorruptedStream(err.to_string()),
+    };
+
+    let mut bytes_recovered = 0;
+    let mut packets_recovered = 0;
+    let mut last_packet_time = None;
+
+    for packet in PacketStream::new(&packets_buffer) {
+        match packet {
+            Ok(packet) => {
+                bytes_recovered += packet.inner().len();
+                packets_recovered += 1;
+                // Timestamps are not always in order and the end of stream packet has a timestamp of 0
+                last_packet_time =
+                    Some(last_packet_time.map_or(packet.time(), |time: f32| time.max(packet.time())));
+            }
+            Err(_) => {
+                if reason == RecoveryReason::Complete {
+                    reason = RecoveryReason::IncompletePacket;
+                }
+                break;
+            }
+        }
+    }
+
+    packets_buffer.truncate(bytes_recovered);
+
+    let report = RecoveryReport {
+        bytes_recovered,
+        packets_recovered,
+        last_packet_time,
+        reason,
+    };
+
+    (packets_buffer, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet_parser::METADATA_SIZE;
+    use crate::{ReplayParser, ReplayWriter};
+
+    /// Packet of type `0x0A` with a payload of `size` bytes
+    fn packet(time: f32, size: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend(size.to_le_bytes());
+        data.extend(0x0Au32.to_le_bytes());
+        data.extend(time.to_le_bytes());
+        data.resize(METADATA_SIZE + size as usize, 0xAB);
+
+        data
+    }
+
+    fn recover(packets_buffer: Vec<u8>) -> (ReplayParser, RecoveryReport) {
+        let input = ReplayWriter::new()
+            .push_json(&serde_json::json!({ "playerName": "_Dacite" }))
+            .unwrap()
+            .set_packets_buffer(packets_buffer)
+            .to_bytes()
+            .unwrap();
+
+        ReplayParser::parse_recover(input).unwrap()
+    }
+
+    #[test]
+    fn recovers_complete_packets_before_truncated_packet() {
+        let packets = [packet(0.5, 20), packet(2.0, 8), packet(1.5, 0)];
+        // Only the header and 10 bytes of the payload of the last packet are there
+        let mut packets_buffer = packets.concat();
+        packets_buffer.extend(&packet(3.0, 30)[..METADATA_SIZE + 10]);
+
+        let (replay, report) = recover(packets_buffer);
+        assert_eq!(report.reason, RecoveryReason::IncompletePacket);
+        assert!(!report.is_complete());
+        assert_eq!(report.packets_recovered, 3);
+        assert_eq!(report.bytes_recovered, packets.concat().len());
+        assert_eq!(report.last_packet_time, Some(2.0));
+        assert_eq!(replay.packets_buffer().unwrap(), packets.concat());
+
+        let (_, report) = recover(packets.concat());
+        assert!(report.is_complete());
+        assert_eq!(report.packets_recovered, 3);
+    }
+
+    #[test]
+    fn stops_at_corrupted_length() {
+        let mut packets = [packet(0.5, 20), packet(1.0, 8), packet(1.5, 4), packet(2.0, 4)];
+        packets[2][..4].copy_from_slice(&0xFFFF_0000u32.to_le_bytes());
+
+        let (replay, report) = recover(packets.concat());
+        assert_eq!(report.reason, RecoveryReason::IncompletePacket);
+        assert_eq!(report.packets_recovered, 2);
+        assert_eq!(report.last_packet_time, Some(1.0));
+        assert_eq!(replay.packets_buffer().unwrap(), packets[..2].concat());
+    }
+
+    #[test]
+    fn recovers_packets_before_corrupted_stream() {
+        let mut input = std::fs::read("input_files/example.wotreplay").unwrap();
+        let original = ReplayParser::parse(input.clone()).unwrap();
+        let packet_count = original.packet_stream().count();
+
+        // Most of the file is the binary portion, so this is in the middle of the compressed packets
+        let middle = input.len() / 2;
+        input[middle] ^= 0xFF;
+
+        let (replay, report) = ReplayParser::parse_recover(input).unwrap();
+        assert!(
+            matches!(report.reason, RecoveryReason::CorruptedStream(_)),
+            "{:?}",
+            report.reason
+        );
+        assert!(report.packets_recovered > packet_count / 4);
+        assert!(report.packets_recovered < packet_count);
+        assert_eq!(replay.packet_stream().count(), report.packets_recovered);
+        assert_eq!(replay.packets_buffer().unwrap().len(), report.bytes_recovered);
+
+        // The last packet may be garbage from before the corruption was detected
+        let intact = original
+            .packet_stream()
+            .zip(replay.packet_stream())
+            .take_while(|(original, recovered)| {
+                original.as_ref().unwrap().inner() == recovered.as_ref().unwrap().inner()
+            })
+            .count();
+        assert!(intact >= report.packets_recovered - 1);
+    }
+}
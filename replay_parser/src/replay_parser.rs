@@ -1,8 +1,6 @@
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use blowfish::cipher::KeyInit;
-use blowfish::{cipher::BlockDecrypt, Blowfish};
-use byteorder::{ReadBytesExt, BE, LE};
 use miniz_oxide::inflate::decompress_to_vec_zlib;
 use nom::{
     bytes::complete::take,
@@ -12,9 +10,15 @@ use nom::{
 use serde_json::Value as JsonVal;
 use wot_types::ArenaBonusType;
 
+use crate::crypto::{decrypt, xor_decrypted};
+use crate::events::{ArenaUpdateData, AvatarMethods, EntityMethod, EntityMethodEvent, VehicleMethods};
+use crate::recovery::{recover_packets, RecoveryReport};
 use crate::utils::as_i64;
-use crate::{replay_errors, Context};
-use crate::{BattleContext, BattleEvent, EventStream, PacketStream, ReplayError};
+use crate::{replay_errors, ArenaPeriodSpan, Context};
+use crate::{
+    BattleContext, BattleEvent, DefinitionRegistry, EventStream, Packet, PacketIndex, PacketStream,
+    ReplayError, ReplayWriter,
+};
 /// Parse a wotreplay from file. Only deals with that wotreplay. If you need to parse multiple replays, create
 /// multiple instances of `ReplayParser`.
 /// ## Example 1 - Print Replay Events
@@ -84,6 +88,39 @@ impl ReplayParser {
         })
     }
 
+    /// Same as [parse](ReplayParser::parse) except a truncated or corrupted binary portion is not an error.
+    /// As much of the packet stream as possible is recovered and the packets stop at the last complete one.
+    /// ## Example
+    /// ```
+    /// # use wot_replay_parser::*;
+    /// let mut input = std::fs::read("input_files/example.wotreplay").unwrap();
+    /// input.truncate(input.len() / 2);
+    ///
+    /// let (replay, report) = ReplayParser::parse_recover(input).unwrap();
+    /// assert_eq!(report.reason, RecoveryReason::TruncatedStream);
+    /// assert_eq!(replay.packet_stream().count(), report.packets_recovered);
+    /// ```
+    pub fn parse_recover(input: Vec<u8>) -> Result<(Self, RecoveryReport), ReplayError> {
+        let (json_slices, binary_slice) = split_replay_data(&input)?;
+        let (packets_buffer, report) = recover_packets(binary_slice);
+
+        let json = parse_json_portion(json_slices)?;
+
+        let parser = ReplayParser {
+            data: input,
+            json,
+            packets_buffer: Some(packets_buffer),
+        };
+
+        Ok((parser, report))
+    }
+
+    pub fn parse_file_recover<T: AsRef<Path>>(path: T) -> Result<(Self, RecoveryReport), ReplayError> {
+        let input = std::fs::read(path)?;
+
+        Self::parse_recover(input)
+    }
+
     /// Only parse the JSON portion of the replay. Use this if the packets portion is not needed because
     /// parsing the binary into packets is quite expensive.
     pub fn parse_json(input: Vec<u8>) -> Result<Self, ReplayError> {
@@ -110,6 +147,13 @@ impl ReplayParser {
         Ok(())
     }
 
+    /// JSON portion of this wotreplay as it appears in the file (i.e not parsed)
+    pub fn raw_json(&self) -> Result<Vec<&[u8]>, ReplayError> {
+        let (json_slices, _binary_slice) = split_replay_data(&self.data)?;
+
+        Ok(json_slices)
+    }
+
     /// Get JSON values of this wotreplay
     pub fn replay_json(&self) -> &[JsonVal] {
         &self.json
@@ -139,15 +183,26 @@ impl ReplayParser {
         }
     }
 
-    pub fn battle_start_time(&self) -> f32 {
-        for packet in self.packet_stream() {
-            let packet = packet.unwrap();
-            if packet.packet_type() == 0x16 && packet.payload().read_u32::<LE>().unwrap() == 3 {
-                return packet.time();
-            }
-        }
+    /// Time at which the battle started (i.e. the end of the countdown), in the same time as
+    /// [Packet::time](crate::Packet::time)
+    pub fn battle_start_time(&self) -> Result<f32, ReplayError> {
+        crate::battle_context::get_battle_start_time(self.packet_stream(), self.version()?)
+    }
 
-        -1.0
+    /// Periods of the arena (loading, countdown, battle and post-battle) with the time they started and
+    /// ended
+    /// ## Example
+    /// ```
+    /// # use wot_replay_parser::*;
+    /// # use wot_replay_parser::wot_types::ArenaPeriod;
+    /// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+    ///
+    /// let periods = replay.arena_periods().unwrap();
+    /// let battle = periods.iter().find(|span| span.period == ArenaPeriod::Battle).unwrap();
+    /// assert_eq!(Some(battle.start), replay.battle_start_time().ok());
+    /// ```
+    pub fn arena_periods(&self) -> Result<Vec<ArenaPeriodSpan>, ReplayError> {
+        crate::battle_context::get_arena_periods(self.packet_stream(), self.version()?)
     }
 
     /// An iterator over the packets in the replay. Must call `load_packets` if its not already loaded. The
@@ -160,15 +215,40 @@ impl ReplayParser {
         )
     }
 
-    /// An iterator over the events in the replay. This is a layer of abstraction over `PacketStream`. Each
-    /// packet is converted into the event it represents. It is important for
-    pub fn event_stream(&self) -> Result<EventStream, ReplayError> {
-        let packet_stream = self.packet_stream();
+    /// Index the packets of the replay for random access by time, type and entity. Must call `load_packets`
+    /// if its not already loaded
+    pub fn packet_index(&self) -> Result<PacketIndex<'_>, ReplayError> {
         let version = self
             .parse_replay_version()
             .ok_or_else(|| ReplayError::ReplayJsonFormatError("failed to parse replay version".into()))?;
 
-        Ok(EventStream::new(packet_stream, version))
+        PacketIndex::new(self.packets_buffer()?, version)
+    }
+
+    /// An iterator over the events in the replay. This is a layer of abstraction over `PacketStream`. Each
+    /// packet is converted into the event it represents. It is important for
+    pub fn event_stream(&self) -> Result<EventStream, ReplayError> {
+        let version = self.version()?;
+
+        // Players are only used to find the sender of chat messages, a replay without them is still parsed
+        let players = self
+            .replay_json_start()
+            .ok()
+            .and_then(|json| crate::utils::get_player_list(json).ok())
+            .unwrap_or_default();
+        let context = self.new_context(version, players, None);
+
+        Ok(EventStream::with_context(self.packet_stream(), context))
+    }
+
+    /// Same as [event_stream](ReplayParser::event_stream) but packets are parsed with the definitions of
+    /// `registry` if it has them for the version of this replay
+    pub fn event_stream_with_registry(
+        &self, registry: &DefinitionRegistry,
+    ) -> Result<EventStream<'_>, ReplayError> {
+        let context = self.context_with_registry(registry)?;
+
+        Ok(EventStream::with_context(self.packet_stream(), context))
     }
 
     pub fn battle_context(&self) -> BattleContext {
@@ -198,33 +278,14 @@ impl ReplayParser {
 
     /// Parse the replay version. Return `None` if parsing fails
     pub fn parse_replay_version(&self) -> Option<[u16; 4]> {
-        // TODO: MAKE FASTER!
         let json = self.replay_json_start().ok()?;
 
-        // World\u{a0}of\u{a0}Tanks v.1.9.1.1 #378
-        // "坦克世界 v.0.9.10 #77"
-        let version = json.pointer("/clientVersionFromXml")?.as_str()?;
-
-        // ["World\u{a0}of\u{a0}Tanks", "v.1.9.1.1", "#378"]
-        // ["坦克世界", "v.0.9.10", "#77"]
-        let version: Vec<_> = version.split(' ').collect();
-
-        // "v.0.9.10"
-        // "v.1.9.1.1"
-        let version = version.get(1)?;
-
-        let mut version_iter = version.split('.');
-
-        version_iter.next(); // skip "v"
-
-        let major: u16 = version_iter.next()?.parse().ok()?;
-        let minor: u16 = version_iter.next()?.parse().ok()?;
-        let patch: u16 = version_iter.next()?.parse().ok()?;
-        let extra: u16 = version_iter.next().unwrap_or("0").parse().ok()?;
-
-        let version_array = [major, minor, patch, extra];
+        crate::utils::parse_version(json)
+    }
 
-        Some(version_array)
+    fn version(&self) -> Result<[u16; 4], ReplayError> {
+        self.parse_replay_version()
+            .ok_or_else(|| ReplayError::ReplayJsonFormatError("cannot parse replay version".into()))
     }
 
     /// Parse the Arena Unique ID of the battle in the replay.
@@ -261,17 +322,148 @@ impl ReplayParser {
         })
     }
 
+    /// Create a replay that only contains the part of the battle between `start` and `end` (in seconds, same
+    /// as [Packet::time](crate::Packet::time)). To keep the replay playable, the following packets from
+    /// before `start` are also kept:
+    /// - `AvatarCreate`, `EntityCreate`, `EntityEnter`, `EntityLeave`, `EntityControl`, `ArenaPeriod`,
+    ///   `ArenaPeriodLength`, `GameVersion` and `CryptoKey`
+    /// - `UpdateArena`, for ex. the vehicle list, the destroyed vehicles, the statistics and the arena
+    ///   period, except the radar and combat equipment effects
+    /// - `OnHealthChanged` of the vehicles
+    /// - packets we do not parse yet that are part of the initial world state (timestamp `0`), for ex. the
+    ///   space data that loads the map
+    /// - packets that fail to parse, since we cannot tell whether the state depends on them
+    /// - the last position of each entity and the last value of each entity property
+    ///
+    /// Everything after `end` is dropped. Timestamps are not changed so the battle timer shown by the game
+    /// client stays correct.
+    /// ## Example
+    /// ```
+    /// # use wot_replay_parser::*;
+    /// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+    ///
+    /// let clip = replay.clip(100.0, 130.0).unwrap();
+    /// assert!(clip.packets_buffer().len() < replay.packets_buffer().unwrap().len());
+    /// ```
+    pub fn clip(&self, start: f32, end: f32) -> Result<ReplayWriter, ReplayError> {
+        let mut context = self.context()?;
+
+        // Packets to keep. Positions and properties from before `start` are replaced by later ones
+        let mut kept: Vec<Option<Packet>> = Vec::new();
+        let mut latest_state: HashMap<(u32, Vec<u8>), usize> = HashMap::new();
+
+        for packet in self.packet_stream() {
+            let packet = packet?;
+
+            // Every packet is parsed so that the context keeps track of the entities
+            let event = BattleEvent::parse(&packet, &mut context);
+            let time = packet.time();
+
+            if (start..=end).contains(&time) {
+                kept.push(Some(packet));
+            } else if time < start {
+                if let Some(key) = state_key(&packet, &event) {
+                    if let Some(index) = latest_state.insert(key, kept.len()) {
+                        kept[index] = None;
+                    }
+                    kept.push(Some(packet));
+                } else if is_setup_packet(&packet, &event) {
+                    kept.push(Some(packet));
+                }
+            }
+        }
+
+        let mut writer = ReplayWriter::from_parser(self)?;
+        writer.clear_packets().extend_packets(kept.into_iter().flatten());
+
+        Ok(writer)
+    }
+
     pub fn context(&self) -> Result<Context, ReplayError> {
-        let version = self
-            .parse_replay_version()
-            .ok_or_else(|| ReplayError::ReplayJsonFormatError("cannot parse replay version".into()))?;
+        let version = self.version()?;
+
+        let player_list = crate::utils::get_player_list(self.replay_json_start()?)?;
+
+        Ok(self.new_context(version, player_list, None))
+    }
+
+    /// Same as [context](ReplayParser::context) but with the definitions of `registry` if it has them for
+    /// the version of this replay
+    pub fn context_with_registry(&self, registry: &DefinitionRegistry) -> Result<Context, ReplayError> {
+        let version = self.version()?;
+
+        let player_list = crate::utils::get_player_list(self.replay_json_start()?)?;
 
-        let player_list = crate::utils::get_player_list(self)?;
+        Ok(self.new_context(version, player_list, Some(registry)))
+    }
+
+    /// Context shared by every way of parsing the packets, so that they all know the same players and teams
+    fn new_context(
+        &self, version: [u16; 4], players: HashMap<i32, String>, registry: Option<&DefinitionRegistry>,
+    ) -> Context {
+        let mut context = match registry {
+            Some(registry) => Context::with_registry(version, players, registry),
+            None => Context::new(version, players),
+        };
+        context.set_enemies(self.enemy_list());
+
+        context
+    }
+
+    /// Like the players, the teams are only used for chat messages so a replay without them is still parsed
+    fn enemy_list(&self) -> HashSet<i32> {
+        self.replay_json_start()
+            .ok()
+            .and_then(|json| crate::utils::get_enemy_list(json).ok())
+            .unwrap_or_default()
+    }
+}
 
-        Ok(Context::new(version, player_list))
+/// Packets from before the start of a clip that hold state the rest of the battle depends on
+fn is_setup_packet(packet: &Packet, event: &Result<BattleEvent, ReplayError>) -> bool {
+    match event {
+        Ok(
+            BattleEvent::AvatarCreate(_)
+            | BattleEvent::EntityCreate(_)
+            | BattleEvent::EntityEnter(_)
+            | BattleEvent::EntityLeave(_)
+            | BattleEvent::EntityControl(_)
+            | BattleEvent::ArenaPeriod(_)
+            | BattleEvent::ArenaPeriodLength(_)
+            | BattleEvent::GameVersion(_)
+            | BattleEvent::CryptoKey(_),
+        ) => true,
+        Ok(BattleEvent::EntityMethod(EntityMethodEvent {
+            method: EntityMethod::Avatar(AvatarMethods::UpdateArena(update)),
+            ..
+        })) => !matches!(
+            update.update_data,
+            ArenaUpdateData::RadarInfoReceived(_) | ArenaUpdateData::CombatEquipmentUsed(_)
+        ),
+        Ok(BattleEvent::EntityMethod(EntityMethodEvent {
+            method: EntityMethod::Vehicle(VehicleMethods::OnHealthChanged(_)),
+            ..
+        })) => true,
+        Ok(BattleEvent::Unimplemented { .. }) => packet.time() == 0.0,
+        Err(_) => true,
+        _ => false,
     }
 }
 
+/// Positions and entity properties are overwritten by later packets of the same entity (and property). Only
+/// the last one before the start of a clip is needed
+fn state_key(packet: &Packet, event: &Result<BattleEvent, ReplayError>) -> Option<(u32, Vec<u8>)> {
+    let key_len = match event {
+        // Entity ID
+        Ok(BattleEvent::Position(_)) => 4,
+        // Entity ID and property ID
+        Ok(BattleEvent::EntityProperty(_)) => 8,
+        _ => return None,
+    };
+
+    Some((packet.packet_type(), packet.payload().get(..key_len)?.to_vec()))
+}
+
 fn parse_binary(input: &[u8]) -> Result<Vec<u8>, ReplayError> {
     let (_json_slices, binary_slice) = split_replay_data(input)?;
 
@@ -287,19 +479,23 @@ fn parse_binary(input: &[u8]) -> Result<Vec<u8>, ReplayError> {
 fn parse_json_portion(json_slices: Vec<&[u8]>) -> Result<Vec<serde_json::Value>, ReplayError> {
     let mut json_values = Vec::new();
     for slice in json_slices {
-        let json_value = serde_json::from_slice(slice).or_else(|_| {
-            let slice_as_string = String::from_utf8_lossy(slice);
-            let fixed = replay_errors::fix_json_bugs(slice_as_string);
-
-            serde_json::from_str(&fixed)
-        })?;
-
-        json_values.push(json_value);
+        json_values.push(parse_json_slice(slice)?);
     }
 
     Ok(json_values)
 }
 
+pub(crate) fn parse_json_slice(slice: &[u8]) -> Result<serde_json::Value, ReplayError> {
+    let json_value = serde_json::from_slice(slice).or_else(|_| {
+        let slice_as_string = String::from_utf8_lossy(slice);
+        let fixed = replay_errors::fix_json_bugs(slice_as_string);
+
+        serde_json::from_str(&fixed)
+    })?;
+
+    Ok(json_value)
+}
+
 /// Return the JSON part and Binary part of the `.wotreplay` file as a tuple
 fn split_replay_data(input: &[u8]) -> Result<(Vec<&[u8]>, &[u8]), ReplayError> {
     let (remaining, _magic_num) = take(4_usize)(input)?;
@@ -324,29 +520,123 @@ fn decrypt_and_decompress_binary(binary: &[u8]) -> Result<Vec<u8>, ReplayError>
     Ok(decompressed)
 }
 
-fn decrypt(input_blocks: &[u8]) -> Result<Vec<u8>, ReplayError> {
-    // Init blowfish cipher
-    let mut wot_blowfish_key = [0; 16];
-    hex::decode_to_slice("DE72BEA0DE04BEB1DEFEBEEFDEADBEEF", &mut wot_blowfish_key).unwrap();
-    let bf: Blowfish<BE> = Blowfish::new_from_slice(&wot_blowfish_key).unwrap();
+#[cfg(test)]
+mod tests {
+    use wot_types::ArenaPeriod;
+
+    use super::*;
+    use crate::events::Vector3;
+    use crate::{ArenaState, BaseState, LiveEntity};
+
+    /// State that a clip must reproduce at its start
+    #[derive(Debug, PartialEq)]
+    struct State {
+        entities:  HashMap<i32, LiveEntity>,
+        period:    Option<ArenaPeriod>,
+        positions: HashMap<i32, [f32; 3]>,
+
+        /// Destroyed vehicles and their killer
+        dead:   HashMap<i32, Option<i32>>,
+        health: HashMap<i32, Option<i32>>,
+        bases:  Vec<BaseState>,
+    }
 
-    let output_len = input_blocks.len() + (input_blocks.len() % 8);
-    let mut output_blocks = input_blocks.to_vec();
-    output_blocks.resize(output_len, 0);
+    /// Live entities, arena period, last position of each entity and state of the arena after the packets
+    /// until `time`
+    fn state_at(replay: &ReplayParser, time: f32) -> State {
+        let (arena, errors) = ArenaState::from_replay(replay).unwrap();
+        assert!(errors.is_empty());
+        let arena = arena.state_at(time);
 
-    for i in (0..output_blocks.len()).step_by(8) {
-        let block = &mut output_blocks[i..(i + 8)];
-        bf.decrypt_block(block.into());
-    }
+        let mut context = replay.context().unwrap();
+        let mut period = None;
+        let mut positions = HashMap::new();
 
-    Ok(output_blocks)
-}
+        for packet in replay.packet_stream() {
+            let packet = packet.unwrap();
+            if packet.time() > time {
+                break;
+            }
 
-#[inline]
-fn xor_decrypted(mut decrypted: Vec<u8>) -> Vec<u8> {
-    for i in 8..decrypted.len() {
-        decrypted[i] ^= decrypted[i - 8];
+            match BattleEvent::parse(&packet, &mut context) {
+                Ok(BattleEvent::ArenaPeriod(event)) => period = Some(event.period),
+                Ok(BattleEvent::EntityMethod(EntityMethodEvent {
+                    method: EntityMethod::Avatar(AvatarMethods::UpdateArena(update)),
+                    ..
+                })) => {
+                    if let ArenaUpdateData::Period(update) = update.update_data {
+                        period = Some(update.period);
+                    }
+                }
+                Ok(BattleEvent::Position(position)) => {
+                    let Vector3 { x, y, z } = position.position;
+                    positions.insert(position.entity_id, [x, y, z]);
+                }
+                _ => {}
+            }
+        }
+
+        State {
+            entities: context.live_entities().clone(),
+            period,
+            positions,
+            dead: arena
+                .vehicles()
+                .filter(|vehicle| !vehicle.alive)
+                .map(|vehicle| (vehicle.vehicle_id, vehicle.killer_id))
+                .collect(),
+            health: arena
+                .vehicles()
+                .map(|vehicle| (vehicle.vehicle_id, vehicle.health))
+                .collect(),
+            bases: arena.bases().cloned().collect(),
+        }
     }
 
-    decrypted
+    #[test]
+    fn context_knows_the_enemies() {
+        let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+        let mut context = replay.context().unwrap();
+
+        let enemies = crate::utils::get_enemy_list(replay.replay_json_start().unwrap()).unwrap();
+        assert!(!enemies.is_empty());
+        assert!(enemies.iter().all(|&enemy| context.is_enemy(enemy)));
+
+        // A message of an enemy in the color of the team chat is on the chat of both teams
+        let enemy_id = *enemies.iter().min().unwrap();
+        let player = context.find_player(enemy_id).unwrap();
+        let name = player.split(", ").next().unwrap();
+        let raw =
+            format!("<font color='#80D63A'>{name} (Tank)&nbsp;: </font><font color='#80D63A'>hi</font>");
+
+        let mut data = Vec::new();
+        data.extend((raw.len() as u32 + 4).to_le_bytes());
+        data.extend(0x23u32.to_le_bytes());
+        data.extend(10.0f32.to_le_bytes());
+        data.extend((raw.len() as u32).to_le_bytes());
+        data.extend(raw.as_bytes());
+
+        let Ok(BattleEvent::Chat(message)) = BattleEvent::parse(&Packet::new(0, &data), &mut context) else {
+            panic!("not a chat message");
+        };
+        assert_eq!(message.sender_id, Some(enemy_id));
+        assert_eq!(message.channel, crate::events::ChatChannel::All);
+    }
+
+    #[test]
+    fn clip_keeps_state_at_start() {
+        let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+        // Vehicles were destroyed and a base is being captured before the start
+        let clip = replay.clip(200.0, 230.0).unwrap();
+        let clip = ReplayParser::parse(clip.to_bytes().unwrap()).unwrap();
+
+        let state = state_at(&replay, 200.0);
+        assert!(!state.entities.is_empty());
+        assert!(state.period.is_some());
+        assert!(!state.positions.is_empty());
+        assert_eq!(state.dead.len(), 3);
+        assert!(state.bases.iter().any(|base| base.points > 0));
+
+        assert_eq!(state_at(&clip, 200.0), state);
+    }
 }
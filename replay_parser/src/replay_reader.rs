@@ -0,0 +1,338 @@
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use byteorder::{ReadBytesExt, LE};
+use miniz_oxide::inflate::stream::{inflate, InflateState};
+use miniz_oxide::{DataFormat, MZError, MZFlush, MZStatus};
+use serde_json::Value as JsonVal;
+
+use crate::crypto::BlockDecryptor;
+use crate::packet_parser::{OwnedPacket, METADATA_SIZE};
+use crate::{Context, ReplayError};
+
+/// Size of the chunks of encrypted data that is decrypted and inflated at a time
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Streaming counterpart of [ReplayParser](crate::ReplayParser). Only the location of each section of the
+/// `.wotreplay` is read upfront. JSON blocks are read on demand and the binary portion is decrypted and
+/// inflated incrementally as packets are requested, so memory usage does not grow with the size of the
+/// replay.
+/// ## Example
+/// ```
+/// # use wot_replay_parser::*;
+/// let mut reader = ReplayReader::open("input_files/example.wotreplay").unwrap();
+/// let mut context = reader.context().unwrap();
+///
+/// for packet in reader.packets().unwrap() {
+///     let packet = packet.unwrap();
+///     let event = BattleEvent::parse(&packet.as_packet(), &mut context).unwrap();
+/// }
+/// ```
+pub struct ReplayReader<R> {
+    inner: R,
+
+    /// Offset and length of each JSON block
+    json_blocks: Vec<(u64, u32)>,
+
+    /// Offset of the encrypted binary portion
+    binary_offset: u64,
+
+    /// Size of the packet buffer once the binary portion is inflated
+    decompressed_size: u32,
+}
+
+impl ReplayReader<BufReader<File>> {
+    pub fn open<T: AsRef<Path>>(path: T) -> Result<Self, ReplayError> {
+        let file = File::open(path)?;
+
+        Self::new(BufReader::new(file))
+    }
+}
+
+impl<R: Read + Seek> ReplayReader<R> {
+    /// Read the header of the `.wotreplay`. This skips over the JSON blocks without parsing them
+    pub fn new(mut inner: R) -> Result<Self, ReplayError> {
+        let _magic_num = inner.read_u32::<LE>()?;
+
+        let json_count = inner.read_u32::<LE>()?;
+        let mut json_blocks = Vec::with_capacity(json_count as usize);
+        for _ in 0..json_count {
+            let len = inner.read_u32::<LE>()?;
+            let offset = inner.stream_position()?;
+
+            json_blocks.push((offset, len));
+            inner.seek(SeekFrom::Current(len as i64))?;
+        }
+
+        let decompressed_size = inner.read_u32::<LE>()?;
+        let _compressed_size = inner.read_u32::<LE>()?;
+        let binary_offset = inner.stream_position()?;
+
+        Ok(ReplayReader {
+            inner,
+            json_blocks,
+            binary_offset,
+            decompressed_size,
+        })
+    }
+
+    /// Number of JSON blocks in the replay. Complete replays have two
+    pub fn json_count(&self) -> usize {
+        self.json_blocks.len()
+    }
+
+    /// Size of the packet buffer. Useful for pre-allocating when the packets are collected anyway
+    pub fn decompressed_size(&self) -> usize {
+        self.decompressed_size as usize
+    }
+
+    /// Read and parse the JSON block at `index`
+    pub fn read_json(&mut self, index: usize) -> Result<JsonVal, ReplayError> {
+        let (offset, len) = *self.json_blocks.get(index).ok_or_else(|| {
+            ReplayError::ReplayJsonFormatError(format!("missing json block at index {index}"))
+        })?;
+
+        let mut slice = vec![0; len as usize];
+        self.inner.seek(SeekFrom::Start(offset))?;
+        self.inner.read_exact(&mut slice)?;
+
+        crate::replay_parser::parse_json_slice(&slice)
+    }
+
+    pub fn replay_json_start(&mut self) -> Result<JsonVal, ReplayError> {
+        self.read_json(0)
+    }
+
+    /// Only available for complete replays
+    pub fn replay_json_end(&mut self) -> Option<Result<JsonVal, ReplayError>> {
+        (self.json_count() > 1).then(|| self.read_json(1))
+    }
+
+    /// Parse the replay version. Return `None` if parsing fails
+    pub fn parse_replay_version(&mut self) -> Option<[u16; 4]> {
+        let json = self.replay_json_start().ok()?;
+
+        crate::utils::parse_version(&json)
+    }
+
+    pub fn context(&mut self) -> Result<Context, ReplayError> {
+        let json = self.replay_json_start()?;
+
+        let version = crate::utils::parse_version(&json)
+            .ok_or_else(|| ReplayError::ReplayJsonFormatError("cannot parse replay version".into()))?;
+        let player_list = crate::utils::get_player_list(&json)?;
+
+        Ok(Context::new(version, player_list))
+    }
+
+    /// An iterator over the packets in the replay. The binary portion is decrypted and inflated as the
+    /// iterator is advanced
+    pub fn packets(&mut self) -> Result<PacketReader<&mut R>, ReplayError> {
+        self.inner.seek(SeekFrom::Start(self.binary_offset))?;
+
+        Ok(PacketReader::new(&mut self.inner))
+    }
+
+    /// Same as [packets](ReplayReader::packets) but takes ownership of the underlying reader
+    pub fn into_packets(mut self) -> Result<PacketReader<R>, ReplayError> {
+        self.inner.seek(SeekFrom::Start(self.binary_offset))?;
+
+        Ok(PacketReader::new(self.inner))
+    }
+}
+
+/// Iterator over the packets of the binary portion of a `.wotreplay`. Similar to
+/// [PacketStream](crate::PacketStream) except it yields [OwnedPacket] because the packets are not held in
+/// a buffer that outlives the iterator.
+pub struct PacketReader<R> {
+    inner:  BufReader<BinaryReader<R>>,
+    count:  i32,
+    failed: bool,
+}
+
+impl<R: Read> PacketReader<R> {
+    /// `inner` must be positioned at the start of the encrypted binary portion
+    pub fn new(inner: R) -> Self {
+        PacketReader {
+            inner:  BufReader::with_capacity(CHUNK_SIZE, BinaryReader::new(inner)),
+            count:  0,
+            failed: false,
+        }
+    }
+
+    fn read_packet(&mut self) -> Result<Option<OwnedPacket>, ReplayError> {
+        let mut metadata = [0; METADATA_SIZE];
+
+        let header_len = read_up_to(&mut self.inner, &mut metadata)?;
+        if header_len == 0 {
+            return Ok(None);
+        } else if header_len != METADATA_SIZE {
+            return Err(ReplayError::PacketStreamError);
+        }
+
+        let payload_size = u32::from_le_bytes(metadata[..4].try_into().unwrap()) as usize;
+
+        let mut data = Vec::with_capacity(METADATA_SIZE + payload_size);
+        data.extend_from_slice(&metadata);
+        data.resize(METADATA_SIZE + payload_size, 0);
+
+        if read_up_to(&mut self.inner, &mut data[METADATA_SIZE..])? != payload_size {
+            return Err(ReplayError::PacketStreamError);
+        }
+
+        let packet_id = self.count;
+        self.count += 1;
+
+        Ok(Some(OwnedPacket::new(packet_id, data)))
+    }
+}
+
+impl<R: Read> Iterator for PacketReader<R> {
+    type Item = Result<OwnedPacket, ReplayError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let packet = self.read_packet().transpose();
+        if matches!(packet, Some(Err(_))) {
+            self.failed = true;
+        }
+
+        packet
+    }
+}
+
+/// Reads up to `buf.len()` bytes. Unlike `read_exact`, a short read at EOF is not an error. Decompression
+/// errors are converted to `ReplayError`
+fn read_up_to<R: Read>(reader: &mut R, mut buf: &mut [u8]) -> Result<usize, ReplayError> {
+    let mut total = 0;
+    while !buf.is_empty() {
+        match reader.read(buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                total += n;
+                buf = &mut buf[n..];
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) if err.kind() == std::io::ErrorKind::InvalidData => {
+                return Err(ReplayError::Other("decompression error".to_string()))
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(total)
+}
+
+/// Decrypts and inflates the binary portion of a `.wotreplay` as it is read
+pub(crate) struct BinaryReader<R> {
+    decryptor: BlockDecryptor<R>,
+    state:     Box<InflateState>,
+
+    /// Decrypted bytes that are not yet inflated
+    input:     Vec<u8>,
+    input_pos: usize,
+
+    input_exhausted: bool,
+    stream_ended:    bool,
+}
+
+impl<R: Read> BinaryReader<R> {
+    pub fn new(inner: R) -> Self {
+        BinaryReader {
+            decryptor:       BlockDecryptor::new(inner),
+            state:           InflateState::new_boxed(DataFormat::Zlib),
+            input:           vec![0; CHUNK_SIZE],
+            input_pos:       CHUNK_SIZE,
+            input_exhausted: false,
+            stream_ended:    false,
+        }
+    }
+
+    fn refill(&mut self) -> std::io::Result<()> {
+        self.input.resize(CHUNK_SIZE, 0);
+        let filled = self.decryptor.read_blocks(&mut self.input)?;
+
+        self.input.truncate(filled);
+        self.input_pos = 0;
+        self.input_exhausted = filled == 0;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for BinaryReader<R> {
+    fn read(&mut self, output: &mut [u8]) -> std::io::Result<usize> {
+        if output.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            if self.stream_ended {
+                return Ok(0);
+            }
+
+            if self.input_pos == self.input.len() && !self.input_exhausted {
+                self.refill()?;
+            }
+
+            // Never `MZFlush::Finish`. It turns a truncated stream into a data error
+            let result = inflate(&mut self.state, &self.input[self.input_pos..], output, MZFlush::None);
+            self.input_pos += result.bytes_consumed;
+
+            match result.status {
+                Ok(MZStatus::StreamEnd) => {
+                    self.stream_ended = true;
+                    return Ok(result.bytes_written);
+                }
+                Ok(_) | Err(MZError::Buf) if result.bytes_written > 0 => return Ok(result.bytes_written),
+                Ok(_) | Err(MZError::Buf) if !self.input_exhausted => continue,
+                Ok(_) | Err(MZError::Buf) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        "compressed stream ended unexpectedly",
+                    ))
+                }
+                // A failed inflate state keeps failing so the error is returned on the next call
+                Err(_) if result.bytes_written > 0 => return Ok(result.bytes_written),
+                Err(err) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("decompression error: {err:?}"),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReplayParser;
+
+    #[test]
+    fn streams_same_packets_as_parser() {
+        let parser = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+        let mut reader = ReplayReader::open("input_files/example.wotreplay").unwrap();
+
+        assert_eq!(reader.json_count(), parser.replay_json().len());
+        assert_eq!(
+            &reader.replay_json_start().unwrap(),
+            parser.replay_json_start().unwrap()
+        );
+
+        let mut packet_count = 0;
+        for (expected, actual) in parser.packet_stream().zip(reader.packets().unwrap()) {
+            let (expected, actual) = (expected.unwrap(), actual.unwrap());
+
+            assert_eq!(expected.inner(), actual.as_packet().inner());
+            packet_count += 1;
+        }
+
+        assert_eq!(packet_count, parser.packet_stream().count());
+    }
+}
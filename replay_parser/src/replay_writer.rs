@@ -0,0 +1,148 @@
+use std::io::Write;
+use std::path::Path;
+
+use byteorder::{WriteBytesExt, LE};
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use serde_json::Value as JsonVal;
+
+use crate::crypto::{encrypt, xor_encrypt, BLOCK_SIZE};
+use crate::{Packet, ReplayError, ReplayParser};
+
+/// Magic number at the start of every `.wotreplay`
+pub const REPLAY_MAGIC_NUM: u32 = 0x11343212;
+
+/// Level used when compressing the packets buffer. Same as the zlib default
+const COMPRESSION_LEVEL: u8 = 6;
+
+/// Produces a `.wotreplay` from JSON blocks and packets. This is the inverse of
+/// [ReplayParser](crate::ReplayParser): the packets buffer is zlib compressed, XOR chained and encrypted
+/// with the same Blowfish key the game uses.
+/// ## Example - Round trip a replay
+/// ```
+/// # use wot_replay_parser::*;
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+///
+/// let writer = ReplayWriter::from_parser(&replay).unwrap();
+/// let bytes = writer.to_bytes().unwrap();
+///
+/// let round_trip = ReplayParser::parse(bytes).unwrap();
+/// assert_eq!(replay.packets_buffer().unwrap(), round_trip.packets_buffer().unwrap());
+/// ```
+#[derive(Default, Clone)]
+pub struct ReplayWriter {
+    /// Serialized JSON blocks
+    json: Vec<Vec<u8>>,
+
+    /// Uncompressed packets
+    packets_buffer: Vec<u8>,
+}
+
+impl ReplayWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start with the JSON blocks and packets of a parsed replay. The original JSON bytes are kept as is.
+    /// Must call `load_packets` if the replay was parsed with
+    /// [parse_json](ReplayParser::parse_json)
+    pub fn from_parser(parser: &ReplayParser) -> Result<Self, ReplayError> {
+        Ok(ReplayWriter {
+            json:           parser.raw_json()?.into_iter().map(<[u8]>::to_vec).collect(),
+            packets_buffer: parser.packets_buffer()?.to_vec(),
+        })
+    }
+
+    /// Append a JSON block. A complete replay has two: the battle info and the battle results
+    pub fn push_json(&mut self, json: &JsonVal) -> Result<&mut Self, ReplayError> {
+        self.json.push(serde_json::to_vec(json)?);
+
+        Ok(self)
+    }
+
+    /// Replace all JSON blocks
+    pub fn set_json(&mut self, json: &[JsonVal]) -> Result<&mut Self, ReplayError> {
+        self.json.clear();
+        for json in json {
+            self.push_json(json)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Append a packet to the end of the packets buffer
+    pub fn push_packet(&mut self, packet: &Packet) -> &mut Self {
+        self.packets_buffer.extend_from_slice(packet.inner());
+
+        self
+    }
+
+    /// Append packets to the end of the packets buffer
+    pub fn extend_packets<'a, I>(&mut self, packets: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Packet<'a>>,
+    {
+        for packet in packets {
+            self.push_packet(&packet);
+        }
+
+        self
+    }
+
+    /// Replace the packets buffer. The buffer must be a sequence of packets like the one returned by
+    /// [packets_buffer](ReplayParser::packets_buffer)
+    pub fn set_packets_buffer(&mut self, packets_buffer: Vec<u8>) -> &mut Self {
+        self.packets_buffer = packets_buffer;
+
+        self
+    }
+
+    pub fn clear_packets(&mut self) -> &mut Self {
+        self.packets_buffer.clear();
+
+        self
+    }
+
+    pub fn packets_buffer(&self) -> &[u8] {
+        &self.packets_buffer
+    }
+
+    /// Write the `.wotreplay` to `writer`
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), ReplayError> {
+        writer.write_u32::<LE>(REPLAY_MAGIC_NUM)?;
+
+        writer.write_u32::<LE>(self.json.len() as u32)?;
+        for json in &self.json {
+            writer.write_u32::<LE>(json.len() as u32)?;
+            writer.write_all(json)?;
+        }
+
+        let mut compressed = compress_to_vec_zlib(&self.packets_buffer, COMPRESSION_LEVEL);
+        let compressed_size = compressed.len();
+
+        let padding = (BLOCK_SIZE - compressed_size % BLOCK_SIZE) % BLOCK_SIZE;
+        compressed.resize(compressed_size + padding, 0);
+
+        let encrypted = encrypt(xor_encrypt(compressed));
+
+        writer.write_u32::<LE>(self.packets_buffer.len() as u32)?;
+        writer.write_u32::<LE>(compressed_size as u32)?;
+        writer.write_all(&encrypted)?;
+
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ReplayError> {
+        let mut output = Vec::new();
+        self.write(&mut output)?;
+
+        Ok(output)
+    }
+
+    pub fn write_file<T: AsRef<Path>>(&self, path: T) -> Result<(), ReplayError> {
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
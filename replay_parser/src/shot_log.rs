@@ -0,0 +1,253 @@
+use serde::Serialize;
+use wot_types::AttackReason;
+
+use crate::events::{AvatarMethods, EntityMethod, HitPoint, ShotResult, VehicleMethods};
+use crate::{BattleEvent, DamageLedger, ReplayError, ReplayParser};
+
+/// Time between two ticks of the server (in seconds). The hit points and the damage of a shot are sent in
+/// the same tick as its results
+const SERVER_TICK: f32 = 0.1;
+
+/// Every shot of the player who recorded the replay that hit a vehicle, built from `ShowShotResults`, with
+/// the hit points and the damage of the shot on each vehicle.
+///
+/// Only the damage of the shot itself is counted. Damage of the fire started by a shot is not (see
+/// [DamageLedger] for that), nor are shots that missed since the server does not send results for them.
+///
+/// ```no_run
+/// # use wot_replay_parser::{ReplayParser, ShotLog};
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+/// let (shot_log, _errors) = ShotLog::from_replay(&replay).unwrap();
+///
+/// for shot in shot_log.shots() {
+///     for hit in &shot.hits {
+///         println!("{}: {:?} for {}", hit.result.vehicle_id, hit.result.outcome(), hit.damage);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShotLog {
+    player_vehicle_id: i32,
+    shots:             Vec<PlayerShot>,
+
+    /// Damage of every vehicle, to know the health lost to each shot
+    ledger: DamageLedger,
+}
+
+/// A shot of the recording player. A shot (or the explosion of an HE shell) can hit multiple vehicles
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerShot {
+    /// Time of the results of the shot (same as [Packet::time](crate::Packet::time))
+    pub time: f32,
+    pub hits: Vec<ShotHit>,
+}
+
+/// Result of a shot on one vehicle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShotHit {
+    pub result: ShotResult,
+
+    /// Where the shell hit the vehicle. Empty if the server did not send them (ex: a vehicle only hit by the
+    /// explosion of an HE shell)
+    pub points: Vec<HitPoint>,
+
+    /// Health lost by the vehicle. Like [DamageInstance](crate::DamageInstance), damage beyond the remaining
+    /// health is not counted
+    pub damage: i32,
+}
+
+impl ShotLog {
+    pub fn new(player_vehicle_id: i32) -> Self {
+        ShotLog {
+            player_vehicle_id,
+            shots: Vec::new(),
+            ledger: DamageLedger::new(),
+        }
+    }
+
+    /// Apply every event of the replay. The recording player is found in the JSON of the replay. Like
+    /// [DamageLedger::from_replay], packets that cannot be parsed are skipped and returned with the log
+    pub fn from_replay(replay: &ReplayParser) -> Result<(Self, Vec<ReplayError>), ReplayError> {
+        let player_vehicle_id = crate::utils::get_player_vehicle_id(replay.replay_json_start()?)?;
+        let mut shot_log = ShotLog::new(player_vehicle_id);
+        let mut errors = Vec::new();
+
+        for event in replay.event_stream()?.timed() {
+            match event {
+                Ok((time, event)) => shot_log.apply(time, &event),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Ok((shot_log, errors))
+    }
+
+    /// Apply an event to the log. Hit points and damage are added to the last shot if they are for one of
+    /// the vehicles it hit and were sent in the same tick
+    pub fn apply(&mut self, time: f32, event: &BattleEvent) {
+        let shot_damage = self
+            .ledger
+            .apply(time, event)
+            .filter(|damage| {
+                damage.reason == Some(AttackReason::Shot)
+                    && damage.attacker_id == Some(self.player_vehicle_id)
+            })
+            .map(|damage| (damage.victim_id, damage.amount));
+        if let Some((victim_id, amount)) = shot_damage {
+            if let Some(hit) = self.last_hit(time, victim_id) {
+                hit.damage += amount;
+            }
+
+            return;
+        }
+
+        let BattleEvent::EntityMethod(method_event) = event else {
+            return;
+        };
+        match &method_event.method {
+            EntityMethod::Avatar(AvatarMethods::ShowShotResults(results)) => {
+                let hits = results
+                    .iter()
+                    .map(|result| ShotHit {
+                        result: result.clone(),
+                        points: Vec::new(),
+                        damage: 0,
+                    })
+                    .collect();
+
+                self.shots.push(PlayerShot { time, hits });
+            }
+            EntityMethod::Vehicle(VehicleMethods::ShowDamageFromShot(shot))
+                if shot.entity_id as i32 == self.player_vehicle_id =>
+            {
+                if let Some(hit) = self.last_hit(time, method_event.entity_id) {
+                    hit.points.extend(shot.points.iter().cloned());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Result on a vehicle of the last shot, if that shot is from this tick
+    fn last_hit(&mut self, time: f32, vehicle_id: i32) -> Option<&mut ShotHit> {
+        let shot = self
+            .shots
+            .last_mut()
+            .filter(|shot| time - shot.time < SERVER_TICK)?;
+
+        shot.hits
+            .iter_mut()
+            .find(|hit| hit.result.vehicle_id == vehicle_id)
+    }
+
+    pub fn player_vehicle_id(&self) -> i32 {
+        self.player_vehicle_id
+    }
+
+    /// Every shot that hit a vehicle, in the order they were fired
+    pub fn shots(&self) -> &[PlayerShot] {
+        &self.shots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EntityMethodEvent, OnHealthChanged, ShotOutcome, ShowDamageFromShot};
+
+    const PLAYER: i32 = 10;
+
+    fn method(entity_id: i32, method: EntityMethod) -> BattleEvent {
+        BattleEvent::EntityMethod(EntityMethodEvent { entity_id, method })
+    }
+
+    fn health_changed(victim_id: i32, old_health: i16, new_health: i16, attacker_id: i32) -> BattleEvent {
+        let health_changed = OnHealthChanged {
+            new_health,
+            old_health: Some(old_health),
+            attacker_id,
+            attack_reason: 0,
+        };
+
+        method(
+            victim_id,
+            EntityMethod::Vehicle(VehicleMethods::OnHealthChanged(health_changed)),
+        )
+    }
+
+    fn damage_from_shot(victim_id: i32, attacker_id: i32, points: &[u64]) -> BattleEvent {
+        let shot = ShowDamageFromShot {
+            entity_id:            attacker_id as u32,
+            points:               points.iter().map(|&point| HitPoint::from(point)).collect(),
+            effects_index:        0,
+            damage_factor:        0,
+            last_material_shield: Some(false),
+        };
+
+        method(
+            victim_id,
+            EntityMethod::Vehicle(VehicleMethods::ShowDamageFromShot(shot)),
+        )
+    }
+
+    #[test]
+    fn attributes_hit_points_and_damage_to_shots() {
+        let mut shot_log = ShotLog::new(PLAYER);
+        // Penetration of vehicle 1 and the explosion of the shell blocked by vehicle 2
+        let results = [0x0000_0020_0000_0001, 0x0000_0110_0000_0002].map(ShotResult::from);
+        shot_log.apply(
+            5.0,
+            &method(
+                100,
+                EntityMethod::Avatar(AvatarMethods::ShowShotResults(results.to_vec())),
+            ),
+        );
+        shot_log.apply(5.0, &damage_from_shot(1, PLAYER, &[0x0204]));
+        shot_log.apply(5.0, &health_changed(1, 1000, 600, PLAYER));
+        shot_log.apply(5.0, &health_changed(2, 300, -20, PLAYER));
+
+        // Another vehicle shooting vehicle 1 in the same tick, then a later hit of the player without results
+        shot_log.apply(5.0, &damage_from_shot(1, 3, &[0x0104]));
+        shot_log.apply(5.0, &health_changed(1, 600, 500, 3));
+        shot_log.apply(9.0, &health_changed(1, 500, 200, PLAYER));
+
+        let [shot] = shot_log.shots() else {
+            panic!("expected one shot, got {:?}", shot_log.shots());
+        };
+        assert_eq!(shot.time, 5.0);
+        assert_eq!(shot.hits[0].result.outcome(), ShotOutcome::Penetrated);
+        assert_eq!(shot.hits[0].points.len(), 1);
+        assert_eq!(shot.hits[0].points[0].component_index, 2);
+        assert_eq!(shot.hits[0].damage, 400);
+        assert_eq!(shot.hits[1].result.outcome(), ShotOutcome::Blocked);
+        assert!(shot.hits[1].points.is_empty());
+        assert_eq!(shot.hits[1].damage, 300);
+    }
+
+    #[test]
+    fn example_replay_shots() {
+        let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+        let (shot_log, errors) = ShotLog::from_replay(&replay).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(shot_log.player_vehicle_id(), 9383752);
+
+        let hits = shot_log
+            .shots()
+            .iter()
+            .flat_map(|shot| &shot.hits)
+            .collect::<Vec<_>>();
+        let damage = hits.iter().map(|hit| hit.damage).collect::<Vec<_>>();
+        assert_eq!(damage, [601, 676, 0, 689, 75, 685, 709, 679]);
+        // All of the damage of the player was dealt by shots, see `damageDealt` in the battle results
+        assert_eq!(damage.iter().sum::<i32>(), 4114);
+
+        // Every hit has its points, and the penetrations that dealt damage have a piercing effect
+        assert!(hits.iter().all(|hit| !hit.points.is_empty()));
+        for hit in hits
+            .iter()
+            .filter(|hit| hit.result.outcome() == ShotOutcome::Penetrated && hit.damage > 0)
+        {
+            assert!(hit.points.iter().any(HitPoint::is_penetration), "{hit:?}");
+        }
+    }
+}
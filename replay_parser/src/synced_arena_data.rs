@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use wot_types::WotValue;
+
+use crate::events::{ArenaUpdateData, AvatarMethods, EntityMethod, SyncObjects};
+use crate::{BattleEvent, ReplayError, ReplayParser};
+
+/// Replicated key-value store of the arena's synced data containers. It is built from the `SyncObjects` and
+/// `SyncObjectsDiff` arena updates and keeps every change so that the state can be queried at any time of
+/// the replay.
+///
+/// ```no_run
+/// # use wot_replay_parser::{ReplayParser, SyncedArenaData};
+/// let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+/// let (synced_data, _errors) = SyncedArenaData::from_replay(&replay).unwrap();
+///
+/// for change in synced_data.changes() {
+///     println!("{:.2} {} {:?} = {:?}", change.time, change.object_id, change.path, change.new_value);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SyncedArenaData {
+    objects: HashMap<i64, WotValue>,
+    changes: Vec<SyncedDataChange>,
+
+    /// Updates that were applied, so that the state at an earlier time can be rebuilt
+    history: Vec<(f32, ArenaUpdateData)>,
+}
+
+/// A value of the store that was changed by a `SyncObjects` or `SyncObjectsDiff` update
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncedDataChange {
+    /// Time of the update (same as [Packet::time](crate::Packet::time))
+    pub time:      f32,
+    pub object_id: i64,
+
+    /// Keys from the root of the object to the value that changed. Integer keys are converted to strings.
+    /// Empty when the whole object was replaced by `SyncObjects`
+    pub path:      Vec<String>,
+    pub old_value: Option<WotValue>,
+
+    /// `WotValue::None` when the value was removed
+    pub new_value: WotValue,
+}
+
+impl SyncedArenaData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply every synced data update of the replay. Like
+    /// [ArenaState::from_replay](crate::ArenaState::from_replay), packets that cannot be parsed are skipped
+    /// and returned with the store
+    pub fn from_replay(replay: &ReplayParser) -> Result<(Self, Vec<ReplayError>), ReplayError> {
+        let mut synced_data = SyncedArenaData::new();
+        let mut errors = Vec::new();
+
+        for event in replay.event_stream()?.timed() {
+            match event {
+                Ok((time, BattleEvent::EntityMethod(method_event))) => {
+                    if let EntityMethod::Avatar(AvatarMethods::UpdateArena(update)) = method_event.method {
+                        synced_data.apply(time, &update.update_data);
+                    }
+                }
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Ok((synced_data, errors))
+    }
+
+    /// Apply an arena update to the store. Updates that are not `SyncObjects` or `SyncObjectsDiff` are
+    /// ignored. Returns the changes caused by this update
+    pub fn apply(&mut self, time: f32, update: &ArenaUpdateData) -> &[SyncedDataChange] {
+        let first_change = self.changes.len();
+
+        match update {
+            ArenaUpdateData::SyncObjects(SyncObjects { objects }) => {
+                for (&object_id, data) in objects {
+                    let old_value = self.objects.insert(object_id, data.clone());
+
+                    if old_value.as_ref() != Some(data) {
+                        self.changes.push(SyncedDataChange {
+                            time,
+                            object_id,
+                            path: Vec::new(),
+                            old_value,
+                            new_value: data.clone(),
+                        });
+                    }
+                }
+            }
+            ArenaUpdateData::SyncObjectsDiff(SyncObjects { objects }) => {
+                for (&object_id, diff) in objects {
+                    if *diff == WotValue::None {
+                        if let Some(old_value) = self.objects.remove(&object_id) {
+                            self.changes.push(SyncedDataChange {
+                                time,
+                                object_id,
+                                path: Vec::new(),
+                                old_value: Some(old_value),
+                                new_value: WotValue::None,
+                            });
+                        }
+                        continue;
+                    }
+
+                    let object = self.objects.entry(object_id).or_insert_with(empty_dict);
+                    let mut merger = DiffMerger {
+                        time,
+                        object_id,
+                        path: Vec::new(),
+                        changes: &mut self.changes,
+                    };
+
+                    merger.merge(object, diff);
+                }
+            }
+            _ => return &[],
+        }
+
+        self.history.push((time, update.clone()));
+
+        &self.changes[first_change..]
+    }
+
+    /// Current data of every object
+    pub fn objects(&self) -> &HashMap<i64, WotValue> {
+        &self.objects
+    }
+
+    /// Current data of an object
+    pub fn get(&self, object_id: i64) -> Option<&WotValue> {
+        self.objects.get(&object_id)
+    }
+
+    /// Current value at `path` in an object. Integer keys can be given as strings
+    pub fn get_path(&self, object_id: i64, path: &[&str]) -> Option<&WotValue> {
+        let mut value = self.get(object_id)?;
+
+        for key in path {
+            value = match value {
+                WotValue::NamedCollection(dict) => dict.get(*key)?,
+                WotValue::NamedIntCollection(dict) => dict.get(&key.parse().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some(value)
+    }
+
+    /// Every change since the store was created, in the order they were applied
+    pub fn changes(&self) -> &[SyncedDataChange] {
+        &self.changes
+    }
+
+    /// State of the store after the updates received until `time` (inclusive)
+    pub fn state_at(&self, time: f32) -> SyncedArenaData {
+        let mut synced_data = SyncedArenaData::new();
+
+        for (update_time, update) in self
+            .history
+            .iter()
+            .take_while(|(update_time, _)| *update_time <= time)
+        {
+            synced_data.apply(*update_time, update);
+        }
+
+        synced_data
+    }
+}
+
+/// Merge a diff into an object. Dicts are merged key by key, a `None` value removes the key and any other
+/// value replaces the old one. Like in the client's `synchronizeDicts`, an empty dict merges nothing into the
+/// dict it is merged into.
+///
+/// The `None` deletion marker follows the way the client synchronizes its other dict caches. We have no
+/// replay with a removal (or a Comp7 replay at all) to confirm it
+struct DiffMerger<'a> {
+    time:      f32,
+    object_id: i64,
+    path:      Vec<String>,
+    changes:   &'a mut Vec<SyncedDataChange>,
+}
+
+impl DiffMerger<'_> {
+    fn merge(&mut self, target: &mut WotValue, diff: &WotValue) {
+        match (target, diff) {
+            // An empty dict is always parsed as `NamedCollection`, whatever the type of its keys
+            (target, diff) if is_dict(target) && is_empty_dict(diff) => {}
+            (WotValue::NamedCollection(target), WotValue::NamedCollection(diff)) => {
+                self.merge_dict(target, diff, |key| key.clone())
+            }
+            (WotValue::NamedIntCollection(target), WotValue::NamedIntCollection(diff)) => {
+                self.merge_dict(target, diff, |key| key.to_string())
+            }
+            (target, diff) => {
+                if target != diff {
+                    let old_value = std::mem::replace(target, diff.clone());
+                    self.push_change(Some(old_value), diff.clone());
+                }
+            }
+        }
+    }
+
+    fn merge_dict<K: Clone + Eq + Hash>(
+        &mut self, target: &mut HashMap<K, WotValue>, diff: &HashMap<K, WotValue>, key_name: fn(&K) -> String,
+    ) {
+        for (key, value) in diff {
+            self.path.push(key_name(key));
+
+            match target.get_mut(key) {
+                Some(_) if *value == WotValue::None => {
+                    let old_value = target.remove(key);
+                    self.push_change(old_value, WotValue::None);
+                }
+                Some(existing) => self.merge(existing, value),
+                // Removing a key that is not there
+                None if *value == WotValue::None => {}
+                None => {
+                    target.insert(key.clone(), value.clone());
+                    self.push_change(None, value.clone());
+                }
+            }
+
+            self.path.pop();
+        }
+    }
+
+    fn push_change(&mut self, old_value: Option<WotValue>, new_value: WotValue) {
+        self.changes.push(SyncedDataChange {
+            time: self.time,
+            object_id: self.object_id,
+            path: self.path.clone(),
+            old_value,
+            new_value,
+        });
+    }
+}
+
+fn empty_dict() -> WotValue {
+    WotValue::NamedCollection(HashMap::new())
+}
+
+fn is_dict(value: &WotValue) -> bool {
+    matches!(
+        value,
+        WotValue::NamedCollection(_) | WotValue::NamedIntCollection(_) | WotValue::NamedByteCollection(_)
+    )
+}
+
+fn is_empty_dict(value: &WotValue) -> bool {
+    matches!(value, WotValue::NamedCollection(dict) if dict.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dict<const N: usize>(entries: [(&str, WotValue); N]) -> WotValue {
+        WotValue::NamedCollection(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    fn update(objects: WotValue, is_diff: bool) -> ArenaUpdateData {
+        let WotValue::NamedCollection(objects) = objects else {
+            unreachable!()
+        };
+        let objects = SyncObjects {
+            objects: objects
+                .into_iter()
+                .map(|(key, value)| (key.parse().unwrap(), value))
+                .collect(),
+        };
+
+        if is_diff {
+            ArenaUpdateData::SyncObjectsDiff(objects)
+        } else {
+            ArenaUpdateData::SyncObjects(objects)
+        }
+    }
+
+    #[test]
+    fn applies_diffs_and_rebuilds_past_state() {
+        let mut synced_data = SyncedArenaData::new();
+
+        let full = dict([("2", dict([("points", WotValue::Int(0)), ("roles", dict([]))]))]);
+        assert_eq!(synced_data.apply(1.0, &update(full, false)).len(), 1);
+
+        let diff = dict([(
+            "2",
+            dict([
+                ("points", WotValue::Int(5)),
+                ("roles", dict([("1", WotValue::Int(3))])),
+            ]),
+        )]);
+        let changes = synced_data.apply(2.0, &update(diff, true));
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().any(|change| change.path == ["points"]
+            && change.old_value == Some(WotValue::Int(0))
+            && change.new_value == WotValue::Int(5)));
+        assert!(changes
+            .iter()
+            .any(|change| change.path == ["roles", "1"] && change.old_value.is_none()));
+
+        assert!(synced_data.apply(3.0, &ArenaUpdateData::Unimplemented).is_empty());
+        assert_eq!(synced_data.get_path(2, &["roles", "1"]), Some(&WotValue::Int(3)));
+        assert_eq!(
+            synced_data.state_at(1.5).get_path(2, &["points"]),
+            Some(&WotValue::Int(0))
+        );
+        assert_eq!(
+            synced_data.state_at(2.0).get_path(2, &["points"]),
+            Some(&WotValue::Int(5))
+        );
+    }
+
+    /// `SyncObjectsDiff` payload as it is sent over the wire. This is a synthetic pickle: there is no Comp7
+    /// replay in `input_files`
+    fn pickled_diff(objects: serde_pickle::Value) -> ArenaUpdateData {
+        let pickle = serde_pickle::value_to_vec(&objects, serde_pickle::SerOptions::new()).unwrap();
+        let mut data = vec![25];
+        crate::packet_parser::write_byte_array(&pickle, &mut data);
+
+        crate::events::UpdateArena::from(&data, [1, 16, 1, 0])
+            .unwrap()
+            .update_data
+    }
+
+    #[test]
+    fn removes_keys_and_ignores_empty_dicts() {
+        use serde_pickle::{HashableValue as Key, Value};
+
+        let mut synced_data = SyncedArenaData::new();
+        let full = dict([
+            (
+                "2",
+                dict([
+                    ("points", WotValue::Int(5)),
+                    ("roles", dict([("1", WotValue::Int(3)), ("2", WotValue::Int(4))])),
+                    ("timers", dict([("1", WotValue::Float(30.0))])),
+                ]),
+            ),
+            ("3", dict([("state", WotValue::Int(1))])),
+        ]);
+        synced_data.apply(1.0, &update(full, false));
+
+        // `{2: {'roles': {'1': None}, 'timers': {}, 'missing': None}, 3: None}`
+        let object = Value::Dict(
+            [
+                (
+                    Key::String("roles".into()),
+                    Value::Dict([(Key::String("1".into()), Value::None)].into()),
+                ),
+                (Key::String("timers".into()), Value::Dict(Default::default())),
+                (Key::String("missing".into()), Value::None),
+            ]
+            .into(),
+        );
+        let diff = pickled_diff(Value::Dict(
+            [(Key::I64(2), object), (Key::I64(3), Value::None)].into(),
+        ));
+
+        let changes = synced_data.apply(2.0, &diff).to_vec();
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().any(|change| change.path == ["roles", "1"]
+            && change.old_value == Some(WotValue::Int(3))
+            && change.new_value == WotValue::None));
+        assert!(changes
+            .iter()
+            .any(|change| change.object_id == 3 && change.path.is_empty()));
+
+        assert_eq!(synced_data.get_path(2, &["roles", "1"]), None);
+        assert_eq!(synced_data.get_path(2, &["roles", "2"]), Some(&WotValue::Int(4)));
+        assert_eq!(
+            synced_data.get_path(2, &["timers"]),
+            Some(&dict([("1", WotValue::Float(30.0))]))
+        );
+        assert_eq!(synced_data.get_path(2, &["points"]), Some(&WotValue::Int(5)));
+        assert_eq!(synced_data.get(3), None);
+
+        // Nothing left to remove
+        assert!(synced_data.apply(3.0, &diff).is_empty());
+        assert!(synced_data.state_at(1.0).get(3).is_some());
+    }
+}
@@ -1,10 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde_json::Map;
 use serde_json::Value as JsonVal;
 use time::Duration;
 
-use crate::ReplayParser;
 use crate::{ReplayError, VERSIONS};
 
 /// `[0, 9, 15, 0]` => `"0_9_15_0"`
@@ -90,9 +89,36 @@ pub const fn get_json_type(json: &JsonVal) -> &'static str {
     }
 }
 
-pub fn get_player_list(parser: &ReplayParser) -> Result<HashMap<i32, String>, ReplayError> {
-    let json = parser.replay_json_start()?;
+/// Parse the replay version from the initial JSON object of the replay. Return `None` if parsing fails
+pub fn parse_version(json: &JsonVal) -> Option<[u16; 4]> {
+    // World\u{a0}of\u{a0}Tanks v.1.9.1.1 #378
+    // "坦克世界 v.0.9.10 #77"
+    let version = json.pointer("/clientVersionFromXml")?.as_str()?;
 
+    // ["World\u{a0}of\u{a0}Tanks", "v.1.9.1.1", "#378"]
+    // ["坦克世界", "v.0.9.10", "#77"]
+    let version: Vec<_> = version.split(' ').collect();
+
+    // "v.0.9.10"
+    // "v.1.9.1.1"
+    let version = version.get(1)?;
+
+    let mut version_iter = version.split('.');
+
+    version_iter.next(); // skip "v"
+
+    let major: u16 = version_iter.next()?.parse().ok()?;
+    let minor: u16 = version_iter.next()?.parse().ok()?;
+    let patch: u16 = version_iter.next()?.parse().ok()?;
+    let extra: u16 = version_iter.next().unwrap_or("0").parse().ok()?;
+
+    let version_array = [major, minor, patch, extra];
+
+    Some(version_array)
+}
+
+/// Player list from the initial JSON object of the replay
+pub fn get_player_list(json: &JsonVal) -> Result<HashMap<i32, String>, ReplayError> {
     let mut player_list = HashMap::new();
     let vehicles = as_map("/vehicles", json)?;
     for (avatar_id, veh) in vehicles.into_iter() {
@@ -108,6 +134,53 @@ pub fn get_player_list(parser: &ReplayParser) -> Result<HashMap<i32, String>, Re
     Ok(player_list)
 }
 
+/// Vehicle of the recording player, from the initial JSON object of the replay
+pub fn get_player_vehicle_id(json: &JsonVal) -> Result<i32, ReplayError> {
+    let player_name = as_string("/playerName", json)?;
+
+    for (avatar_id, veh) in as_map("/vehicles", json)? {
+        if as_string("/name", veh)? == player_name {
+            return avatar_id
+                .parse::<i32>()
+                .map_err(|err| ReplayError::Other(err.to_string()));
+        }
+    }
+
+    Err(ReplayError::Other(format!(
+        "{player_name} is not in the vehicles"
+    )))
+}
+
+/// Vehicles of the players that are not in the team of the recording player, from the initial JSON object
+/// of the replay
+pub fn get_enemy_list(json: &JsonVal) -> Result<HashSet<i32>, ReplayError> {
+    let player_name = as_string("/playerName", json)?;
+    let vehicles = as_map("/vehicles", json)?;
+
+    let mut teams = HashMap::new();
+    let mut player_team = None;
+    for (avatar_id, veh) in vehicles.into_iter() {
+        let avatar_id = avatar_id
+            .parse::<i32>()
+            .map_err(|err| ReplayError::Other(err.to_string()))?;
+        let team = as_i64("/team", veh)?;
+
+        if as_string("/name", veh)? == player_name {
+            player_team = Some(team);
+        }
+        teams.insert(avatar_id, team);
+    }
+
+    let player_team =
+        player_team.ok_or_else(|| ReplayError::Other(format!("{player_name} is not in the vehicles")))?;
+
+    Ok(teams
+        .into_iter()
+        .filter(|(_, team)| *team != player_team)
+        .map(|(avatar_id, _)| avatar_id)
+        .collect())
+}
+
 /// Validate this version by checking if we have definition files for this version. If not return version
 /// closest to the input version
 pub fn validate_version(mut version: [u16; 4]) -> [u16; 4] {
@@ -0,0 +1,34 @@
+use wot_replay_parser::{DamageLedger, ReplayParser};
+
+#[test]
+fn damage_of_recorder_matches_battle_results() {
+    let replay = ReplayParser::parse_file("input_files/example.wotreplay").unwrap();
+    let (ledger, errors) = DamageLedger::from_replay(&replay).unwrap();
+    assert!(errors.is_empty());
+
+    let start = replay.replay_json_start().unwrap();
+    let player_name = &start["playerName"];
+    let (vehicle_id, _) = start["vehicles"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .find(|(_, vehicle)| &vehicle["name"] == player_name)
+        .unwrap();
+
+    // `personal` has the results of the vehicle of the recorder, keyed by its type, and the ones of its
+    // account
+    let battle_results = &replay.replay_json_end().unwrap()[0];
+    let (_, personal) = battle_results["personal"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .find(|(key, _)| *key != "avatar")
+        .unwrap();
+    let damage_dealt = personal["damageDealt"].as_i64().unwrap();
+
+    // Only the damage of the recorder can be checked: the client does not receive the changes of health of
+    // the vehicles it cannot see
+    let totals = ledger.vehicle_totals(vehicle_id.parse().unwrap()).unwrap();
+    assert_eq!(damage_dealt, 4114);
+    assert_eq!(i64::from(totals.dealt), damage_dealt);
+}
@@ -0,0 +1,34 @@
+use num_enum::TryFromPrimitive;
+use serde::Serialize;
+
+// Took from https://github.com/IzeBerg/wot-src/blob/EU/sources/res/scripts/common/constants.py
+// class BATTLE_EVENT_TYPE
+#[repr(i32)]
+#[derive(PartialEq, Hash, Eq, Copy, Clone, Debug, TryFromPrimitive, strum::Display, Serialize)]
+pub enum BattleEventType {
+    Spotted               = 0,
+    RadioAssist           = 1,
+    TrackAssist           = 2,
+    BaseCapturePoints     = 3,
+    BaseCaptureDropped    = 4,
+    Tanking               = 5,
+    Crit                  = 6,
+    Damage                = 7,
+    Kill                  = 8,
+    ReceivedCrit          = 9,
+    ReceivedDamage        = 10,
+    StunAssist            = 11,
+    TargetVisibility      = 12,
+    Detected              = 13,
+
+    // Frontline
+    DestructibleDamaged   = 14,
+    DestructibleDestroyed = 15,
+    DestructiblesDefended = 16,
+    DefenderBonus         = 17,
+    SmokeAssist           = 18,
+    InspireAssist         = 19,
+
+    MultiStun             = 20,
+    EquipmentTimerExpired = 21,
+}
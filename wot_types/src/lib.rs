@@ -12,3 +12,9 @@ pub use arena_attack_reasons::AttackReason;
 
 mod arena_periods;
 pub use arena_periods::{ArenaPeriod, FinishReason};
+
+mod battle_event_types;
+pub use battle_event_types::BattleEventType;
+
+mod vehicle_hits;
+pub use vehicle_hits::{TankPart, VehicleHitEffect, VehicleHitFlags};
@@ -0,0 +1,62 @@
+use num_enum::TryFromPrimitive;
+use serde::Serialize;
+
+// Took from https://github.com/IzeBerg/wot-src/blob/EU/sources/res/scripts/common/constants.py
+// class VEHICLE_HIT_EFFECT
+#[repr(i32)]
+#[derive(PartialEq, Hash, Eq, Copy, Clone, Debug, TryFromPrimitive, strum::Display, Serialize)]
+pub enum VehicleHitEffect {
+    IntermediateRicochet      = 0,
+    FinalRicochet             = 1,
+    ArmorNotPierced           = 2,
+    ArmorPiercedNoDamage      = 3,
+    ArmorPierced              = 4,
+    CriticalHit               = 5,
+    ArmorPiercedDeviceDamaged = 6,
+}
+
+// Took from https://github.com/IzeBerg/wot-src/blob/EU/sources/res/scripts/common/items/components/component_constants.py
+// class TankPartIndexes. Wheeled vehicles have more parts after `Gun`
+#[repr(i32)]
+#[derive(PartialEq, Hash, Eq, Copy, Clone, Debug, TryFromPrimitive, strum::Display, Serialize)]
+pub enum TankPart {
+    Chassis = 0,
+    Hull    = 1,
+    Turret  = 2,
+    Gun     = 3,
+}
+
+/// `VEHICLE_HIT_FLAGS` of the result of a shot (see `constants.py`)
+#[derive(PartialEq, Hash, Eq, Copy, Clone, Debug, Serialize)]
+pub struct VehicleHitFlags(pub u32);
+
+impl VehicleHitFlags {
+    pub const ARMOR_WITH_ZERO_DF_NOT_PENETRATED_BY_EXPLOSION: u32 = 1024;
+    pub const ARMOR_WITH_ZERO_DF_NOT_PENETRATED_BY_PROJECTILE: u32 = 64;
+    pub const ARMOR_WITH_ZERO_DF_PENETRATED_BY_EXPLOSION: u32 = 2048;
+    pub const ARMOR_WITH_ZERO_DF_PENETRATED_BY_PROJECTILE: u32 = 128;
+    pub const FIRE_STARTED: u32 = 4;
+    pub const IS_ANY_NOT_PIERCING_MASK: u32 = Self::MATERIAL_WITH_POSITIVE_DF_NOT_PENETRATED_BY_PROJECTILE
+        | Self::ARMOR_WITH_ZERO_DF_NOT_PENETRATED_BY_PROJECTILE
+        | Self::MATERIAL_WITH_POSITIVE_DF_NOT_PENETRATED_BY_EXPLOSION
+        | Self::ARMOR_WITH_ZERO_DF_NOT_PENETRATED_BY_EXPLOSION;
+    pub const IS_ANY_PIERCING_MASK: u32 = Self::MATERIAL_WITH_POSITIVE_DF_PENETRATED_BY_PROJECTILE
+        | Self::ARMOR_WITH_ZERO_DF_PENETRATED_BY_PROJECTILE
+        | Self::MATERIAL_WITH_POSITIVE_DF_PENETRATED_BY_EXPLOSION
+        | Self::ARMOR_WITH_ZERO_DF_PENETRATED_BY_EXPLOSION;
+    pub const MATERIAL_WITH_POSITIVE_DF_NOT_PENETRATED_BY_EXPLOSION: u32 = 256;
+    pub const MATERIAL_WITH_POSITIVE_DF_NOT_PENETRATED_BY_PROJECTILE: u32 = 16;
+    pub const MATERIAL_WITH_POSITIVE_DF_PENETRATED_BY_EXPLOSION: u32 = 512;
+    pub const MATERIAL_WITH_POSITIVE_DF_PENETRATED_BY_PROJECTILE: u32 = 32;
+    pub const RICOCHET: u32 = 8;
+    pub const VEHICLE_KILLED: u32 = 1;
+    pub const VEHICLE_WAS_DEAD_BEFORE_ATTACK: u32 = 2;
+
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+
+    pub fn intersects(&self, flags: u32) -> bool {
+        self.0 & flags != 0
+    }
+}